@@ -60,6 +60,16 @@ macro_rules! define_bench {
                 })
             });
 
+            group.bench_function("integral", |b| {
+                let x = black_box($fp::from_bits(-98765432123456789));
+                b.iter(move || x.integral(RoundMode::Floor))
+            });
+
+            group.bench_function("to_string", |b| {
+                let x = black_box($fp::from_bits(-98765432123456789));
+                b.iter(move || x.to_string())
+            });
+
             group.bench_function("next_power_of_ten", |b| {
                 let mut value = 0;
 
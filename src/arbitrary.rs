@@ -0,0 +1,45 @@
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::{
+    ops::{Bounded, CheckedAdd, CheckedSub, One, Zero},
+    FixedPoint,
+};
+
+/// Generates random bits of the inner integer, but a quarter of values are picked
+/// from edge cases (`MIN`, `MAX`, `ZERO`, `ONE` and their neighbours), which random bits
+/// almost never hit.
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a, I, P> Arbitrary<'a> for FixedPoint<I, P>
+where
+    I: Arbitrary<'a> + One,
+    Self: Copy + Bounded + Zero + One + CheckedAdd<Output = Self> + CheckedSub<Output = Self>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1u8, 4u8)? {
+            let epsilon = Self::from_bits(I::ONE);
+            // Values that can't be represented (e.g. `-EPSILON` of unsigned layouts) turn into
+            // `ZERO`, slightly increasing its chance.
+            let edge_cases = [
+                Self::MIN,
+                Self::MIN.cadd(epsilon).unwrap_or(Self::ZERO),
+                Self::ZERO.csub(epsilon).unwrap_or(Self::ZERO),
+                Self::ZERO,
+                epsilon,
+                Self::ONE,
+                Self::MAX.csub(epsilon).unwrap_or(Self::ZERO),
+                Self::MAX,
+            ];
+            return u.choose(&edge_cases).copied();
+        }
+
+        I::arbitrary(u).map(Self::from_bits)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(
+            <u8 as Arbitrary>::size_hint(depth),
+            size_hint::or((1, Some(1)), I::size_hint(depth)),
+        )
+    }
+}
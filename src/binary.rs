@@ -0,0 +1,391 @@
+//! Binary scaled fixed point numbers.
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use typenum::Unsigned;
+
+#[cfg(feature = "i128")]
+use crate::i256::I256;
+use crate::ops::*;
+use crate::{const_fn, ArithmeticError, ConvertError, Result};
+
+/// Fixed point number scaled by `2 ^ FRAC_BITS` instead of `10 ^ PRECISION`.
+///
+/// The internal representation is an integer value pre-multiplied by `2 ^ FRAC_BITS`,
+/// so multiplication and division by the scale reduce to shifts. It implements the same
+/// [`ops`][ops] traits as [`FixedPoint`][FixedPoint] with the same rounding and error model,
+/// but decimal fractions like `0.1` aren't exactly representable. Use it when performance
+/// matters more than decimal exactness (e.g. games, DSP).
+///
+/// `FRAC_BITS` must be less than the number of bits of the layout minus one, so that `ONE` is
+/// representable. Otherwise, using the type fails to compile.
+///
+/// ```ignore
+/// use core::convert::TryFrom;
+/// use fixnum::{BinFixedPoint, typenum::U16, ops::{One, RoundingMul, RoundingDiv, RoundMode::*}};
+///
+/// type Q16 = BinFixedPoint<i32, U16>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = Q16::try_from(3)?;
+/// let b = Q16::ONE.rdiv(Q16::try_from(4)?, Floor)?;
+/// assert_eq!(a.rmul(b, Floor)?.to_string(), "0.75");
+/// assert_eq!(Q16::ONE.rdiv(a, Floor)?.into_bits(), 21845);
+/// assert_eq!(Q16::EPSILON.to_string(), "0.0000152587890625");
+/// # Ok(()) }
+/// ```
+///
+/// [FixedPoint]: ./struct.FixedPoint.html
+/// [ops]: ./ops/index.html
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "i128", feature = "i64", feature = "i32", feature = "i16")))
+)]
+pub struct BinFixedPoint<I, F> {
+    inner: I,
+    _marker: PhantomData<F>,
+}
+
+impl<I, F> BinFixedPoint<I, F> {
+    pub const fn from_bits(raw: I) -> Self {
+        BinFixedPoint {
+            inner: raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn as_bits(&self) -> &I {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn into_bits(self) -> I {
+        self.inner
+    }
+}
+
+macro_rules! impl_bin_fixed_point {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+        convert = $convert:expr;
+        try_from = [$($try_from:ty),*];
+    ) => {
+        $(#[$attr])?
+        impl<F: Unsigned> BinFixedPoint<$layout, F> {
+            pub const FRAC_BITS: u32 = F::U32;
+            pub const EPSILON: Self = Self::from_bits(1);
+
+            const COEF: $layout = const_fn::checked(
+                1 << Self::FRAC_BITS,
+                (Self::FRAC_BITS as usize) < 8 * core::mem::size_of::<$layout>() - 1,
+            ) as _;
+            const COEF_PROMOTED: $promotion = $convert(Self::COEF) as _;
+
+            /// Returns the distance between adjacent values, i.e. [`EPSILON`][EPSILON].
+            ///
+            /// [EPSILON]: #associatedconstant.EPSILON
+            #[inline]
+            pub const fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            /// Checks whether `self` is greater than zero.
+            #[inline]
+            pub const fn is_positive(self) -> bool {
+                self.inner > 0
+            }
+
+            /// Checks whether `self` is less than zero.
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                self.inner < 0
+            }
+
+            /// Checks whether `self` is zero.
+            #[inline]
+            pub const fn is_zero(self) -> bool {
+                self.inner == 0
+            }
+
+            /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn cneg(self) -> Result<Self> {
+                self.inner
+                    .checked_neg()
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Returns the absolute value. Returns `Err` on overflow (for [`MIN` value][MIN]).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn abs(self) -> Result<Self> {
+                self.inner
+                    .checked_abs()
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Takes [rounded][RoundMode] integral part of the number.
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn integral(self, mode: RoundMode) -> $layout {
+                let result = self.inner / Self::COEF;
+                let loss = self.inner - result * Self::COEF;
+
+                if loss != 0 && mode.is_away_from_zero(self.inner.signum() as i32, loss, Self::COEF) {
+                    // Can't overflow, because the integral part is less than `MAX / COEF`.
+                    result + self.inner.signum()
+                } else {
+                    result
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> Zero for BinFixedPoint<$layout, F> {
+            const ZERO: Self = Self::from_bits(0);
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> One for BinFixedPoint<$layout, F> {
+            const ONE: Self = Self::from_bits(Self::COEF);
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> Bounded for BinFixedPoint<$layout, F> {
+            const MIN: Self = Self::from_bits($layout::MIN);
+            const MAX: Self = Self::from_bits($layout::MAX);
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> RoundingMul for BinFixedPoint<$layout, F> {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                let value = $promotion::from(self.inner) * $promotion::from(rhs.inner);
+                // Division by a power of two, which is replaced by shifts for primitive types.
+                let result = value / Self::COEF_PROMOTED;
+                let loss = value - result * Self::COEF_PROMOTED;
+                let sign = self.inner.signum() * rhs.inner.signum();
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != $convert(0) && mode.is_away_from_zero(sign as i32, loss, Self::COEF_PROMOTED) {
+                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> RoundingDiv for BinFixedPoint<$layout, F> {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let numerator = $promotion::from(self.inner) * Self::COEF_PROMOTED;
+                let denominator = $promotion::from(rhs.inner);
+                let result = numerator / denominator;
+                let loss = numerator - result * denominator;
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != $convert(0) {
+                    let sign = self.inner.signum() * rhs.inner.signum();
+
+                    if mode.is_away_from_zero(sign as i32, loss, denominator) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> CheckedAdd for BinFixedPoint<$layout, F> {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                self.inner
+                    .checked_add(rhs.inner)
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_add(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> CheckedSub for BinFixedPoint<$layout, F> {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                self.inner
+                    .checked_sub(rhs.inner)
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_sub(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> CheckedMul<$layout> for BinFixedPoint<$layout, F> {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: $layout) -> Result<Self> {
+                self.inner
+                    .checked_mul(rhs)
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: $layout) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_mul(rhs))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> CheckedMul<BinFixedPoint<$layout, F>> for $layout {
+            type Output = BinFixedPoint<$layout, F>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: BinFixedPoint<$layout, F>) -> Result<Self::Output> {
+                rhs.cmul(self)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: BinFixedPoint<$layout, F>) -> Self::Output {
+                Self::Output::from_bits(self.saturating_mul(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> fmt::Debug for BinFixedPoint<$layout, F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self)
+            }
+        }
+
+        /// Prints the exact decimal expansion, which has at most `FRAC_BITS` fractional digits.
+        $(#[$attr])?
+        impl<F: Unsigned> fmt::Display for BinFixedPoint<$layout, F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // The magnitude of `MIN` fits the promoted type.
+                let mut magnitude = $promotion::from(self.inner);
+                if self.inner < 0 {
+                    magnitude = -magnitude;
+                    f.write_str("-")?;
+                }
+
+                let integral = magnitude / Self::COEF_PROMOTED;
+                let mut fractional = magnitude - integral * Self::COEF_PROMOTED;
+                write!(f, "{}.", integral)?;
+
+                if fractional == $convert(0) {
+                    return f.write_str("0");
+                }
+
+                // Every step removes one binary digit, so the loop ends after `FRAC_BITS` steps.
+                let ten = $convert(10);
+                while fractional != $convert(0) {
+                    fractional *= ten;
+                    let digit = fractional / Self::COEF_PROMOTED;
+                    fractional -= digit * Self::COEF_PROMOTED;
+                    write!(f, "{}", digit)?;
+                }
+                Ok(())
+            }
+        }
+
+        $(#[$attr])?
+        impl<F: Unsigned> From<BinFixedPoint<$layout, F>> for f64 {
+            fn from(value: BinFixedPoint<$layout, F>) -> Self {
+                // Scaling by a power of two is exact.
+                value.inner as f64 / BinFixedPoint::<$layout, F>::COEF as f64
+            }
+        }
+
+        $(
+            impl<F: Unsigned> TryFrom<$try_from> for BinFixedPoint<$layout, F> {
+                type Error = ConvertError;
+
+                fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                    $layout::try_from(value)
+                        .ok()
+                        .and_then(|value| value.checked_mul(Self::COEF))
+                        .map(Self::from_bits)
+                        .ok_or(ConvertError::new("too big number"))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_bin_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
+    inner = i16;
+    promoted_to = i32;
+    convert = crate::identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "i32")]
+impl_bin_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
+    inner = i32;
+    promoted_to = i64;
+    convert = crate::identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "i64")]
+impl_bin_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
+    inner = i64;
+    promoted_to = i128;
+    convert = crate::identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "i128")]
+impl_bin_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+    inner = i128;
+    promoted_to = I256;
+    convert = I256::from_i128;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
@@ -0,0 +1,41 @@
+use core::result::Result;
+
+use bincode::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+use crate::FixedPoint;
+
+/// Encodes the inner representation, e.g. `1500000000i64` for `1.5` of `FixedPoint<i64, U9>`,
+/// according to the integer encoding of the configuration.
+/// The precision isn't encoded, it's implied by the type.
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<I: Encode, P> Encode for FixedPoint<I, P> {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.inner.encode(encoder)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<Context, I: Decode<Context>, P> Decode<Context> for FixedPoint<I, P> {
+    #[inline]
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        I::decode(decoder).map(Self::from_bits)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<'de, Context, I: BorrowDecode<'de, Context>, P> BorrowDecode<'de, Context>
+    for FixedPoint<I, P>
+{
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        I::borrow_decode(decoder).map(Self::from_bits)
+    }
+}
@@ -0,0 +1,24 @@
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::FixedPoint;
+
+/// Encodes the inner representation, e.g. `1500000000i64` for `1.5` of `FixedPoint<i64, U9>`.
+/// The precision isn't encoded, it's implied by the type.
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<I: BorshSerialize, P> BorshSerialize for FixedPoint<I, P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.inner.serialize(writer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<I: BorshDeserialize, P> BorshDeserialize for FixedPoint<I, P> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        I::deserialize_reader(reader).map(Self::from_bits)
+    }
+}
@@ -0,0 +1,13 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::{FixedPoint, Precision};
+
+// SAFETY: `FixedPoint` is `#[repr(transparent)]` over `I`, so zeroed bits are valid if they're
+// valid for `I`.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+unsafe impl<I: Zeroable, P> Zeroable for FixedPoint<I, P> {}
+
+// SAFETY: the layout is the one of `I` (without padding and with any bit pattern allowed),
+// the marker of `P` is zero-sized.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+unsafe impl<I: Pod, P: Precision> Pod for FixedPoint<I, P> {}
@@ -0,0 +1,53 @@
+//! Precision given by a const generic parameter instead of a `typenum` type.
+//!
+//! ```ignore
+//! use fixnum::{cg, ops::{RoundingMul, RoundMode::*}};
+//!
+//! type Amount = cg::FixedPoint<i64, 9>;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let a: Amount = "0.5".parse()?;
+//! assert_eq!(a.rmul(a, Floor)?, "0.25".parse()?);
+//! assert_eq!(Amount::PRECISION, 9);
+//! # Ok(()) }
+//! ```
+
+use crate::Precision;
+
+/// Number of decimal places as a const generic parameter.
+///
+/// It's mapped to the `typenum` type of the same value by [`ToTypenum`][ToTypenum], so
+/// `cg::FixedPoint<i64, 9>` is the same type as `FixedPoint<i64, typenum::U9>` and all
+/// the same operations are available. Up to 38 places are supported.
+///
+/// [ToTypenum]: ./trait.ToTypenum.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Places<const P: u32>;
+
+/// Maps [`Places`][Places] to the `typenum` type of the same value.
+///
+/// [Places]: ./struct.Places.html
+pub trait ToTypenum {
+    type Typenum: Precision;
+}
+
+macro_rules! impl_to_typenum {
+    ($( $places:literal => $typenum:ident ),* $(,)?) => {
+        $(
+            impl ToTypenum for Places<$places> {
+                type Typenum = typenum::$typenum;
+            }
+        )*
+    };
+}
+
+impl_to_typenum!(
+    0 => U0, 1 => U1, 2 => U2, 3 => U3, 4 => U4, 5 => U5, 6 => U6, 7 => U7, 8 => U8, 9 => U9,
+    10 => U10, 11 => U11, 12 => U12, 13 => U13, 14 => U14, 15 => U15, 16 => U16, 17 => U17,
+    18 => U18, 19 => U19, 20 => U20, 21 => U21, 22 => U22, 23 => U23, 24 => U24, 25 => U25,
+    26 => U26, 27 => U27, 28 => U28, 29 => U29, 30 => U30, 31 => U31, 32 => U32, 33 => U33,
+    34 => U34, 35 => U35, 36 => U36, 37 => U37, 38 => U38,
+);
+
+/// `FixedPoint` with `P` decimal places, e.g. `cg::FixedPoint<i64, 9>`.
+pub type FixedPoint<I, const P: u32> = crate::FixedPoint<I, <Places<P> as ToTypenum>::Typenum>;
@@ -1,6 +1,6 @@
-#[cfg(not(feature = "i128"))]
+#[cfg(not(any(feature = "i128", feature = "i256", feature = "u128")))]
 pub type Int = i64;
-#[cfg(feature = "i128")]
+#[cfg(any(feature = "i128", feature = "i256", feature = "u128"))]
 pub type Int = i128;
 
 macro_rules! const_assert {
@@ -11,6 +11,13 @@ macro_rules! const_assert {
     };
 }
 
+/// Returns `value` if `is_valid`, otherwise stops const evaluation, so a constant defined this
+/// way fails to compile instead of holding a wrong value.
+/// Indexing out of bounds is the only way to do it on old compilers.
+pub const fn checked(value: Int, is_valid: bool) -> Int {
+    [value][!is_valid as usize]
+}
+
 pub const fn pow10(power: i32) -> Int {
     const POW_10: [Int; 19] = [
         1,
@@ -0,0 +1,195 @@
+//! [`diesel`](https://docs.rs/diesel) support mapping `FixedPoint` to `NUMERIC`/`DECIMAL`.
+//!
+//! Reading fails if the value can't be represented exactly: it has nonzero digits beyond
+//! `PRECISION` or is out of range. Trailing zeros (e.g. of `NUMERIC(20, 12)`) are fine.
+
+use core::{convert::TryFrom, fmt::Write as _, str};
+use std::{io::Write as _, string::String, vec::Vec};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    mysql::{Mysql, MysqlType, MysqlValue},
+    pg::{data_types::PgNumeric, Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Numeric,
+};
+
+use crate::{ConvertError, FixedPoint, Precision};
+
+/// Derives `AsExpression<Numeric>` and `Queryable` for `FixedPoint` itself.
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive, sql_type = Numeric)]
+#[allow(dead_code)]
+struct FixedPointProxy<I, P>(FixedPoint<I, P>);
+
+/// Parses a decimal ignoring trailing zeros of the fractional part.
+fn parse_decimal<F: str::FromStr<Err = ConvertError>>(str: &str) -> Result<F, ConvertError> {
+    let str = if str.contains('.') {
+        str.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        str
+    };
+    str.parse()
+}
+
+/// Formats base-10000 digits of `PgNumeric` as a decimal.
+fn format_pg_numeric(is_negative: bool, weight: i16, digits: &[i16]) -> String {
+    let digit_at = |exponent: i32| {
+        usize::try_from(i32::from(weight) - exponent)
+            .ok()
+            .and_then(|index| digits.get(index).copied())
+            .unwrap_or(0)
+    };
+
+    let mut str = String::new();
+    if is_negative {
+        str.push('-');
+    }
+
+    str.push_str(if weight < 0 { "0" } else { "" });
+    for exponent in (0..=i32::from(weight)).rev() {
+        if exponent == i32::from(weight) {
+            let _ = write!(str, "{}", digit_at(exponent));
+        } else {
+            let _ = write!(str, "{:04}", digit_at(exponent));
+        }
+    }
+
+    let last_exponent = i32::from(weight) + 1 - digits.len() as i32;
+    if last_exponent < 0 {
+        str.push('.');
+        for exponent in (last_exponent..0).rev() {
+            let _ = write!(str, "{:04}", digit_at(exponent));
+        }
+    }
+
+    str
+}
+
+macro_rules! impl_diesel {
+    ($layout:ty) => {
+        impl_diesel!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> From<FixedPoint<$layout, P>> for PgNumeric {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let coef = FixedPoint::<$layout, P>::COEF as u128;
+                let precision = FixedPoint::<$layout, P>::PRECISION as u32;
+                let abs = u128::from(value.inner.unsigned_abs());
+                let (mut integral, mut fractional) = (abs / coef, abs % coef);
+
+                // Digits are stored in base 10000, most significant first.
+                let mut digits = Vec::new();
+                while integral > 0 {
+                    digits.push((integral % 10_000) as i16);
+                    integral /= 10_000;
+                }
+                let mut weight = digits.len() as i16 - 1;
+                digits.reverse();
+
+                // The last fractional digit is padded with zeros on the right.
+                let groups = precision.div_ceil(4);
+                let fractional_start = digits.len();
+                if groups > 0 {
+                    let last_len = precision - 4 * (groups - 1);
+                    let last_coef = 10u128.pow(last_len);
+                    digits.push((fractional % last_coef * 10u128.pow(4 - last_len)) as i16);
+                    fractional /= last_coef;
+                    for _ in 1..groups {
+                        digits.push((fractional % 10_000) as i16);
+                        fractional /= 10_000;
+                    }
+                    digits[fractional_start..].reverse();
+                }
+
+                // Normalize as Postgres does: no leading and trailing zero digits.
+                let leading = digits.iter().take_while(|digit| **digit == 0).count();
+                digits.drain(..leading);
+                weight -= leading as i16;
+                while digits.last() == Some(&0) {
+                    digits.pop();
+                }
+                if digits.is_empty() {
+                    weight = 0;
+                }
+
+                let scale = precision as u16;
+                if value.inner < 0 {
+                    PgNumeric::Negative { weight, scale, digits }
+                } else {
+                    PgNumeric::Positive { weight, scale, digits }
+                }
+            }
+        }
+
+        /// Fails if the value has nonzero digits beyond `PRECISION`, is out of range or `NaN`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> TryFrom<PgNumeric> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            fn try_from(numeric: PgNumeric) -> Result<Self, ConvertError> {
+                let str = match numeric {
+                    PgNumeric::Positive { weight, digits, .. } => {
+                        format_pg_numeric(false, weight, &digits)
+                    }
+                    PgNumeric::Negative { weight, digits, .. } => {
+                        format_pg_numeric(true, weight, &digits)
+                    }
+                    PgNumeric::NaN => return Err(ConvertError::new("NaN can't be represented")),
+                };
+                parse_decimal(&str)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> ToSql<Numeric, Pg> for FixedPoint<$layout, P> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                let numeric = PgNumeric::from(*self);
+                ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> FromSql<Numeric, Pg> for FixedPoint<$layout, P> {
+            fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+                let numeric = PgNumeric::from_sql(value)?;
+                Ok(Self::try_from(numeric)?)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> ToSql<Numeric, Mysql> for FixedPoint<$layout, P> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+                write!(out, "{}", self).map(|_| IsNull::No).map_err(Into::into)
+            }
+        }
+
+        /// Accepts `DECIMAL` values only.
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        $(#[$attr])?
+        impl<P: Precision> FromSql<Numeric, Mysql> for FixedPoint<$layout, P> {
+            fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+                match value.value_type() {
+                    MysqlType::Numeric => Ok(parse_decimal(str::from_utf8(value.as_bytes())?)?),
+                    other => Err(format!("expected DECIMAL, got {:?}", other).into()),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_diesel!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_diesel!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_diesel!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_diesel!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
@@ -0,0 +1,437 @@
+//! Fixed point numbers with the precision known only at runtime.
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "i128")]
+use crate::i256::I256;
+use crate::ops::*;
+use crate::{ArithmeticError, ConvertError, FixedPoint, Precision, Result};
+
+/// Fixed point decimal number carrying the number of decimal places at runtime.
+///
+/// Useful when the precision comes from a database schema or an instrument definition.
+/// Arithmetic requires both operands to have the same precision and returns
+/// `DomainViolation` otherwise, use [`rescale`][rescale] to align them explicitly.
+///
+/// Values are compared structurally, so `1.5` with precision `1` isn't equal to `1.50`
+/// with precision `2`.
+///
+/// ```ignore
+/// use core::convert::TryFrom;
+/// use fixnum::{DynFixedPoint, FixedPoint, typenum::U2, ops::{CheckedAdd, RoundMode::*}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a: DynFixedPoint<i64> = "1.50".parse()?;
+/// let b: DynFixedPoint<i64> = "0.125".parse()?;
+/// assert_eq!(a.precision(), 2);
+/// assert!(a.cadd(b).is_err());
+/// assert_eq!(a.cadd(b.rescale(2, Floor)?)?.to_string(), "1.62");
+/// assert_eq!(FixedPoint::<i64, U2>::try_from(a)?, "1.5".parse()?);
+/// # Ok(()) }
+/// ```
+///
+/// [rescale]: #method.rescale
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "i128", feature = "i64", feature = "i32", feature = "i16")))
+)]
+pub struct DynFixedPoint<I> {
+    inner: I,
+    precision: u8,
+}
+
+impl<I> DynFixedPoint<I> {
+    /// Returns the number of decimal places.
+    #[inline]
+    pub const fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub const fn as_bits(&self) -> &I {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn into_bits(self) -> I {
+        self.inner
+    }
+}
+
+macro_rules! impl_dyn_fixed_point {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+        convert = $convert:expr;
+        max_precision = $max_precision:literal;
+    ) => {
+        $(#[$attr])?
+        impl DynFixedPoint<$layout> {
+            /// The largest precision, for which `ONE` is representable.
+            pub const MAX_PRECISION: u8 = $max_precision;
+
+            /// Creates a value from the raw representation, i.e. the number multiplied by
+            /// `10 ^ precision`. Returns `Err` if the precision exceeds
+            /// [`MAX_PRECISION`][MAX_PRECISION].
+            ///
+            /// [MAX_PRECISION]: #associatedconstant.MAX_PRECISION
+            pub fn from_bits(raw: $layout, precision: u8) -> Result<Self, ConvertError> {
+                if precision > Self::MAX_PRECISION {
+                    return Err(ConvertError::new("requested precision is too high"));
+                }
+                Ok(Self {
+                    inner: raw,
+                    precision,
+                })
+            }
+
+            /// Creates an integer value with the given precision.
+            pub fn from_integer(value: $layout, precision: u8) -> Result<Self, ConvertError> {
+                let zero = Self::from_bits(0, precision)?;
+                value
+                    .checked_mul(zero.coef())
+                    .map(|inner| Self { inner, ..zero })
+                    .ok_or(ConvertError::new("too big number"))
+            }
+
+            #[inline]
+            fn coef(self) -> $layout {
+                (10 as $layout).pow(u32::from(self.precision))
+            }
+
+            #[inline]
+            fn with_bits(self, inner: $layout) -> Self {
+                Self { inner, ..self }
+            }
+
+            #[inline]
+            fn check_precision(self, rhs: Self) -> Result<()> {
+                if self.precision == rhs.precision {
+                    Ok(())
+                } else {
+                    Err(ArithmeticError::DomainViolation)
+                }
+            }
+
+            /// Checks whether `self` is greater than zero.
+            #[inline]
+            pub const fn is_positive(self) -> bool {
+                self.inner > 0
+            }
+
+            /// Checks whether `self` is less than zero.
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                self.inner < 0
+            }
+
+            /// Checks whether `self` is zero.
+            #[inline]
+            pub const fn is_zero(self) -> bool {
+                self.inner == 0
+            }
+
+            /// Checked negation. Returns `Err` on overflow (you can't negate the minimal value).
+            #[inline]
+            pub fn cneg(self) -> Result<Self> {
+                self.inner
+                    .checked_neg()
+                    .map(|inner| self.with_bits(inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Returns the absolute value. Returns `Err` on overflow (for the minimal value).
+            #[inline]
+            pub fn abs(self) -> Result<Self> {
+                self.inner
+                    .checked_abs()
+                    .map(|inner| self.with_bits(inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Converts to another precision, [rounding][RoundMode] the value when the precision
+            /// decreases. Returns `Err` on overflow or if the precision exceeds
+            /// [`MAX_PRECISION`][MAX_PRECISION] (as `DomainViolation`).
+            ///
+            /// [MAX_PRECISION]: #associatedconstant.MAX_PRECISION
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rescale(self, precision: u8, mode: RoundMode) -> Result<Self> {
+                let (inner, _) = self.rescale_bits(precision, mode)?;
+                Ok(Self { inner, precision })
+            }
+
+            /// Returns the rescaled bits and whether the value has been rounded.
+            fn rescale_bits(self, precision: u8, mode: RoundMode) -> Result<($layout, bool)> {
+                if precision > Self::MAX_PRECISION {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                if precision >= self.precision {
+                    let coef = (10 as $layout).pow(u32::from(precision - self.precision));
+                    return self
+                        .inner
+                        .checked_mul(coef)
+                        .map(|inner| (inner, false))
+                        .ok_or(ArithmeticError::Overflow);
+                }
+
+                let coef = (10 as $layout).pow(u32::from(self.precision - precision));
+                let result = self.inner / coef;
+                let loss = self.inner - result * coef;
+                let sign = self.inner.signum();
+
+                if loss != 0 && mode.is_away_from_zero(sign as i32, loss, coef) {
+                    // Can't overflow, because the magnitude has decreased at least tenfold.
+                    Ok((result + sign, true))
+                } else {
+                    Ok((result, loss != 0))
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl CheckedAdd for DynFixedPoint<$layout> {
+            type Output = DynFixedPoint<$layout>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                self.check_precision(rhs)?;
+                self.inner
+                    .checked_add(rhs.inner)
+                    .map(|inner| self.with_bits(inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl CheckedSub for DynFixedPoint<$layout> {
+            type Output = DynFixedPoint<$layout>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                self.check_precision(rhs)?;
+                self.inner
+                    .checked_sub(rhs.inner)
+                    .map(|inner| self.with_bits(inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl CheckedMul<$layout> for DynFixedPoint<$layout> {
+            type Output = DynFixedPoint<$layout>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: $layout) -> Result<Self> {
+                self.inner
+                    .checked_mul(rhs)
+                    .map(|inner| self.with_bits(inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl RoundingMul for DynFixedPoint<$layout> {
+            type Output = DynFixedPoint<$layout>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                self.check_precision(rhs)?;
+
+                let coef = $promotion::from(self.coef());
+                let value = $promotion::from(self.inner) * $promotion::from(rhs.inner);
+                let result = value / coef;
+                let loss = value - result * coef;
+                let sign = self.inner.signum() * rhs.inner.signum();
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != $convert(0) && mode.is_away_from_zero(sign as i32, loss, coef) {
+                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                Ok(self.with_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl RoundingDiv for DynFixedPoint<$layout> {
+            type Output = DynFixedPoint<$layout>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                self.check_precision(rhs)?;
+
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let numerator = $promotion::from(self.inner) * $promotion::from(self.coef());
+                let denominator = $promotion::from(rhs.inner);
+                let result = numerator / denominator;
+                let loss = numerator - result * denominator;
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != $convert(0) {
+                    let sign = self.inner.signum() * rhs.inner.signum();
+
+                    if mode.is_away_from_zero(sign as i32, loss, denominator) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(self.with_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl fmt::Debug for DynFixedPoint<$layout> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self)
+            }
+        }
+
+        /// Prints exactly `precision` fractional digits, so the output round-trips through
+        /// `FromStr` with the same precision.
+        $(#[$attr])?
+        impl fmt::Display for DynFixedPoint<$layout> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coef = self.coef().unsigned_abs();
+                let magnitude = self.inner.unsigned_abs();
+                let sign = if self.is_negative() { "-" } else { "" };
+                let integral = magnitude / coef;
+
+                if self.precision == 0 {
+                    return write!(f, "{}{}", sign, integral);
+                }
+
+                write!(
+                    f,
+                    "{}{}.{:0width$}",
+                    sign,
+                    integral,
+                    magnitude % coef,
+                    width = self.precision as usize
+                )
+            }
+        }
+
+        /// Parses a decimal number, the precision is the number of fractional digits.
+        $(#[$attr])?
+        impl FromStr for DynFixedPoint<$layout> {
+            type Err = ConvertError;
+
+            fn from_str(str: &str) -> Result<Self, Self::Err> {
+                let str = str.trim();
+                let (integral_str, fractional_str) = match str.find('.') {
+                    Some(index) => (&str[..index], &str[index + 1..]),
+                    None => (str, ""),
+                };
+
+                if !fractional_str.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(ConvertError::new(
+                        "can't parse fractional part: must contain digits only",
+                    ));
+                }
+
+                let precision = u8::try_from(fractional_str.len())
+                    .map_err(|_| ConvertError::new("requested precision is too high"))?;
+                let zero = Self::from_bits(0, precision)?;
+
+                // The sign is taken from the integral part, which may be `-0`.
+                let is_negative = integral_str.starts_with('-');
+                let integral: $layout = integral_str
+                    .parse()
+                    .map_err(|_| ConvertError::new("can't parse integral part"))?;
+                let fractional: $layout = if fractional_str.is_empty() {
+                    0
+                } else {
+                    fractional_str
+                        .parse()
+                        .map_err(|_| ConvertError::new("can't parse fractional part"))?
+                };
+                let fractional = if is_negative { -fractional } else { fractional };
+
+                integral
+                    .checked_mul(zero.coef())
+                    .and_then(|inner| inner.checked_add(fractional))
+                    .map(|inner| zero.with_bits(inner))
+                    .ok_or(ConvertError::new("too big number"))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> From<FixedPoint<$layout, P>> for DynFixedPoint<$layout> {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                Self {
+                    inner: value.into_bits(),
+                    precision: P::I32 as u8,
+                }
+            }
+        }
+
+        /// Converts to the static precision `P`, failing if the value doesn't fit or has
+        /// more significant decimal places than `P`.
+        $(#[$attr])?
+        impl<P: Precision> TryFrom<DynFixedPoint<$layout>> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            fn try_from(value: DynFixedPoint<$layout>) -> Result<Self, Self::Error> {
+                let precision = u8::try_from(P::I32)
+                    .map_err(|_| ConvertError::new("requested precision is too high"))?;
+                match value.rescale_bits(precision, RoundMode::TowardZero) {
+                    Ok((inner, false)) => Ok(Self::from_bits(inner)),
+                    Ok((_, true)) => Err(ConvertError::new("requested precision is too low")),
+                    Err(ArithmeticError::DomainViolation) => {
+                        Err(ConvertError::new("requested precision is too high"))
+                    }
+                    Err(_) => Err(ConvertError::new("too big number")),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
+    inner = i16;
+    promoted_to = i32;
+    convert = crate::identity;
+    max_precision = 4;
+);
+#[cfg(feature = "i32")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
+    inner = i32;
+    promoted_to = i64;
+    convert = crate::identity;
+    max_precision = 9;
+);
+#[cfg(feature = "i64")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
+    inner = i64;
+    promoted_to = i128;
+    convert = crate::identity;
+    max_precision = 18;
+);
+#[cfg(feature = "i128")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+    inner = i128;
+    promoted_to = I256;
+    convert = I256::from_i128;
+    max_precision = 38;
+);
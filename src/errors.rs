@@ -11,10 +11,13 @@ macro_rules! impl_error {
             }
         }
 
+        impl_error!(@anyhow $err);
+    };
+    (@anyhow $err:ident) => {
         #[cfg(test)]
         impl From<$err> for anyhow::Error {
             fn from(err: $err) -> Self {
-                Self::msg(err.as_str())
+                Self::msg(err)
             }
         }
     };
@@ -47,16 +50,45 @@ impl_error!(ArithmeticError);
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConvertError {
     reason: &'static str,
+    index: Option<usize>,
 }
 
 impl ConvertError {
     pub(crate) fn new(reason: &'static str) -> Self {
-        Self { reason }
+        Self {
+            reason,
+            index: None,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_index(self, index: usize) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
     }
 
     pub const fn as_str(&self) -> &'static str {
         self.reason
     }
+
+    /// Index of the element that failed to convert, if the error comes from a list.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl Display for ConvertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.as_str())?;
+
+        if let Some(index) = self.index {
+            write!(f, " (at index {})", index)?;
+        }
+
+        Ok(())
+    }
 }
 
-impl_error!(ConvertError);
+impl_error!(@anyhow ConvertError);
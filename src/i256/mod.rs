@@ -1,8 +1,13 @@
 use core::cmp::{Ordering, PartialOrd};
-use core::convert::TryFrom;
-use core::ops::{Div, Mul, Neg, Sub};
-
-use crate::ops::sqrt::Sqrt;
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+#[cfg(feature = "i256")]
+use crate::ops::{Bounded, CheckedAdd, CheckedMul, CheckedSub, RoundingDiv};
 use crate::ops::{One, RoundMode, RoundingSqrt, Zero};
 use crate::{ArithmeticError, ConvertError};
 
@@ -13,119 +18,358 @@ const SIGN_MASK: u64 = 1 << (UINT_CHUNK_BITS_COUNT - 1); // MSB = 1, other are e
 
 mod u256;
 
-use u256::U256;
-
-/// Signed 256-bit number. Works on top of U256 with help of two's complement.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) use u256::U256;
+#[cfg(feature = "i256")]
+use u256::U512;
+
+/// Signed 256-bit integer. Works on top of U256 with help of two's complement.
+///
+/// Used internally for promoting `i128` values, and published for users who need wide
+/// intermediate integer math. Arithmetic operators panic on overflow and division by zero, use
+/// `checked_*` methods to handle these cases.
+///
+/// ```ignore
+/// use fixnum::I256;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = I256::from(i128::MAX);
+/// let b: I256 = "-170141183460469231731687303715884105727".parse()?;
+/// assert_eq!((a * a / b).to_string(), "-170141183460469231731687303715884105727");
+/// assert_eq!(a.checked_mul(a * a), None);
+/// assert!(i128::try_from(a * a).is_err());
+/// # Ok(()) }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct I256 {
     inner: U256,
 }
 
-impl I256 {
-    pub const I128_MAX: Self = Self::from_i128(i128::MAX);
-    pub const I128_MIN: Self = Self::from_i128(i128::MIN);
-    pub const U128_MAX: Self = Self::new(U256([u64::MAX, u64::MAX, 0, 0]));
-    pub const MAX: Self = Self::new(U256([u64::MAX, u64::MAX, u64::MAX, !SIGN_MASK]));
-    pub const MIN: Self = Self::new(U256([0, 0, 0, SIGN_MASK]));
+/// Signed 512-bit integer, the promotion of the `FixedPoint<I256, P>` layout.
+#[cfg(feature = "i256")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct I512 {
+    inner: U512,
+}
 
-    const fn new(x: U256) -> Self {
-        I256 { inner: x }
-    }
+/// Implements arithmetic of a signed integer on top of the unsigned `$uint` of `$n_words` words.
+macro_rules! impl_signed {
+    ($(#[$attr:meta])* $vis:vis $name:ident($uint:ident, $n_words:tt)) => {
+        $(#[$attr])*
+        impl $name {
+            $vis const MAX: Self = {
+                let mut words = [u64::MAX; $n_words];
+                words[$n_words - 1] = !SIGN_MASK;
+                Self::new($uint(words))
+            };
+            $vis const MIN: Self = {
+                let mut words = [0; $n_words];
+                words[$n_words - 1] = SIGN_MASK;
+                Self::new($uint(words))
+            };
+
+            const fn new(x: $uint) -> Self {
+                $name { inner: x }
+            }
 
-    pub const fn from_i128(x: i128) -> Self {
-        let msb = if x < 0 { u64::MAX } else { 0 };
-        Self::new(U256([x as u64, (x >> 64) as u64, msb, msb])) // The only way to do it const
-    }
+            /// Checked addition. Returns `None` on overflow.
+            $vis fn checked_add(self, rhs: Self) -> Option<Self> {
+                let (x, _) = self.inner.overflowing_add(rhs.inner);
+                let result = Self::new(x);
+                // Overflow takes place only if both operands have the same sign, that differs from the result.
+                if self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative() {
+                    None
+                } else {
+                    Some(result)
+                }
+            }
 
-    const fn is_negative(self) -> bool {
-        let most_significant_chunk: u64 = self.chunks()[UINT_CHUNKS_COUNT - 1];
-        most_significant_chunk & SIGN_MASK != 0
-    }
+            /// Checked subtraction. Returns `None` on overflow.
+            $vis fn checked_sub(self, rhs: Self) -> Option<Self> {
+                let (x, _) = self.inner.overflowing_sub(rhs.inner);
+                let result = Self::new(x);
+                // Overflow takes place only if operands have different signs and the sign of the result
+                // differs from the sign of `self`.
+                if self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative() {
+                    None
+                } else {
+                    Some(result)
+                }
+            }
 
-    const fn chunks(&self) -> &[u64; UINT_CHUNKS_COUNT] {
-        &self.inner.0
-    }
-}
+            /// Checked multiplication. Returns `None` on overflow.
+            $vis fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let (magnitude, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+                if overflow {
+                    return None;
+                }
+                Self::from_magnitude(magnitude, self.is_negative() != rhs.is_negative())
+            }
 
-impl Mul for I256 {
-    type Output = Self;
+            /// Checked division, the quotient is truncated toward zero.
+            /// Returns `None` if `rhs` is zero or on overflow (`MIN / -1`).
+            $vis fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs == Self::ZERO {
+                    return None;
+                }
+                let magnitude = self.unsigned_abs() / rhs.unsigned_abs();
+                Self::from_magnitude(magnitude, self.is_negative() != rhs.is_negative())
+            }
 
-    #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        let lhs_was_negative = self.is_negative();
-        let rhs_was_negative = rhs.is_negative();
+            /// Checked remainder, the result has the sign of `self`.
+            /// Returns `None` if `rhs` is zero or on overflow (`MIN % -1`), like primitive integers.
+            $vis fn checked_rem(self, rhs: Self) -> Option<Self> {
+                if rhs == Self::ZERO || (self == Self::MIN && rhs == -Self::ONE) {
+                    return None;
+                }
+                let (_, magnitude) = self.unsigned_abs().div_mod(rhs.unsigned_abs());
+                Self::from_magnitude(magnitude, self.is_negative())
+            }
 
-        let lhs = if lhs_was_negative { -self } else { self };
-        let rhs = if rhs_was_negative { -rhs } else { rhs };
+            /// Checked negation. Returns `None` for `MIN`.
+            $vis fn checked_neg(self) -> Option<Self> {
+                if self == Self::MIN {
+                    None
+                } else {
+                    Some(-self)
+                }
+            }
 
-        // Mustn't overflow because we're usually promoting just i128 to I256.
-        let result = Self::new(lhs.inner * rhs.inner);
-        if lhs_was_negative == rhs_was_negative {
-            result
-        } else {
-            -result
+            $vis const fn is_negative(self) -> bool {
+                let most_significant_chunk: u64 = self.chunks()[$n_words - 1];
+                most_significant_chunk & SIGN_MASK != 0
+            }
+
+            /// Returns the magnitude, which fits the unsigned type even for `MIN`.
+            pub(crate) fn unsigned_abs(self) -> $uint {
+                if self.is_negative() {
+                    let (x, _) = (!self.inner).overflowing_add(Self::ONE.inner);
+                    x
+                } else {
+                    self.inner
+                }
+            }
+
+            /// Makes a number from its magnitude and sign, returns `None` if it's out of range.
+            pub(crate) fn from_magnitude(magnitude: $uint, is_negative: bool) -> Option<Self> {
+                let value = Self::new(magnitude);
+                match (is_negative, value.is_negative()) {
+                    (false, false) => Some(value),
+                    (false, true) => None,
+                    // `-MIN` is `MIN` itself.
+                    (true, true) if value == Self::MIN => Some(value),
+                    (true, true) => None,
+                    (true, false) => Some(-value),
+                }
+            }
+
+            const fn chunks(&self) -> &[u64; $n_words] {
+                &self.inner.0
+            }
         }
-    }
-}
 
-impl Div for I256 {
-    type Output = Self;
+        impl_op!(
+            $name,
+            Add,
+            AddAssign,
+            add,
+            add_assign,
+            checked_add,
+            "attempt to add with overflow"
+        );
+        impl_op!(
+            $name,
+            Sub,
+            SubAssign,
+            sub,
+            sub_assign,
+            checked_sub,
+            "attempt to subtract with overflow"
+        );
+        impl_op!(
+            $name,
+            Mul,
+            MulAssign,
+            mul,
+            mul_assign,
+            checked_mul,
+            "attempt to multiply with overflow"
+        );
+        impl_op!(
+            $name,
+            Div,
+            DivAssign,
+            div,
+            div_assign,
+            checked_div,
+            "attempt to divide by zero or with overflow"
+        );
+        impl_op!(
+            $name,
+            Rem,
+            RemAssign,
+            rem,
+            rem_assign,
+            checked_rem,
+            "attempt to calculate the remainder with a divisor of zero or with overflow"
+        );
 
-    #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        let lhs_was_negative = self.is_negative();
-        let rhs_was_negative = rhs.is_negative();
+        impl Neg for $name {
+            type Output = Self;
 
-        let lhs = if lhs_was_negative { -self } else { self };
-        let rhs = if rhs_was_negative { -rhs } else { rhs };
+            #[inline]
+            fn neg(self) -> Self::Output {
+                // Neg isn't defined for `MIN` because on two's complement we always have one extra negative value.
+                debug_assert_ne!(self, Self::MIN);
+                // Overflow takes place when we negate zero.
+                let (x, _) = (!self.inner).overflowing_add(Self::ONE.inner);
+                Self::new(x)
+            }
+        }
 
-        let result = Self::new(lhs.inner / rhs.inner);
-        if lhs_was_negative == rhs_was_negative {
-            result
-        } else {
-            -result
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (self.is_negative(), other.is_negative()) {
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    _ => self.inner.cmp(&other.inner),
+                }
+            }
         }
-    }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl One for $name {
+            const ONE: Self = Self::new($uint::ONE);
+        }
+
+        impl Zero for $name {
+            const ZERO: Self = Self::new($uint::ZERO);
+        }
+
+        impl RoundingSqrt for $name {
+            type Error = ArithmeticError;
+
+            /// Integer square root of a non-negative integer S is a non-negative integer Q such that:
+            /// Floor, TowardZero: `Q ≤ sqrt(S)`
+            /// Ceil, AwayFromZero: `Q ≥ sqrt(S)`
+            /// Nearest: `|Q - sqrt(S)| ≤ 1/2`
+            #[inline]
+            fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                if self.is_negative() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+                self.inner.rsqrt(mode).map(Self::new)
+            }
+        }
+    };
 }
 
-impl Sub for I256 {
-    type Output = Self;
+macro_rules! impl_op {
+    ($name:ident, $op:ident, $op_assign:ident, $method:ident, $method_assign:ident, $checked:ident, $msg:literal) => {
+        impl $op for $name {
+            type Output = Self;
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let (x, _) = self.inner.overflowing_sub(rhs.inner);
-        Self::new(x)
-    }
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl $op_assign for $name {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
 }
 
-impl Neg for I256 {
-    type Output = Self;
+impl_signed!(pub I256(U256, 4));
+#[cfg(feature = "i256")]
+impl_signed!(
+    // The promotion only multiplies and divides, so not all methods are used.
+    #[allow(dead_code)]
+    pub(crate) I512(U512, 8)
+);
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        // Neg isn't defined for `I256::MIN` because on two's complement we always have one extra negative value.
-        debug_assert_ne!(self, Self::MIN);
-        // Overflow takes place when we negate zero.
-        let (x, _) = (!self.inner).overflowing_add(Self::ONE.inner);
-        Self::new(x)
+impl I256 {
+    pub const I128_MAX: Self = Self::from_i128(i128::MAX);
+    pub const I128_MIN: Self = Self::from_i128(i128::MIN);
+    pub const U128_MAX: Self = Self::new(U256([u64::MAX, u64::MAX, 0, 0]));
+
+    pub const fn from_i128(x: i128) -> Self {
+        let msb = if x < 0 { u64::MAX } else { 0 };
+        Self::new(U256([x as u64, (x >> 64) as u64, msb, msb])) // The only way to do it const
+    }
+
+    /// Creates a number from its two's complement representation in little-endian byte order.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut words = [0; UINT_CHUNKS_COUNT];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self::new(U256(words))
+    }
+
+    /// Creates a number from its two's complement representation in big-endian byte order.
+    pub fn from_be_bytes(mut bytes: [u8; 32]) -> Self {
+        bytes.reverse();
+        Self::from_le_bytes(bytes)
+    }
+
+    /// Returns the two's complement representation in little-endian byte order.
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.chunks()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the two's complement representation in big-endian byte order.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+}
+impl fmt::Debug for I256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
     }
 }
 
-impl Ord for I256 {
-    #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        match (self.is_negative(), other.is_negative()) {
-            (true, false) => Ordering::Less,
-            (false, true) => Ordering::Greater,
-            _ => self.inner.cmp(&other.inner),
+impl fmt::Display for I256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            f.write_str("-")?;
         }
+        write!(f, "{}", self.unsigned_abs())
     }
 }
 
-impl PartialOrd for I256 {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl FromStr for I256 {
+    type Err = ConvertError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (is_negative, digits) = match str.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, str.strip_prefix('+').unwrap_or(str)),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ConvertError::new("can't parse integer"));
+        }
+
+        U256::from_decimal_str(digits)
+            .and_then(|magnitude| Self::from_magnitude(magnitude, is_negative))
+            .ok_or(ConvertError::new("too big integer"))
     }
 }
 
@@ -136,11 +380,11 @@ impl From<i128> for I256 {
 }
 
 impl TryFrom<I256> for i128 {
-    type Error = ArithmeticError;
+    type Error = ConvertError;
 
     fn try_from(x: I256) -> Result<Self, Self::Error> {
         if x > I256::I128_MAX || x < I256::I128_MIN {
-            return Err(ArithmeticError::Overflow);
+            return Err(ConvertError::new("too big integer"));
         }
         Ok(i128::from(x.chunks()[0]) | (i128::from(x.chunks()[1]) << 64))
     }
@@ -163,39 +407,310 @@ impl TryFrom<I256> for u128 {
     }
 }
 
-impl One for I256 {
-    const ONE: Self = Self::from_i128(1);
+macro_rules! impl_int_conversions {
+    ($($via:ty => [$($int:ty),*]),*) => {
+        $($(
+            impl From<$int> for I256 {
+                fn from(x: $int) -> Self {
+                    Self::from(<$via>::from(x))
+                }
+            }
+
+            impl TryFrom<I256> for $int {
+                type Error = ConvertError;
+
+                fn try_from(x: I256) -> Result<Self, Self::Error> {
+                    <$via>::try_from(x)
+                        .ok()
+                        .and_then(|x| <$int>::try_from(x).ok())
+                        .ok_or(ConvertError::new("too big integer"))
+                }
+            }
+        )*)*
+    };
+}
+
+impl_int_conversions!(i128 => [i8, i16, i32, i64], u128 => [u8, u16, u32, u64]);
+
+// `isize` and `usize` are at most 64 bits wide.
+impl From<isize> for I256 {
+    fn from(x: isize) -> Self {
+        Self::from_i128(x as i128)
+    }
+}
+
+impl From<usize> for I256 {
+    fn from(x: usize) -> Self {
+        Self::from(x as u128)
+    }
 }
 
-impl Zero for I256 {
-    const ZERO: Self = Self::from_i128(0);
+impl TryFrom<I256> for isize {
+    type Error = ConvertError;
+
+    fn try_from(x: I256) -> Result<Self, Self::Error> {
+        i64::try_from(x)
+            .ok()
+            .and_then(|x| isize::try_from(x).ok())
+            .ok_or(ConvertError::new("too big integer"))
+    }
+}
+
+impl TryFrom<I256> for usize {
+    type Error = ConvertError;
+
+    fn try_from(x: I256) -> Result<Self, Self::Error> {
+        u64::try_from(x)
+            .ok()
+            .and_then(|x| usize::try_from(x).ok())
+            .ok_or(ConvertError::new("too big integer"))
+    }
 }
 
-impl RoundingSqrt for I256 {
+/// Methods of primitive integers used by the `FixedPoint<I256, P>` layout.
+#[cfg(feature = "i256")]
+impl I256 {
+    pub(crate) const BITS: u32 = TOTAL_BITS_COUNT as u32;
+
+    /// Multiplies a non-negative number by `rhs` in `const` context.
+    /// Stops const evaluation on overflow, so a constant defined this way fails to compile.
+    pub(crate) const fn const_mul(self, rhs: u64) -> Self {
+        let mut words = self.inner.0;
+        let mut carry = 0;
+        let mut i = 0;
+        while i < UINT_CHUNKS_COUNT {
+            let product = words[i] as u128 * rhs as u128 + carry;
+            words[i] = product as u64;
+            carry = product >> UINT_CHUNK_BITS_COUNT;
+            i += 1;
+        }
+        let result = Self::new(U256(words));
+        [result][(carry != 0 || result.is_negative()) as usize]
+    }
+
+    pub(crate) const fn is_zero(self) -> bool {
+        let chunks = self.chunks();
+        chunks[0] == 0 && chunks[1] == 0 && chunks[2] == 0 && chunks[3] == 0
+    }
+
+    pub(crate) fn signum(self) -> Self {
+        if self.is_negative() {
+            -Self::ONE
+        } else if self == Self::ZERO {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    pub(crate) fn abs(self) -> Self {
+        if self.is_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    pub(crate) fn abs_diff(self, other: Self) -> U256 {
+        let (difference, _) = if self < other {
+            other.inner.overflowing_sub(self.inner)
+        } else {
+            self.inner.overflowing_sub(other.inner)
+        };
+        difference
+    }
+
+    pub(crate) fn wrapping_neg(self) -> Self {
+        let (x, _) = (!self.inner).overflowing_add(Self::ONE.inner);
+        Self::new(x)
+    }
+
+    pub(crate) fn leading_zeros(self) -> u32 {
+        self.inner.leading_zeros()
+    }
+
+    pub(crate) fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    pub(crate) fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+
+    pub(crate) fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (x, _) = self.inner.overflowing_add(rhs.inner);
+        (Self::new(x), self.checked_add(rhs).is_none())
+    }
+
+    pub(crate) fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (x, _) = self.inner.overflowing_sub(rhs.inner);
+        (Self::new(x), self.checked_sub(rhs).is_none())
+    }
+
+    pub(crate) fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(if rhs.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+
+    pub(crate) fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(if rhs.is_negative() {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    pub(crate) fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or(if self.is_negative() == rhs.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
+    /// Euclidean remainder for a positive `rhs`, which is all `add_mod` needs.
+    pub(crate) fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+        if remainder.is_negative() {
+            remainder + rhs
+        } else {
+            remainder
+        }
+    }
+
+    /// Lossy conversion like `as f64` for primitives.
+    pub(crate) fn to_f64(self) -> f64 {
+        let magnitude = self.unsigned_abs().to_f64();
+        if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+#[cfg(feature = "i256")]
+impl Bounded for I256 {
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+}
+
+#[cfg(feature = "i256")]
+impl CheckedAdd for I256 {
+    type Output = Self;
     type Error = ArithmeticError;
 
-    /// Integer square root of a non-negative integer S is a non-negative integer Q such that:
-    /// Floor: `Q ≤ sqrt(S)`
-    /// Ceil: `Q ≥ sqrt(S)`
     #[inline]
-    fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error> {
-        if self.is_negative() {
-            return Err(ArithmeticError::DomainViolation);
-        }
-        let lo = self.inner.sqrt()?;
-        let inner = match mode {
-            RoundMode::Floor => lo,
-            RoundMode::Ceil => {
-                if lo * lo == self.inner {
-                    lo
-                } else {
-                    // `sqrt` will always be closer to zero than `self` so overflow will never happen
-                    let (hi, _) = lo.overflowing_add(Self::ONE.inner);
-                    hi
-                }
+    fn cadd(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        self.checked_add(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self::Output {
+        I256::saturating_add(self, rhs)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl CheckedSub for I256 {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        self.checked_sub(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_sub(self, rhs: Self) -> Self::Output {
+        I256::saturating_sub(self, rhs)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl CheckedMul for I256 {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        self.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_mul(self, rhs: Self) -> Self::Output {
+        I256::saturating_mul(self, rhs)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl RoundingDiv for I256 {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self::Output, Self::Error> {
+        let mut result = self.checked_div(rhs).ok_or(if rhs == Self::ZERO {
+            ArithmeticError::DivisionByZero
+        } else {
+            ArithmeticError::Overflow
+        })?;
+        let loss = self - result * rhs;
+
+        if loss != Self::ZERO {
+            let sign = self.signum() * rhs.signum();
+            let sign_i32 = if sign.is_negative() { -1 } else { 1 };
+
+            if mode.is_away_from_zero(sign_i32, loss, rhs) {
+                result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
             }
-        };
-        Ok(Self::new(inner))
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl I512 {
+    pub(crate) const fn from_i256(x: I256) -> Self {
+        let msb = if x.is_negative() { u64::MAX } else { 0 };
+        let [w0, w1, w2, w3] = x.inner.0;
+        Self::new(U512([w0, w1, w2, w3, msb, msb, msb, msb]))
+    }
+}
+
+#[cfg(feature = "i256")]
+impl From<I256> for I512 {
+    fn from(x: I256) -> Self {
+        Self::from_i256(x)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl TryFrom<I512> for I256 {
+    type Error = ConvertError;
+
+    fn try_from(x: I512) -> Result<Self, Self::Error> {
+        U256::try_from(x.unsigned_abs())
+            .ok()
+            .and_then(|magnitude| Self::from_magnitude(magnitude, x.is_negative()))
+            .ok_or(ConvertError::new("too big integer"))
     }
 }
 
@@ -326,4 +841,104 @@ mod tests {
         t(35, 5, 7);
         t(-35, 5, -7);
     }
+
+    #[test]
+    fn it_adds() {
+        fn t(a: i128, b: i128, expected: i128) {
+            let a = I256::from(a);
+            let b = I256::from(b);
+            assert_eq!(i128::try_from(a + b).unwrap(), expected);
+            assert_eq!(i128::try_from((-a) + (-b)).unwrap(), -expected);
+        }
+        t(0, 0, 0);
+        t(4321, -1111, 3210);
+        t(i128::MAX, 0, i128::MAX);
+        assert_eq!(I256::MAX.checked_add(I256::ONE), None);
+        assert_eq!(I256::MIN.checked_sub(I256::ONE), None);
+        assert_eq!(I256::MAX.checked_mul(I256::from(2)), None);
+        assert_eq!(I256::MIN.checked_neg(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_on_add_overflow() {
+        let _x = I256::MAX + I256::ONE;
+    }
+
+    #[test]
+    fn it_calculates_remainder() {
+        fn t(a: i128, b: i128) {
+            assert_eq!(
+                i128::try_from(I256::from(a) % I256::from(b)).unwrap(),
+                a % b
+            );
+            assert_eq!(
+                i128::try_from(I256::from(a) / I256::from(b)).unwrap(),
+                a / b
+            );
+        }
+        t(35, 5);
+        t(37, 5);
+        t(-37, 5);
+        t(37, -5);
+        t(-37, -5);
+        t(i128::MIN, 7);
+        assert_eq!(I256::MIN / I256::ONE, I256::MIN);
+        assert_eq!(I256::MIN % I256::MAX, -I256::ONE);
+        assert_eq!(I256::ONE.checked_div(I256::ZERO), None);
+        assert_eq!(I256::ONE.checked_rem(I256::ZERO), None);
+        assert_eq!(I256::MIN.checked_div(-I256::ONE), None);
+        assert_eq!(I256::MIN.checked_rem(-I256::ONE), None);
+    }
+
+    #[test]
+    fn it_converts_bytes() {
+        fn t(x: I256) {
+            assert_eq!(I256::from_le_bytes(x.to_le_bytes()), x);
+            assert_eq!(I256::from_be_bytes(x.to_be_bytes()), x);
+        }
+        t(I256::ZERO);
+        t(I256::MAX);
+        t(I256::MIN);
+        t(I256::from(-1234567));
+
+        let mut bytes = [0; 32];
+        bytes[31] = 1;
+        assert_eq!(I256::from_be_bytes(bytes), I256::ONE);
+        assert_eq!(I256::from_le_bytes(bytes).to_be_bytes()[0], 1);
+        assert_eq!((-I256::ONE).to_le_bytes(), [0xff; 32]);
+    }
+
+    #[test]
+    fn it_converts_ints() {
+        assert_eq!(i64::try_from(I256::from(i64::MIN)).unwrap(), i64::MIN);
+        assert_eq!(u8::try_from(I256::from(255u8)).unwrap(), 255);
+        assert!(u8::try_from(I256::from(256u16)).is_err());
+        assert!(u64::try_from(-I256::ONE).is_err());
+        assert!(i128::try_from(I256::MAX).is_err());
+        assert!(u128::try_from(I256::U128_MAX).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn it_parses_and_formats() {
+        fn t(str: &str) {
+            assert_eq!(str.parse::<I256>().unwrap().to_string(), str);
+        }
+        t("0");
+        t("-1");
+        t("170141183460469231731687303715884105728");
+        t("57896044618658097711785492504343953926634992332820282019728792003956564819967");
+        t("-57896044618658097711785492504343953926634992332820282019728792003956564819968");
+        assert_eq!("+15".parse::<I256>().unwrap(), I256::from(15));
+        assert_eq!(format!("{:?}", I256::from(-15)), "-15");
+        assert!(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819968"
+                .parse::<I256>()
+                .is_err()
+        );
+        assert!("".parse::<I256>().is_err());
+        assert!("-".parse::<I256>().is_err());
+        assert!("1.5".parse::<I256>().is_err());
+    }
 }
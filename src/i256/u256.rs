@@ -8,7 +8,7 @@ use core::convert::TryFrom;
 
 use crate::errors::{ArithmeticError, ConvertError};
 use crate::ops::sqrt::Sqrt;
-use crate::ops::Zero;
+use crate::ops::{One, RoundMode, RoundingSqrt, Zero};
 
 macro_rules! impl_map_from {
     ($thing:ident, $from:ty, $to:ty) => {
@@ -61,7 +61,7 @@ macro_rules! uint_overflowing_binop {
 
 macro_rules! uint_full_mul_reg {
     ($name:ident, 8, $self_expr:expr, $other:expr) => {
-        $crate::uint_full_mul_reg!($name, 8, $self_expr, $other, |a, b| a != 0 || b != 0);
+        uint_full_mul_reg!($name, 8, $self_expr, $other, |a, b| a != 0 || b != 0);
     };
     ($name:ident, $n_words:tt, $self_expr:expr, $other:expr) => {
         uint_full_mul_reg!($name, $n_words, $self_expr, $other, |_, _| true);
@@ -321,6 +321,13 @@ macro_rules! uint {
                 Self([0; $n_words])
             }
 
+            pub(crate) const fn from_u128(x: u128) -> Self {
+                let mut words = [0; $n_words];
+                words[0] = x as u64;
+                words[1] = (x >> 64) as u64;
+                Self(words)
+            }
+
             fn full_shl(self, shift: u32) -> [u64; $n_words + 1] {
                 debug_assert!(shift < Self::WORD_BITS as u32);
                 let mut u = [0u64; $n_words + 1];
@@ -458,7 +465,7 @@ macro_rules! uint {
             /// # Panics
             ///
             /// Panics if `other` is zero.
-            fn div_mod(self, other: Self) -> (Self, Self) {
+            pub(crate) fn div_mod(self, other: Self) -> (Self, Self) {
                 let my_bits = self.bits();
                 let your_bits = other.bits();
 
@@ -612,7 +619,7 @@ macro_rules! uint {
                 (self, carry)
             }
 
-            fn leading_zeros(&self) -> u32 {
+            pub(crate) fn leading_zeros(&self) -> u32 {
                 self.0.iter().rev().fold((0, false), |(acc, one_was_met), &chunk| {
                     if one_was_met {
                         (acc, true)
@@ -807,7 +814,7 @@ macro_rules! uint {
                     Err(_) => {
                         let lo = (self >> 2u32).sqrt()? << 1u32;
                         let hi = least_significant_word_or(lo, 1);
-                        let (hi_square, _): (U256, _) = hi.overflowing_mul(hi);
+                        let (hi_square, _): ($name, _) = hi.overflowing_mul(hi);
                         if hi_square <= self {
                             hi
                         } else {
@@ -818,6 +825,64 @@ macro_rules! uint {
                 Ok(result)
             }
         }
+
+        impl core::ops::Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                let (result, overflow) = self.overflowing_add(rhs);
+                panic_on_overflow(overflow);
+                result
+            }
+        }
+
+        impl core::ops::Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                let (result, overflow) = self.overflowing_sub(rhs);
+                panic_on_overflow(overflow);
+                result
+            }
+        }
+
+        impl One for $name {
+            const ONE: Self = Self::from_u128(1);
+        }
+
+        impl RoundingSqrt for $name {
+            type Error = ArithmeticError;
+
+            /// Integer square root of a non-negative integer S is a non-negative integer Q such that:
+            /// Floor, TowardZero: `Q ≤ sqrt(S)`
+            /// Ceil, AwayFromZero: `Q ≥ sqrt(S)`
+            /// Nearest: `|Q - sqrt(S)| ≤ 1/2`
+            #[inline]
+            fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                let lo = self.sqrt()?;
+                Ok(match mode {
+                    RoundMode::Floor | RoundMode::TowardZero => lo,
+                    // `sqrt` will always be closer to zero than `self` so overflow will never happen
+                    RoundMode::Ceil | RoundMode::AwayFromZero => {
+                        if lo * lo == self {
+                            lo
+                        } else {
+                            lo + Self::ONE
+                        }
+                    }
+                    // `(lo + 1/2)^2 = lo^2 + lo + 1/4`, so a tie is impossible for integers.
+                    RoundMode::Nearest => {
+                        if self - lo * lo > lo {
+                            lo + Self::ONE
+                        } else {
+                            lo
+                        }
+                    }
+                })
+            }
+        }
     };
 
     (@unroll for $v:ident in $start:tt..$end:tt {$($c:tt)*}) => {
@@ -837,12 +902,115 @@ macro_rules! uint {
         { const $v: usize = $a + 2; $c }
         { const $v: usize = $a + 3; $c }
     };
+
+    (@unroll @$v:ident, $a:expr, 8, $c:block) => {
+        uint!(@unroll @$v, $a, 4, $c);
+        uint!(@unroll @$v, $a + 4, 4, $c);
+    };
 }
 
 uint! {
     pub(crate) struct U256(4);
 }
 
+impl U256 {
+    /// Parses a non-empty string of decimal digits, returns `None` on overflow.
+    pub(crate) fn from_decimal_str(str: &str) -> Option<Self> {
+        if str.is_empty() {
+            return None;
+        }
+        str.bytes().try_fold(Self::ZERO, |acc, byte| {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            let (acc, carry) = acc.overflowing_mul_u64(10);
+            let (acc, overflow) = acc.overflowing_add(u64::from(byte - b'0').into());
+            if carry != 0 || overflow {
+                None
+            } else {
+                Some(acc)
+            }
+        })
+    }
+
+    #[cfg(feature = "u128")]
+    pub(crate) fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    #[cfg(feature = "u128")]
+    pub(crate) fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    #[cfg(feature = "i256")]
+    pub(crate) fn to_f64(self) -> f64 {
+        self.0.iter().rev().fold(0., |acc, &word| {
+            acc * 18_446_744_073_709_551_616. + word as f64
+        })
+    }
+}
+
+impl core::fmt::Display for U256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The largest power of ten fitting `u64`, so digits are extracted by 19 at once.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        // `U256::MAX` has 78 digits.
+        let mut chunks = [0u64; 5];
+        let mut len = 0;
+        let mut rest = *self;
+        loop {
+            let (quotient, remainder) = rest.div_mod_small(CHUNK);
+            chunks[len] = remainder.low_u64();
+            len += 1;
+            rest = quotient;
+            if rest == Self::ZERO {
+                break;
+            }
+        }
+
+        write!(f, "{}", chunks[len - 1])?;
+        for chunk in chunks[..len - 1].iter().rev() {
+            write!(f, "{:019}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "i256")]
+uint! {
+    pub(crate) struct U512(8);
+}
+
+#[cfg(feature = "i256")]
+impl From<U256> for U512 {
+    fn from(x: U256) -> Self {
+        let mut words = [0; 8];
+        words[..4].copy_from_slice(&x.0);
+        U512(words)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl TryFrom<U512> for U256 {
+    type Error = ArithmeticError;
+
+    fn try_from(x: U512) -> Result<Self, Self::Error> {
+        if x.0[4..].iter().any(|&word| word != 0) {
+            return Err(ArithmeticError::Overflow);
+        }
+        let mut words = [0; 4];
+        words.copy_from_slice(&x.0[..4]);
+        Ok(U256(words))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
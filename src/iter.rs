@@ -0,0 +1,150 @@
+//! Helpers for processing sequences of [`FixedPoint`][FixedPoint] values.
+//!
+//! [FixedPoint]: ../struct.FixedPoint.html
+
+use crate::ops::{CheckedAdd, One, RoundMode, RoundingMul, Zero};
+use crate::{ArithmeticError, FixedPoint};
+
+/// Iterator adapter yielding the running `(min, max)` pair after each element of a series.
+///
+/// Created by [`running_extrema`][running_extrema]. Doesn't allocate and works in `no_std`.
+///
+/// [running_extrema]: ./fn.running_extrema.html
+#[derive(Debug, Clone)]
+pub struct RunningExtrema<It, F> {
+    iter: It,
+    state: Option<(F, F)>,
+}
+
+/// Tracks the running minimum and maximum of a series.
+///
+/// ```ignore
+/// use fixnum::{FixedPoint, typenum::U9, iter::running_extrema};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a: Amount = "1.5".parse()?;
+/// let b: Amount = "-2".parse()?;
+/// let c: Amount = "3".parse()?;
+///
+/// let mut extrema = running_extrema(vec![a, b, c]);
+/// assert_eq!(extrema.next(), Some((a, a)));
+/// assert_eq!(extrema.next(), Some((b, a)));
+/// assert_eq!(extrema.next(), Some((b, c)));
+/// assert_eq!(extrema.next(), None);
+/// # Ok(()) }
+/// ```
+pub fn running_extrema<I, P, It>(iter: It) -> RunningExtrema<It::IntoIter, FixedPoint<I, P>>
+where
+    It: IntoIterator<Item = FixedPoint<I, P>>,
+    FixedPoint<I, P>: Ord + Copy,
+{
+    RunningExtrema {
+        iter: iter.into_iter(),
+        state: None,
+    }
+}
+
+impl<It, F> RunningExtrema<It, F>
+where
+    F: Copy,
+{
+    /// Returns the running minimum seen so far, or `None` if nothing has been consumed yet.
+    #[inline]
+    pub fn running_min(&self) -> Option<F> {
+        self.state.map(|(min, _)| min)
+    }
+
+    /// Returns the running maximum seen so far, or `None` if nothing has been consumed yet.
+    #[inline]
+    pub fn running_max(&self) -> Option<F> {
+        self.state.map(|(_, max)| max)
+    }
+}
+
+impl<It, I, P> Iterator for RunningExtrema<It, FixedPoint<I, P>>
+where
+    It: Iterator<Item = FixedPoint<I, P>>,
+    FixedPoint<I, P>: Ord + Copy,
+{
+    type Item = (FixedPoint<I, P>, FixedPoint<I, P>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        let state = match self.state {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        };
+        self.state = Some(state);
+        Some(state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Checked summation of an iterator, stopping on the first overflow.
+///
+/// Implemented for every iterator, the sum type is chosen at the call site like with
+/// [`Iterator::sum`][sum]. Use [`FixedPoint::try_sum_detailed`][try_sum_detailed] to find out
+/// where the overflow happened.
+///
+/// ```ignore
+/// use fixnum::{FixedPoint, typenum::U9, ops::Bounded, TrySum};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values: Vec<Amount> = vec!["1.5".parse()?, "2".parse()?, "3".parse()?];
+/// assert_eq!(values.iter().copied().try_sum::<Amount>()?, "6.5".parse()?);
+/// assert!(vec![Amount::MAX, Amount::MAX].into_iter().try_sum::<Amount>().is_err());
+/// # Ok(()) }
+/// ```
+///
+/// [sum]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.sum
+/// [try_sum_detailed]: ../struct.FixedPoint.html#method.try_sum_detailed
+pub trait TrySum: Iterator + Sized {
+    /// Sums the elements with [`cadd`][cadd]. Returns `ZERO` for an empty iterator.
+    ///
+    /// [cadd]: ../ops/trait.CheckedAdd.html#tymethod.cadd
+    fn try_sum<S>(mut self) -> Result<S, ArithmeticError>
+    where
+        S: Zero + CheckedAdd<Self::Item, Output = S, Error = ArithmeticError>,
+    {
+        self.try_fold(S::ZERO, S::cadd)
+    }
+}
+
+impl<It: Iterator> TrySum for It {}
+
+/// Checked rounded product of an iterator, stopping on the first overflow.
+///
+/// ```ignore
+/// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*, TryProduct};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values: Vec<Amount> = vec!["1.5".parse()?, "2".parse()?, "0.000000001".parse()?];
+/// assert_eq!(values.iter().copied().try_product::<Amount>(Floor)?, "0.000000003".parse()?);
+/// assert_eq!(Vec::<Amount>::new().into_iter().try_product::<Amount>(Floor)?, Amount::ONE);
+/// # Ok(()) }
+/// ```
+pub trait TryProduct: Iterator + Sized {
+    /// Multiplies the elements with [`rmul`][rmul] left to right, rounding each step according
+    /// to `mode`. Returns `ONE` for an empty iterator.
+    ///
+    /// [rmul]: ../ops/trait.RoundingMul.html#tymethod.rmul
+    fn try_product<P>(mut self, mode: RoundMode) -> Result<P, ArithmeticError>
+    where
+        P: One + RoundingMul<Self::Item, Output = P, Error = ArithmeticError>,
+    {
+        self.try_fold(P::ONE, |acc, value| acc.rmul(value, mode))
+    }
+}
+
+impl<It: Iterator> TryProduct for It {}
@@ -8,13 +8,59 @@
 //! - `i32` — promotes to `i64` (for mul, div),
 //! - `i64` — promotes to `i128` (for mul, div).
 //!
+//! The number of decimal places is given by a `typenum` type (e.g. `typenum::U9`), or by
+//! a const generic parameter with [`cg::FixedPoint`][cg].
+//!
+//! The same layouts are available for [`BinFixedPoint`][BinFixedPoint], which is scaled by a
+//! power of two instead of a power of ten, and for [`DynFixedPoint`][DynFixedPoint], which
+//! carries the number of decimal places at runtime.
+//!
 //! ## Features
 //! Turn them on in `Cargo.toml`:
 //!
-//! - `i128` — `i128` layout support which will be promoted to internally implemented `I256` for
+//! - `i128` — `i128` layout support which will be promoted to [`I256`][I256] for
 //!   multiplication and division.
-//! - `parity` — [`parity-scale-codec`][parity_scale_codec] support (`Encode` and `Decode`
-//!   implementations).
+//! - `i256` — `FixedPoint<I256, P>` layout with `PRECISION` up to 38, promoted to internal
+//!   512-bit integers for multiplication and division. Transcendental functions take at most
+//!   128-bit values and return `Overflow` for greater ones.
+//! - `u32`, `u64`, `u128` — unsigned layouts promoted to `u64`, `u128` and internal `U256`.
+//!   They are built by the same macro as signed layouts and share their API, but results
+//!   below zero (e.g. of subtraction, `rln` of values below one) are `Overflow`.
+//!   `BinFixedPoint`, `DynFixedPoint`, `diesel`, `num-traits`, `proptest` and
+//!   `sp-arithmetic` support remain signed-only.
+//! - `parity` — [`parity-scale-codec`][parity_scale_codec] support (`Encode`, `Decode` and
+//!   `MaxEncodedLen` implementations).
+//! - `arbitrary` — [`arbitrary`][arbitrary] support for fuzzing: random bits mixed with edge
+//!   cases like `MIN`, `MAX` and `EPSILON` (enables `std`).
+//! - `bincode` — [`bincode`][bincode] 2 support (`Encode` and `Decode` of the inner
+//!   representation) without `serde`.
+//! - `bytemuck` — [`bytemuck`][bytemuck] support (`Pod` and `Zeroable`), so slices of
+//!   `FixedPoint` can be cast to and from bytes.
+//! - `diesel` — [`diesel`][diesel] 2 support for signed layouts: `FixedPoint` maps to `Numeric`
+//!   on Postgres and MySQL. Reading fails if the value has nonzero digits beyond `PRECISION`
+//!   or is out of range (enables `std`).
+//! - `borsh` — [`borsh`][borsh] support (`BorshSerialize` and `BorshDeserialize` of the inner
+//!   representation).
+//! - `rkyv` — [`rkyv`][rkyv] support, `FixedPoint` is archived as `ArchivedFixedPoint` storing
+//!   the archived inner integer.
+//! - `zerocopy` — [`zerocopy`][zerocopy] 0.7 support (`FromZeroes`, `FromBytes`, `AsBytes` and
+//!   `Unaligned` if the inner integer implements them), so `FixedPoint` can be used
+//!   in zero-copy structs.
+//! - `num-traits` — [`num-traits`][num_traits] support for signed layouts: `Num`, `Signed`,
+//!   `Bounded`, `Checked*` and `Saturating`, along with `core::ops` operators rounding toward
+//!   zero and panicking on overflow.
+//! - `sp-arithmetic` — [`sp-arithmetic`][sp_arithmetic] support: signed layouts implement
+//!   `FixedPointNumber` (rounding toward zero) and `From` of `PerThing` types. Enables
+//!   `num-traits`.
+//! - `proptest` — [strategies][proptest] for [`proptest`](https://docs.rs/proptest) and
+//!   `Arbitrary` for signed layouts, shrinking toward zero and whole numbers (enables `std`).
+//! - `rand` — [`rand`][rand] 0.8 support: `rng.gen_range(low..=high)` and `Uniform` sample
+//!   `FixedPoint` values uniformly via [`UniformFixedPoint`][UniformFixedPoint].
+//! - `schemars` — [`schemars`][schemars] 1 support: `JsonSchema` describes the decimal string
+//!   emitted by `serde` for JSON, with a `pattern` limiting fractional digits to `PRECISION`.
+//! - `serde` — `Serialize` and `Deserialize` implementations and [modules][serde] for
+//!   `serde(with)`, available under `no_std` too (enabled by default).
+//! - `alloc` — methods returning collections (enabled by `std`).
 //!
 //! ## Example
 //! ```ignore
@@ -52,6 +98,7 @@
 //! | [`cmul`][cmul] | `let result: Result<FixedPoint, ArithmeticError> = a.cmul(b)` | Checked multiplication. Returns `Err` on overflow. This is multiplication without rounding, hence it's available only when at least one operand is integer. |
 //! | [`rmul`][rmul] | `let result: Result<FixedPoint, ArithmeticError> = a.rmul(b, RoundMode::Ceil)` | Checked rounding multiplication. Returns `Err` on overflow. Because of provided [`RoundMode`][RoundMode] it's possible across the [`FixedPoint`][FixedPoint] values. |
 //! | [`rdiv`][rdiv] | `let result: Result<FixedPoint, ArithmeticError> = a.rdiv(b, RoundMode::Floor)` | Checked [rounding][RoundMode] division. Returns `Err` on overflow. |
+//! | [`rrem`][rrem] | `let result: Result<FixedPoint, ArithmeticError> = a.rrem(b, RoundMode::Floor)` | Checked remainder of the division [rounded][RoundMode] to an integer quotient. Returns `Err` on overflow. |
 //! | [`rsqrt`][rsqrt] | `let result: Result<FixedPoint, ArithmeticError> = a.rsqrt(RoundMode::Floor)` | Checked [rounding][RoundMode] square root. Returns `Err` for negative argument. |
 //! | [`cneg`][cneg] | `let result: Result<FixedPoint, ArithmeticError> = a.cneg()` | Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]). |
 //! | [`integral`][integral] | `let y: {integer} = x.integral(RoundMode::Floor)` | Takes [rounded][RoundMode] integral part of the number. |
@@ -59,10 +106,13 @@
 //! | [`saturating_sub`][saturating_sub] | `let z: FixedPoint = x.saturating_sub(y)` | Saturating subtraction |
 //! | [`saturating_mul`][saturating_mul] | `let z: FixedPoint = x.saturating_mul(y)` | Saturating multiplication. This is multiplication without rounding, hence it's available only when at least one operand is integer. |
 //! | [`saturating_rmul`][saturating_rmul] | `let z: FixedPoint = x.saturating_rmul(y, RoundMode::Floor)` | Saturating [rounding][RoundMode] multiplication |
+//! | [`saturating_rdiv`][saturating_rdiv] | `let z: FixedPoint = x.saturating_rdiv(y, RoundMode::Floor)` | Saturating [rounding][RoundMode] division. Division by zero saturates by the sign of `x` |
 //!
 //! ## Implementing wrapper types.
 //! It's possible to restrict the domain in order to reduce chance of mistakes.
 //! Note that convenient [`fixnum!` macro][fixnum] works with wrapper types too.
+//! The boilerplate of wrapper definitions can be reduced by
+//! [`define_fixed_wrapper!` macro][define_fixed_wrapper].
 //! ```ignore
 //! use derive_more::From;
 //! use fixnum::{impl_op, typenum::U9, FixedPoint, fixnum};
@@ -101,21 +151,42 @@
 //! # Ok(()) }
 //! ```
 //!
+//! [BinFixedPoint]: ./struct.BinFixedPoint.html
 //! [cadd]: ./ops/trait.CheckedAdd.html#tymethod.cadd
+//! [cg]: ./cg/type.FixedPoint.html
 //! [cneg]: ./struct.FixedPoint.html#method.cneg
 //! [csub]: ./ops/trait.CheckedSub.html#tymethod.csub
 //! [cmul]: ./ops/trait.CheckedMul.html#tymethod.cmul
+//! [DynFixedPoint]: ./struct.DynFixedPoint.html
+//! [define_fixed_wrapper]: ./macro.define_fixed_wrapper.html
 //! [fixnum]: ./macro.fixnum.html
 //! [FixedPoint]: ./struct.FixedPoint.html
+//! [I256]: ./struct.I256.html
 //! [integral]: ./struct.FixedPoint.html#method.integral
 //! [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
 //! [parity_scale_codec]: https://docs.rs/parity-scale-codec
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [bincode]: https://docs.rs/bincode
+//! [borsh]: https://docs.rs/borsh
+//! [diesel]: https://docs.rs/diesel/2
+//! [bytemuck]: https://docs.rs/bytemuck
+//! [rkyv]: https://docs.rs/rkyv
+//! [zerocopy]: https://docs.rs/zerocopy/0.7
+//! [num_traits]: https://docs.rs/num-traits
+//! [sp_arithmetic]: https://docs.rs/sp-arithmetic
+//! [proptest]: ./proptest/index.html
+//! [rand]: https://docs.rs/rand/0.8
+//! [UniformFixedPoint]: ./struct.UniformFixedPoint.html
+//! [schemars]: https://docs.rs/schemars
+//! [serde]: ./serde/index.html
 //! [rdiv]: ./ops/trait.RoundingDiv.html#tymethod.rdiv
 //! [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+//! [rrem]: ./ops/trait.RoundingRem.html#tymethod.rrem
 //! [rsqrt]: ./ops/trait.RoundingSqrt.html#tymethod.rsqrt
 //! [RoundMode]: ./ops/enum.RoundMode.html
 //! [saturating_add]: ./ops/trait.CheckedAdd.html#tymethod.saturating_add
 //! [saturating_mul]: ./ops/trait.CheckedMul.html#tymethod.saturating_mul
+//! [saturating_rdiv]: ./ops/trait.RoundingDiv.html#method.saturating_rdiv
 //! [saturating_rmul]: ./ops/trait.RoundingMul.html#tymethod.saturating_rmul
 //! [saturating_sub]: ./ops/trait.CheckedSub.html#tymethod.saturating_sub
 
@@ -123,37 +194,144 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::cmp::Ord;
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
 use core::convert::{TryFrom, TryInto};
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+use core::fmt;
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+use core::i64;
+use core::marker::PhantomData;
 use core::str::FromStr;
-use core::{fmt, i64, marker::PhantomData};
 
 use typenum::Unsigned;
 
-#[cfg(feature = "i128")]
-use crate::i256::I256;
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
 use crate::ops::*;
 pub use typenum;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod array_string;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+mod binary;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod const_fn;
+#[cfg(all(
+    feature = "diesel",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")
+))]
+mod diesel;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+mod dynamic;
 mod errors;
-#[cfg(feature = "i128")]
+#[cfg(any(feature = "i128", feature = "i256", feature = "u128"))]
 mod i256;
 mod macros;
+#[cfg(all(
+    feature = "num-traits",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")
+))]
+mod num_traits;
 #[cfg(feature = "parity")]
 mod parity;
 mod power_table;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(all(
+    feature = "sp-arithmetic",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")
+))]
+mod sp_arithmetic;
 #[cfg(test)]
 mod tests;
 
-#[cfg(not(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")))]
-compile_error!("Some of the next features must be enabled: \"i128\", \"i64\", \"i32\", \"i16\"");
+#[cfg(not(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+)))]
+compile_error!("Some of the next features must be enabled: \"i256\", \"i128\", \"i64\", \"i32\", \"i16\", \"u128\", \"u64\", \"u32\"");
 
+pub use array_string::ArrayString;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+pub use binary::BinFixedPoint;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+pub use dynamic::DynFixedPoint;
 pub use errors::*;
+#[cfg(any(feature = "i128", feature = "i256"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "i128", feature = "i256"))))]
+pub use i256::I256;
+#[cfg(feature = "i256")]
+use i256::I512;
+#[cfg(feature = "u128")]
+use i256::U256;
+pub use iter::{TryProduct, TrySum};
+#[cfg(feature = "rand")]
+pub use rand::UniformFixedPoint;
 
+pub mod cg;
+pub mod iter;
 pub mod ops;
-#[cfg(all(feature = "serde", feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "serde"))))]
+#[cfg(all(
+    feature = "proptest",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde;
 
 #[doc(hidden)]
@@ -181,21 +359,81 @@ type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
 /// MAX = (2 ^ (64 - 1) - 1) / 1e9 = 9223372036.854775807 ~ 9.2e9
 /// ERROR_MAX = 0.5 / 1e9 = 5e-10
 /// ```
+///
+/// Every number has the only representation (e.g. there is no negative zero),
+/// so `Ord` always agrees with the numeric order and `Display` round-trips through `FromStr`.
+///
+/// It's `#[repr(transparent)]`, i.e. has the same layout as the inner integer `I`.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     docsrs,
-    doc(cfg(any(feature = "i128", feature = "i64", feature = "i32", feature = "i16")))
+    doc(cfg(any(
+        feature = "i256",
+        feature = "i128",
+        feature = "i64",
+        feature = "i32",
+        feature = "i16",
+        feature = "u128",
+        feature = "u64",
+        feature = "u32"
+    )))
 )]
 #[cfg_attr(feature = "scale-info", derive(scale_info::TypeInfo))]
 #[cfg_attr(feature = "scale-info", scale_info(skip_type_params(P)))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromZeroes,
+        zerocopy::FromBytes,
+        zerocopy::AsBytes,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
 pub struct FixedPoint<I, P> {
     inner: I,
     _marker: PhantomData<P>,
 }
 
+/// Number of decimal places, a `typenum` unsigned type (e.g. `typenum::U9`).
+/// [`cg::FixedPoint`][cg] takes it as a const generic parameter instead.
+///
+/// [cg]: ./cg/type.FixedPoint.html
 pub trait Precision: Unsigned {}
 impl<U: Unsigned> Precision for U {}
 
+/// Decomposition of a [`FixedPoint`][FixedPoint] value into decimal parts,
+/// as it's printed by `Display`.
+///
+/// E.g. `-12.340` is represented as `negative = true`, `integral = 12`, `fractional = 34`,
+/// `fractional_len = 2`.
+///
+/// [FixedPoint]: ./struct.FixedPoint.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalParts {
+    pub negative: bool,
+    pub integral: u128,
+    /// Fractional part without trailing zeros.
+    pub fractional: u64,
+    /// Number of digits in the fractional part including leading zeros.
+    pub fractional_len: u8,
+}
+
+/// Displays a number with grouped thousands in the integral part, e.g. `1,234,567.890123`.
+/// It's created by [`FixedPoint::fmt_grouped`][fmt_grouped].
+///
+/// [fmt_grouped]: ./struct.FixedPoint.html#method.fmt_grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grouped<T> {
+    value: T,
+    separator: char,
+    decimal_point: char,
+}
+
 impl<I, P> FixedPoint<I, P> {
     pub const fn from_bits(raw: I) -> Self {
         FixedPoint {
@@ -212,8 +450,118 @@ impl<I, P> FixedPoint<I, P> {
     pub fn into_bits(self) -> I {
         self.inner
     }
+
+    /// Parses a list of numbers separated by ASCII whitespace.
+    /// On failure the error reports [the index][index] of the first malformed token.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let list = Amount::parse_list("1.5 -2\t0.25\n")?;
+    /// assert_eq!(list, vec!["1.5".parse()?, "-2".parse()?, "0.25".parse()?]);
+    ///
+    /// let err = Amount::parse_list("1.5 oops 0.25").unwrap_err();
+    /// assert_eq!(err.index(), Some(1));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [index]: ./struct.ConvertError.html#method.index
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn parse_list(str: &str) -> Result<alloc::vec::Vec<Self>, ConvertError>
+    where
+        Self: FromStr<Err = ConvertError>,
+    {
+        str.split_ascii_whitespace()
+            .enumerate()
+            .map(|(index, token)| {
+                token
+                    .parse()
+                    .map_err(|err: ConvertError| err.with_index(index))
+            })
+            .collect()
+    }
+
+    /// Parses a number surrounded by one of `prefixes` and one of `suffixes`, e.g. a currency
+    /// symbol. Surrounding whitespace is trimmed first, then the first matching prefix and
+    /// the first matching suffix are stripped if present. The rest is parsed by `FromStr`.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let expected: Amount = "1.5".parse()?;
+    /// assert_eq!(Amount::from_str_stripping(" $1.50 ", &["$"], &[])?, expected);
+    /// assert_eq!(Amount::from_str_stripping("1.50 USD", &["$"], &[" USD"])?, expected);
+    /// assert!(Amount::from_str_stripping("1.50 EUR", &["$"], &[" USD"]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn from_str_stripping(
+        str: &str,
+        prefixes: &[&str],
+        suffixes: &[&str],
+    ) -> Result<Self, ConvertError>
+    where
+        Self: FromStr<Err = ConvertError>,
+    {
+        let str = str.trim();
+        let str = prefixes
+            .iter()
+            .find_map(|prefix| str.strip_prefix(prefix))
+            .unwrap_or(str);
+        let str = suffixes
+            .iter()
+            .find_map(|suffix| str.strip_suffix(suffix))
+            .unwrap_or(str);
+        str.parse()
+    }
+
+    /// Folds values with a checked binary operation, e.g. `cadd` or a `rmul` closure.
+    /// Returns `Ok(None)` for empty input and stops on the first error.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{CheckedAdd, RoundingMul, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let values: Vec<Amount> = vec!["1.5".parse()?, "2".parse()?, "3".parse()?];
+    /// let sum = Amount::try_reduce(values.clone(), Amount::cadd)?;
+    /// let product = Amount::try_reduce(values, |a, b| a.rmul(b, Floor))?;
+    /// assert_eq!(sum, Some("6.5".parse()?));
+    /// assert_eq!(product, Some("9".parse()?));
+    /// assert_eq!(Amount::try_reduce(vec![], Amount::cadd)?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn try_reduce<It, F>(iter: It, f: F) -> Result<Option<Self>, ArithmeticError>
+    where
+        It: IntoIterator<Item = Self>,
+        F: Fn(Self, Self) -> Result<Self, ArithmeticError>,
+    {
+        let mut iter = iter.into_iter();
+        let first = match iter.next() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        iter.try_fold(first, f).map(Some)
+    }
 }
 
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
 macro_rules! impl_fixed_point {
     (
         $(#[$attr:meta])?
@@ -221,19 +569,78 @@ macro_rules! impl_fixed_point {
         promoted_to = $promotion:tt;
         convert = $convert:expr;
         try_from = [$($try_from:ty),*];
+        kind = $kind:ident;
     ) => {
+        impl_fixed_point!(@$kind $layout, $(#[$attr])?);
+
         $(#[$attr])?
         impl<P: Precision> FixedPoint<$layout, P> {
             pub const PRECISION: i32 = P::I32;
-            pub const EPSILON: Self = Self::from_bits(1);
+            pub const EPSILON: Self = Self::from_bits($layout::ONE);
+
+            // Each of the constants below fails to compile if it doesn't fit the type,
+            // e.g. `HUNDRED` for `FixedPoint<i16, U4>` or `HALF` for zero precision.
+            pub const TWO: Self = Self::from_bits(impl_fixed_point!(@const_mul $layout, Self::COEF, 2));
+            pub const TEN: Self = Self::from_bits(impl_fixed_point!(@const_mul $layout, Self::COEF, 10));
+            pub const HUNDRED: Self = Self::from_bits(impl_fixed_point!(@const_mul $layout, Self::COEF, 100));
+            pub const HALF: Self = Self::from_bits(impl_fixed_point!(@from_int $layout,
+                const_fn::checked(const_fn::pow10(Self::PRECISION) / 2, Self::PRECISION > 0)
+            ));
 
-            const COEF: $layout = const_fn::pow10(Self::PRECISION) as _;
+            const COEF: $layout = impl_fixed_point!(@from_int $layout, const_fn::pow10(Self::PRECISION));
             const COEF_PROMOTED: $promotion = $convert(Self::COEF) as _;
+            const COEF_U128: u128 = const_fn::pow10(Self::PRECISION) as u128;
+
+            /// Returns the distance between adjacent values, i.e. [`EPSILON`][EPSILON].
+            ///
+            /// [EPSILON]: #associatedconstant.EPSILON
+            #[inline]
+            pub const fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            /// Returns the smallest positive value, that is [`EPSILON`][EPSILON] as well.
+            ///
+            /// [EPSILON]: #associatedconstant.EPSILON
+            #[inline]
+            pub const fn min_positive() -> Self {
+                Self::EPSILON
+            }
+
+            /// Returns the largest value less than one, i.e. `1 - EPSILON`.
+            #[inline]
+            pub const fn max_below_one() -> Self {
+                Self::from_bits(impl_fixed_point!(@from_int $layout, const_fn::pow10(Self::PRECISION) - 1))
+            }
+
+            /// Checks whether `self` is greater than zero.
+            #[inline]
+            pub const fn is_positive(self) -> bool {
+                !self.is_negative() && !self.is_zero()
+            }
+
+            /// Checks whether `self` is zero.
+            #[inline]
+            pub const fn is_zero(self) -> bool {
+                impl_fixed_point!(@is_zero $layout, self.inner)
+            }
+
+            /// Returns the sign of an integer of the layout as `i32`, which rounding takes.
+            #[inline]
+            fn signum_i32(x: $layout) -> i32 {
+                if x.is_negative() {
+                    -1
+                } else if x == $layout::ZERO {
+                    0
+                } else {
+                    1
+                }
+            }
         }
 
         $(#[$attr])?
         impl<P: Precision> Zero for FixedPoint<$layout, P> {
-            const ZERO: Self = Self::from_bits(0);
+            const ZERO: Self = Self::from_bits($layout::ZERO);
         }
 
         $(#[$attr])?
@@ -266,7 +673,9 @@ macro_rules! impl_fixed_point {
                 let mut result =
                     $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
 
-                if loss != $convert(0) && mode as i32 == sign as i32 {
+                if loss != <$promotion as Zero>::ZERO
+                    && mode.is_away_from_zero(Self::signum_i32(sign), loss, Self::COEF_PROMOTED)
+                {
                     result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                 }
 
@@ -284,7 +693,7 @@ macro_rules! impl_fixed_point {
                 // TODO(loyd): avoid 128bit arithmetic when possible,
                 //      because LLVM doesn't replace 128bit division by const with multiplication.
 
-                if rhs.inner == 0 {
+                if rhs.is_zero() {
                     return Err(ArithmeticError::DivisionByZero);
                 }
 
@@ -296,10 +705,10 @@ macro_rules! impl_fixed_point {
                 let mut result =
                     $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
 
-                if loss != $convert(0) {
+                if loss != <$promotion as Zero>::ZERO {
                     let sign = self.inner.signum() * rhs.inner.signum();
 
-                    if mode as i32 == sign as i32 {
+                    if mode.is_away_from_zero(Self::signum_i32(sign), loss, denominator) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -315,7 +724,7 @@ macro_rules! impl_fixed_point {
 
             #[inline]
             fn rdiv(self, rhs: $layout, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
-                if rhs == 0 {
+                if rhs == $layout::ZERO {
                     return Err(ArithmeticError::DivisionByZero);
                 }
 
@@ -324,10 +733,10 @@ macro_rules! impl_fixed_point {
                 let mut result = numerator / denominator;
                 let loss = numerator - result * denominator;
 
-                if loss != 0 {
+                if loss != $layout::ZERO {
                     let sign = numerator.signum() * denominator.signum();
 
-                    if mode as i32 == sign as i32 {
+                    if mode.is_away_from_zero(Self::signum_i32(sign), loss, denominator) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -348,6 +757,32 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> RoundingRem for FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rrem(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                let sign = self.inner.signum() * rhs.inner.signum();
+                Self::rrem_promoted($promotion::from(self.inner), $promotion::from(rhs.inner), sign, mode)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingRem<$layout> for FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rrem(self, rhs: $layout, mode: RoundMode) -> Result<Self> {
+                let sign = self.inner.signum() * rhs.signum();
+                // `rhs` is scaled in the promoted type, because it can be out of the layout.
+                let divisor = $promotion::from(rhs) * Self::COEF_PROMOTED;
+                Self::rrem_promoted($promotion::from(self.inner), divisor, sign, mode)
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> CheckedAdd for FixedPoint<$layout, P> {
             type Output = FixedPoint<$layout, P>;
@@ -421,6 +856,37 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> RoundingDot for FixedPoint<$layout, P> {
+            type Error = ArithmeticError;
+
+            fn rdot(xs: &[Self], ys: &[Self], mode: RoundMode) -> Result<Self> {
+                if xs.len() != ys.len() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let zero: $promotion = <$promotion as Zero>::ZERO;
+                let value = xs.iter().zip(ys).try_fold(zero, |acc, (x, y)| {
+                    let product = $promotion::from(x.inner) * $promotion::from(y.inner);
+                    acc.checked_add(product)
+                }).ok_or(ArithmeticError::Overflow)?;
+                let result = value / Self::COEF_PROMOTED;
+                let loss = value - result * Self::COEF_PROMOTED;
+                let sign = if value < zero { -1 } else { 1 };
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != zero && mode.is_away_from_zero(sign, loss, Self::COEF_PROMOTED) {
+                    let one = <$layout as One>::ONE;
+                    result = if sign < 0 { result.checked_sub(one) } else { result.checked_add(one) }
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> RoundingSqrt for FixedPoint<$layout, P> {
             type Error = ArithmeticError;
@@ -440,8 +906,227 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> transcendental::RoundingLog for FixedPoint<$layout, P> {
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rln(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                Self::rln(self, mode)
+            }
+
+            #[inline]
+            fn rlog2(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                Self::rlog2(self, mode)
+            }
+
+            #[inline]
+            fn rlog10(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                Self::rlog10(self, mode)
+            }
+
+            #[inline]
+            fn rlog(self, base: Self, mode: RoundMode) -> Result<Self, Self::Error> {
+                Self::rlog(self, base, mode)
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> FixedPoint<$layout, P> {
+            /// Checked raising to an integer power using binary exponentiation.
+            /// Every multiplication is [rounded][RoundMode] according to `mode`, so the error
+            /// accumulates and can exceed `EPSILON` for large `exp`. For negative results
+            /// the magnitude is rounded in the opposite direction, so `Floor` and `Ceil` still
+            /// give lower and upper bounds. Returns `Err(Overflow)` if any step overflows.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.cpowi(3, Floor)?, "3.375".parse()?);
+            /// let b: Amount = "1.000000001".parse()?;
+            /// assert_eq!(b.cpowi(2, Floor)?, "1.000000002".parse()?);
+            /// assert_eq!(b.cpowi(2, Ceil)?, "1.000000003".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn cpowi(self, exp: u32, mode: RoundMode) -> Result<Self> {
+                if exp == 1 {
+                    return Ok(self);
+                }
+
+                let is_negative = self.is_negative() && exp % 2 == 1;
+                let magnitude_mode = match mode {
+                    RoundMode::Ceil if is_negative => RoundMode::Floor,
+                    RoundMode::Floor if is_negative => RoundMode::Ceil,
+                    _ => mode,
+                };
+
+                let mut base = if self.is_negative() { self.cneg()? } else { self };
+                let mut result = Self::ONE;
+                let mut exp = exp;
+
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = result.rmul(base, magnitude_mode)?;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        base = base.rmul(base, magnitude_mode)?;
+                    }
+                }
+
+                if is_negative {
+                    result.cneg()
+                } else {
+                    Ok(result)
+                }
+            }
+
+            /// Calculates `self * b / c` with a single final [rounding][RoundMode].
+            /// The product is kept in the promoted type, so unlike `rmul` followed by `rdiv`
+            /// it neither rounds twice nor overflows on the intermediate result.
+            /// Returns `Err` only if the final result overflows or `c` is zero.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "0.000000001".parse()?;
+            /// let b: Amount = "0.5".parse()?;
+            /// let c: Amount = "0.25".parse()?;
+            /// assert_eq!(a.mul_div(b, c, Floor)?, "0.000000002".parse()?);
+            /// let two: Amount = "2".parse()?;
+            /// assert_eq!(Amount::MAX.mul_div(two, two, Floor)?, Amount::MAX);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn mul_div(self, b: Self, c: Self, mode: RoundMode) -> Result<Self> {
+                if c.is_zero() {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `(a / COEF) * (b / COEF) / (c / COEF) = a * b / c / COEF`, scales cancel out.
+                let numerator = $promotion::from(self.inner) * $promotion::from(b.inner);
+                let denominator = $promotion::from(c.inner);
+                let result = numerator / denominator;
+                let loss = numerator - result * denominator;
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != <$promotion as Zero>::ZERO {
+                    let sign = self.inner.signum() * b.inner.signum() * c.inner.signum();
+
+                    if mode.is_away_from_zero(Self::signum_i32(sign), loss, denominator) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+
+            /// Checked [rounding][RoundMode] division returning the quotient together with the
+            /// remainder `self - quotient * rhs`. Both are calculated from the same promoted
+            /// intermediate, so it's cheaper than `rdiv` followed by multiplication.
+            /// The equality `quotient * rhs + remainder == self` is exact whenever the product
+            /// fits the precision (e.g. for integer `rhs`), otherwise the remainder is truncated.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "10".parse()?;
+            /// let b: Amount = "3".parse()?;
+            /// assert_eq!(a.rdiv_rem(b, Floor)?, ("3.333333333".parse()?, "0.000000001".parse()?));
+            /// assert_eq!(a.rdiv_rem(b, Ceil)?, ("3.333333334".parse()?, "-0.000000002".parse()?));
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rdiv_rem(self, rhs: Self, mode: RoundMode) -> Result<(Self, Self)> {
+                if rhs.is_zero() {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let numerator = $promotion::from(self.inner) * Self::COEF_PROMOTED;
+                let denominator = $promotion::from(rhs.inner);
+                let result = numerator / denominator;
+                let loss = numerator - result * denominator;
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                let sign = self.inner.signum() * rhs.inner.signum();
+                let loss = if loss != <$promotion as Zero>::ZERO
+                    && mode.is_away_from_zero(Self::signum_i32(sign), loss, denominator)
+                {
+                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    // The quotient is moved away from zero by one, so is `q * denominator`.
+                    // It fails only if the remainder becomes negative for an unsigned layout.
+                    if Self::signum_i32(sign) > 0 {
+                        loss.checked_sub(denominator)
+                    } else {
+                        loss.checked_add(denominator)
+                    }
+                    .ok_or(ArithmeticError::Overflow)?
+                } else {
+                    loss
+                };
+
+                // The loss is scaled by `COEF` as the numerator.
+                let remainder = (loss / Self::COEF_PROMOTED)
+                    .try_into()
+                    .map_err(|_| ArithmeticError::Overflow)?;
+
+                Ok((Self::from_bits(result), Self::from_bits(remainder)))
+            }
+
+            /// Calculates `dividend - q * divisor` where `q` is the rounded quotient.
+            /// The promoted type can't overflow even for `MIN / -1`.
+            fn rrem_promoted(
+                dividend: $promotion,
+                divisor: $promotion,
+                sign: $layout,
+                mode: RoundMode,
+            ) -> Result<FixedPoint<$layout, P>> {
+                if divisor == <$promotion as Zero>::ZERO {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let quotient = dividend / divisor;
+                let loss = dividend - quotient * divisor;
+
+                let remainder = if loss != <$promotion as Zero>::ZERO
+                    && mode.is_away_from_zero(Self::signum_i32(sign), loss, divisor)
+                {
+                    // The quotient is moved away from zero by one, so is `q * divisor`.
+                    // It fails only if the remainder becomes negative for an unsigned layout.
+                    if Self::signum_i32(sign) > 0 {
+                        loss.checked_sub(divisor)
+                    } else {
+                        loss.checked_add(divisor)
+                    }
+                    .ok_or(ArithmeticError::Overflow)?
+                } else {
+                    loss
+                };
+
+                remainder
+                    .try_into()
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
             #[inline]
             pub fn recip(self, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
                 Self::ONE.rdiv(self, mode)
@@ -465,15 +1150,24 @@ macro_rules! impl_fixed_point {
                 mode: RoundMode,
             ) -> FixedPoint<$layout, P> {
                 if a.inner.signum() != b.inner.signum() {
-                    Self::from_bits(a.inner + b.inner).rdiv(2, mode).unwrap()
+                    Self::from_bits(a.inner + b.inner).rdiv($layout::from(2u8), mode).unwrap()
                 } else {
                     let min = a.inner.min(b.inner);
                     let max = a.inner.max(b.inner);
-                    let half_diff = (max - min).rdiv(2, mode).unwrap();
+                    let half_diff = (max - min).rdiv($layout::from(2u8), mode).unwrap();
                     Self::from_bits(min + half_diff)
                 }
             }
 
+            /// Returns `(self.inner / COEF, self.inner % COEF)`.
+            /// The remainder is restored by multiplication, because a division is much more
+            /// expensive, especially for `i128` which has no native instruction.
+            #[inline]
+            fn div_rem_coef(self) -> ($layout, $layout) {
+                let quotient = self.inner / Self::COEF;
+                (quotient, self.inner - quotient * Self::COEF)
+            }
+
             /// Takes [rounded][RoundMode] integral part of the number.
             ///
             /// ```ignore
@@ -496,15 +1190,36 @@ macro_rules! impl_fixed_point {
             #[inline]
             pub fn integral(self, mode: RoundMode) -> $layout {
                 let sign = self.inner.signum();
-                let (int, frac) = (self.inner / Self::COEF, self.inner.abs() % Self::COEF);
+                let (int, frac) = self.div_rem_coef();
 
-                if mode as i32 == sign as i32 && frac > 0 {
+                if frac != $layout::ZERO && mode.is_away_from_zero(Self::signum_i32(sign), frac, Self::COEF) {
                     int + sign
                 } else {
                     int
                 }
             }
 
+            /// [Rounds][RoundMode] the value to an integer of the layout type.
+            /// Unlike the deprecated `rounding_to_i64`, it never truncates the result to `i64`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U18, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i128, U18>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "100000000000000000000.5".parse()?;
+            /// assert_eq!(a.round_to_inner(Floor), 100000000000000000000);
+            /// assert_eq!(a.round_to_inner(Ceil), 100000000000000000001);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn round_to_inner(self, mode: RoundMode) -> $layout {
+                self.integral(mode)
+            }
+
             #[inline]
             pub fn round_towards_zero_by(
                 self,
@@ -516,155 +1231,2513 @@ macro_rules! impl_fixed_point {
                     .map_or(self, Self::from_bits)
             }
 
-            pub fn next_power_of_ten(self) -> Result<FixedPoint<$layout, P>> {
-                if self.inner < 0 {
-                    return self.cneg()?.next_power_of_ten()?.cneg();
+            /// [Rounds][RoundMode] the value to a multiple of `10^exponent`.
+            /// Negative `exponent` selects a fractional place, e.g. `-2` rounds to hundredths.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1234.5678".parse()?;
+            /// assert_eq!(a.round_to_pow10(2, Floor)?, "1200".parse()?);
+            /// assert_eq!(a.round_to_pow10(2, Ceil)?, "1300".parse()?);
+            /// assert_eq!(a.round_to_pow10(-2, Floor)?, "1234.56".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn round_to_pow10(self, exponent: i32, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                let places = exponent.saturating_add(Self::PRECISION);
+                if places <= 0 {
+                    return Ok(self);
                 }
 
-                let lz = self.inner.leading_zeros() as usize;
-                assert!(lz > 0, "unexpected negative value");
-
-                let value = power_table::$layout[lz];
-
-                let value = if self.inner > value {
-                    power_table::$layout[lz - 1]
-                } else {
-                    value
+                let ten = $layout::from(10u8);
+                let unit = match ten.checked_pow(places as u32) {
+                    Some(unit) => unit,
+                    // The unit is out of the layout, so the value is rounded either to zero
+                    // or to the unrepresentable unit.
+                    None => {
+                        let sign = self.inner.signum();
+                        // The half of the unit can still fit the layout.
+                        let is_away_from_zero = sign != $layout::ZERO
+                            && mode.is_away_from_zero_with(Self::signum_i32(sign), || {
+                                ten.checked_pow(places as u32 - 1)
+                                    .and_then(|unit| unit.checked_mul($layout::from(5u8)))
+                                    .map_or(false, |half| self.inner.unsigned_abs() >= half.unsigned_abs())
+                            });
+                        return if is_away_from_zero {
+                            Err(ArithmeticError::Overflow)
+                        } else {
+                            Ok(Self::ZERO)
+                        };
+                    }
                 };
 
-                if value == 0 {
-                    return Err(ArithmeticError::Overflow);
-                }
-
-                // TODO
-                Ok(Self::from_bits(value as $layout))
+                self.inner.rdiv(unit, mode)?.cmul(unit).map(Self::from_bits)
             }
 
-            #[cfg(feature = "std")]
-            #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-            #[deprecated(since = "0.6.0", note = "Use `TryFrom` instead")]
-            pub fn rounding_from_f64(value: f64) -> Result<FixedPoint<$layout, P>> {
-                value.try_into().map_err(|_| ArithmeticError::Overflow)
+            /// [Rounds][RoundMode] the value to `places` decimal places keeping the type.
+            /// If `places` isn't less than the precision, the value is returned as is.
+            /// Returns `Err(Overflow)` if the rounded value doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-1.2345".parse()?;
+            /// assert_eq!(a.round_to_places(2, Floor)?, "-1.24".parse()?);
+            /// assert_eq!(a.round_to_places(2, TowardZero)?, "-1.23".parse()?);
+            /// assert_eq!(a.round_to_places(3, Nearest)?, "-1.235".parse()?);
+            /// assert_eq!(a.round_to_places(9, Ceil)?, a);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn round_to_places(self, places: u32, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                if places >= Self::PRECISION as u32 {
+                    return Ok(self);
+                }
+                self.round_to_pow10(-(places as i32), mode)
             }
 
-            #[deprecated(since = "0.6.0", note = "Use `From` instead")]
-            pub fn to_f64(self) -> f64 {
-                self.into()
+            /// [Rounds][RoundMode] the value to an integer keeping the type.
+            /// Returns `Err(Overflow)` if the rounded value doesn't fit the layout,
+            /// e.g. `MIN` rounded down or `MAX` rounded up.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-2.5".parse()?;
+            /// assert_eq!(a.round(Nearest)?, "-3".parse()?);
+            /// assert_eq!(a.round(TowardZero)?, "-2".parse()?);
+            /// assert_eq!(a.floor()?, "-3".parse()?);
+            /// assert_eq!(a.ceil()?, "-2".parse()?);
+            /// assert!(Amount::MAX.ceil().is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn round(self, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                self.round_to_pow10(0, mode)
+            }
+
+            /// Rounds the value down to an integer keeping the type, see [`round`][round].
+            ///
+            /// [round]: #method.round
+            #[inline]
+            pub fn floor(self) -> Result<FixedPoint<$layout, P>> {
+                self.round(RoundMode::Floor)
+            }
+
+            /// Rounds the value up to an integer keeping the type, see [`round`][round].
+            ///
+            /// [round]: #method.round
+            #[inline]
+            pub fn ceil(self) -> Result<FixedPoint<$layout, P>> {
+                self.round(RoundMode::Ceil)
+            }
+
+            /// Returns the integral part of `self` rounded toward zero, so that
+            /// `self.trunc() + self.fract() == self`. Unlike [`round`][round] it never overflows.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-12.75".parse()?;
+            /// assert_eq!(a.trunc(), "-12".parse()?);
+            /// assert_eq!(a.fract(), "-0.75".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [round]: #method.round
+            #[inline]
+            pub fn trunc(self) -> FixedPoint<$layout, P> {
+                let (_, frac) = self.div_rem_coef();
+                Self::from_bits(self.inner - frac)
+            }
+
+            /// Returns the fractional part of `self` having the same sign as `self`,
+            /// see [`trunc`][trunc].
+            ///
+            /// [trunc]: #method.trunc
+            #[inline]
+            pub fn fract(self) -> FixedPoint<$layout, P> {
+                let (_, frac) = self.div_rem_coef();
+                Self::from_bits(frac)
+            }
+
+            /// Checked multiplication by `10^exponent`, e.g. to convert amounts between currencies
+            /// with different numbers of minor units. Returns `Err(Overflow)` if the result
+            /// doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "12.345".parse()?;
+            /// assert_eq!(a.cmul_pow10(2)?, "1234.5".parse()?);
+            /// assert_eq!(a.rdiv_pow10(2, Floor)?, "0.12345".parse()?);
+            /// assert_eq!(a.rdiv_pow10(8, Ceil)?, "0.000000124".parse()?);
+            /// assert!(a.cmul_pow10(9).is_err());
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn cmul_pow10(self, exponent: u32) -> Result<FixedPoint<$layout, P>> {
+                if self.is_zero() {
+                    return Ok(self);
+                }
+
+                Self::pow10(exponent)
+                    .and_then(|multiplier| self.inner.checked_mul(multiplier))
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// `10^exponent` looked up in the power table, `None` if it doesn't fit the layout.
+            #[inline]
+            fn pow10(exponent: u32) -> Option<$layout> {
+                let index = power_table::pow10_index(<$layout>::BITS, exponent)?;
+                power_table::$layout.get(index).copied().filter(|power| *power != $layout::ZERO)
+            }
+
+            /// Division by `10^exponent` [rounded][RoundMode] according to `mode`.
+            /// It never fails, but for symmetry with [`cmul_pow10`][cmul_pow10] returns `Result`.
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            /// [cmul_pow10]: #method.cmul_pow10
+            #[inline]
+            pub fn rdiv_pow10(self, exponent: u32, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                let divisor = match Self::pow10(exponent) {
+                    Some(divisor) => divisor,
+                    // The divisor is out of the layout, so the value is rounded either to zero
+                    // or to `EPSILON` of the same sign.
+                    None => {
+                        let sign = self.inner.signum();
+                        // The half of the divisor can still fit the layout.
+                        let is_away_from_zero = sign != $layout::ZERO
+                            && mode.is_away_from_zero_with(Self::signum_i32(sign), || {
+                                Self::pow10(exponent - 1)
+                                    .and_then(|unit| unit.checked_mul($layout::from(5u8)))
+                                    .map_or(false, |half| self.inner.unsigned_abs() >= half.unsigned_abs())
+                            });
+                        return Ok(Self::from_bits(if is_away_from_zero { sign } else { $layout::ZERO }));
+                    }
+                };
+
+                self.inner.rdiv(divisor, mode).map(Self::from_bits)
+            }
+
+            pub fn next_power_of_ten(self) -> Result<FixedPoint<$layout, P>> {
+                if self.is_negative() {
+                    return self.cneg()?.next_power_of_ten()?.cneg();
+                }
+
+                // Only unsigned layouts have nonnegative values without leading zeros.
+                let lz = self.inner.leading_zeros() as usize;
+                let value = power_table::$layout[lz];
+
+                let value = if self.inner > value {
+                    lz.checked_sub(1).map_or($layout::ZERO, |lz| power_table::$layout[lz])
+                } else {
+                    value
+                };
+
+                if value == $layout::ZERO {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                // TODO
+                Ok(Self::from_bits(value))
+            }
+
+            #[deprecated(since = "0.6.0", note = "Use `TryFrom` instead")]
+            pub fn rounding_from_f64(value: f64) -> Result<FixedPoint<$layout, P>> {
+                value.try_into().map_err(|_| ArithmeticError::Overflow)
+            }
+
+            #[deprecated(since = "0.6.0", note = "Use `From` instead")]
+            pub fn to_f64(self) -> f64 {
+                self.into()
+            }
+
+            // TODO: make this operation checked
+            #[deprecated(since = "0.7.0", note = "Use `round_to_inner` instead")]
+            pub fn rounding_to_i64(self) -> i64 {
+                let half = Self::COEF / $layout::from(2u8);
+                let x = if self.is_positive() { self.inner + half } else { self.inner - half };
+                impl_fixed_point!(@as_i64 $layout, x / Self::COEF)
+            }
+
+            #[inline]
+            pub fn abs(self) -> Result<Self> {
+                if self.is_negative() {
+                    self.cneg()
+                } else {
+                    Ok(self)
+                }
+            }
+
+            /// Returns `|self|` with the sign of `sign_source`. Zero `sign_source` is treated
+            /// as positive. Returns `Err` only if `self` is [`MIN`][MIN] and the result is positive.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// let b: Amount = "-0.1".parse()?;
+            /// assert_eq!(a.copysign(b)?, "-1.5".parse()?);
+            /// assert_eq!(b.copysign(a)?, "0.1".parse()?);
+            /// assert_eq!(b.copysign("0".parse()?)?, "0.1".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn copysign(self, sign_source: Self) -> Result<Self> {
+                if sign_source.is_negative() && self.is_positive() {
+                    self.cneg()
+                } else if sign_source.is_negative() {
+                    Ok(self)
+                } else {
+                    self.abs()
+                }
+            }
+
+            /// Returns the least value greater than `self`, i.e. `self + EPSILON`.
+            /// Returns `Err(Overflow)` for [`MAX`][MAX].
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.next_up()?, "1.500000001".parse()?);
+            /// assert_eq!(a.next_down()?, "1.499999999".parse()?);
+            /// assert!(Amount::MAX.next_up().is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [MAX]: ./ops/trait.Bounded.html#associatedconstant.MAX
+            #[inline]
+            pub fn next_up(self) -> Result<Self> {
+                self.cadd(Self::EPSILON)
+            }
+
+            /// Returns the greatest value less than `self`, i.e. `self - EPSILON`.
+            /// Returns `Err(Overflow)` for [`MIN`][MIN].
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn next_down(self) -> Result<Self> {
+                self.csub(Self::EPSILON)
+            }
+
+            /// Returns the minimum of two values, `self` if they're equal.
+            /// It's the same as `Ord::min`, but doesn't require the trait to be in scope.
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the maximum of two values, `other` if they're equal.
+            /// It's the same as `Ord::max`, but doesn't require the trait to be in scope.
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            /// Restricts `self` to the `[lo, hi]` interval.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let lo: Amount = "-1".parse()?;
+            /// let hi: Amount = "1".parse()?;
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.clamp(lo, hi), hi);
+            /// assert_eq!(a.min(hi), hi);
+            /// assert_eq!(a.max(hi), a);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo > hi`, use [`clamp_to_range`][clamp_to_range] to get an error instead.
+            ///
+            /// [clamp_to_range]: #method.clamp_to_range
+            #[inline]
+            pub fn clamp(self, lo: Self, hi: Self) -> Self {
+                assert!(lo.inner <= hi.inner, "`lo` must be less than or equal to `hi`");
+                self.max(lo).min(hi)
+            }
+
+            /// Restricts `self` to the `[lo, hi]` interval.
+            /// Returns `Err(DomainViolation)` if `lo > hi`.
+            ///
+            /// ```ignore
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let lo: Amount = "-1".parse()?;
+            /// let hi: Amount = "1".parse()?;
+            /// let a: Amount = "-1.5".parse()?;
+            /// assert_eq!(a.clamp_to_range(lo, hi)?, lo);
+            /// assert_eq!(a.clamp_to_range(hi, lo), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn clamp_to_range(self, lo: Self, hi: Self) -> Result<Self> {
+                if lo.inner > hi.inner {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+                Ok(self.max(lo).min(hi))
+            }
+
+            /// Converts the value to a narrower layout `J` with the same precision,
+            /// saturating to [`MIN`][MIN] or [`MAX`][MAX] of `J` if it doesn't fit.
+            /// Use `TryFrom` to detect the overflow instead.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            /// type Compact = FixedPoint<i16, U2>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-12.34".parse()?;
+            /// let b: Amount = "1000".parse()?;
+            /// assert_eq!(a.saturating_narrow::<i16>(), "-12.34".parse::<Compact>()?);
+            /// assert_eq!(b.saturating_narrow::<i16>(), "327.67".parse::<Compact>()?);
+            /// assert!(Compact::try_from(b).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [MAX]: ./ops/trait.Bounded.html#associatedconstant.MAX
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn saturating_narrow<J>(self) -> FixedPoint<J, P>
+            where
+                FixedPoint<J, P>: TryFrom<Self> + Bounded,
+            {
+                match FixedPoint::<J, P>::try_from(self) {
+                    Ok(value) => value,
+                    Err(_) if self.is_negative() => FixedPoint::MIN,
+                    Err(_) => FixedPoint::MAX,
+                }
+            }
+
+            /// Converts the value to precision `Q`, [rounding][RoundMode] it when `Q` is less
+            /// than `PRECISION`. Returns `Err` if the rescaled value doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::{U2, U9, U18}, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.255".parse()?;
+            /// assert_eq!(a.rescale::<U2>(Floor)?, "1.25".parse()?);
+            /// assert_eq!(a.rescale::<U2>(Nearest)?, "1.26".parse()?);
+            /// assert_eq!(a.rescale::<U18>(Floor)?, "1.255".parse()?);
+            ///
+            /// let b: Amount = "10".parse()?;
+            /// assert!(b.rescale::<U18>(Floor).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn rescale<Q: Precision>(
+                self,
+                mode: RoundMode,
+            ) -> Result<FixedPoint<$layout, Q>, ConvertError> {
+                self.try_convert(mode)
+            }
+
+            /// Checks whether rescaling the value to precision `Q` and back recovers it exactly.
+            /// It's true if `Q` is enough to keep all significant fractional digits and the
+            /// rescaled value fits into the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::{U2, U9}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.25".parse()?;
+            /// let b: Amount = "1.255".parse()?;
+            /// assert!(a.rescale_is_lossless::<U2>());
+            /// assert!(!b.rescale_is_lossless::<U2>());
+            /// # Ok(()) }
+            /// ```
+            pub fn rescale_is_lossless<Q: Precision>(self) -> bool {
+                let ten = $layout::from(10u8);
+
+                if self.is_zero() {
+                    return true;
+                }
+
+                if Q::I32 >= Self::PRECISION {
+                    return ten
+                        .checked_pow((Q::I32 - Self::PRECISION) as u32)
+                        .and_then(|multiplier| self.inner.checked_mul(multiplier))
+                        .is_some();
+                }
+
+                ten.checked_pow((Self::PRECISION - Q::I32) as u32)
+                    .map_or(false, |divisor| self.inner % divisor == $layout::ZERO)
+            }
+
+            /// Checks whether `|self - other| <= tolerance`.
+            /// The difference is computed without overflow, so it works for any values.
+            /// A negative `tolerance` is never satisfied.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "0.3".parse()?;
+            /// let b: Amount = "0.30000001".parse()?;
+            /// assert!(a.approx_eq(b, "0.00000001".parse()?));
+            /// assert!(!a.approx_eq(b, "0.000000009".parse()?));
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+                !tolerance.is_negative()
+                    && self.inner.abs_diff(other.inner) <= tolerance.inner.unsigned_abs()
+            }
+
+            /// Checks whether `|self - other| <= EPSILON`.
+            #[inline]
+            pub fn approx_eq_epsilon(self, other: Self) -> bool {
+                self.approx_eq(other, Self::EPSILON)
+            }
+
+            /// Adds an integer to `self`, saturating at the numeric bounds instead of overflowing.
+            /// Works even if scaling `rhs` to the precision of `Self` overflows on its own.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.saturating_add_int(2), "3.5".parse()?);
+            /// assert_eq!(a.saturating_add_int(i64::MAX), Amount::MAX);
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn saturating_add_int(self, rhs: $layout) -> Self {
+                // `rhs * COEF` may overflow the layout even if the sum doesn't,
+                // so the whole expression is evaluated in the promoted type.
+                let rhs = $promotion::from(rhs) * Self::COEF_PROMOTED;
+                Self::saturating_from_promoted($promotion::from(self.inner) + rhs)
+            }
+
+            /// Subtracts an integer from `self`, saturating at the numeric bounds instead of overflowing.
+            /// Works even if scaling `rhs` to the precision of `Self` overflows on its own.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.saturating_sub_int(2), "-0.5".parse()?);
+            /// assert_eq!(a.saturating_sub_int(i64::MAX), Amount::MIN);
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn saturating_sub_int(self, rhs: $layout) -> Self {
+                let rhs = $promotion::from(rhs) * Self::COEF_PROMOTED;
+                // Only an unsigned layout can go below zero, i.e. its `MIN`.
+                $promotion::from(self.inner)
+                    .checked_sub(rhs)
+                    .map_or(Self::MIN, Self::saturating_from_promoted)
+            }
+
+            /// Calculates `self + rhs` like [`cadd`][cadd], but returns a tuple of the sum
+            /// along with a boolean indicating whether an overflow happened.
+            /// If an overflow would have occurred then the wrapped value is returned.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.overflowing_add(a), ("3".parse()?, false));
+            /// assert_eq!(Amount::MAX.overflowing_add(Amount::EPSILON), (Amount::MIN, true));
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [cadd]: ./ops/trait.CheckedAdd.html#tymethod.cadd
+            #[inline]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (inner, is_overflow) = self.inner.overflowing_add(rhs.inner);
+                (Self::from_bits(inner), is_overflow)
+            }
+
+            /// Calculates `self - rhs` like [`csub`][csub], but returns a tuple of the difference
+            /// along with a boolean indicating whether an overflow happened.
+            /// If an overflow would have occurred then the wrapped value is returned.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.overflowing_sub(a), (Amount::ZERO, false));
+            /// assert_eq!(Amount::MIN.overflowing_sub(Amount::EPSILON), (Amount::MAX, true));
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [csub]: ./ops/trait.CheckedSub.html#tymethod.csub
+            #[inline]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (inner, is_overflow) = self.inner.overflowing_sub(rhs.inner);
+                (Self::from_bits(inner), is_overflow)
+            }
+
+            /// Calculates `self * rhs` like [`rmul`][rmul], but returns a tuple of the product
+            /// along with a boolean indicating whether an overflow happened.
+            /// Wrapping a rounded product makes no sense, so on overflow the value is saturated
+            /// like in [`saturating_rmul`][saturating_rmul].
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.overflowing_rmul(a, Floor), ("2.25".parse()?, false));
+            /// assert_eq!(Amount::MAX.overflowing_rmul(a.cneg()?, Floor), (Amount::MIN, true));
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+            /// [saturating_rmul]: ./ops/trait.RoundingMul.html#method.saturating_rmul
+            #[inline]
+            pub fn overflowing_rmul(self, rhs: Self, mode: RoundMode) -> (Self, bool) {
+                match self.rmul(rhs, mode) {
+                    Ok(result) => (result, false),
+                    Err(_) if (self.is_negative()) == (rhs.is_negative()) => (Self::MAX, true),
+                    Err(_) => (Self::MIN, true),
+                }
+            }
+
+            /// Calculates `self / rhs` like [`rdiv`][rdiv], but returns a tuple of the quotient
+            /// along with a boolean indicating whether an overflow happened.
+            /// On overflow or division by zero the value is saturated like in
+            /// [`saturating_rdiv`][saturating_rdiv].
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, Zero, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(a.overflowing_rdiv(a, Floor), (Amount::ONE, false));
+            /// assert_eq!(a.overflowing_rdiv(Amount::ZERO, Floor), (Amount::MAX, true));
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rdiv]: ./ops/trait.RoundingDiv.html#tymethod.rdiv
+            /// [saturating_rdiv]: ./ops/trait.RoundingDiv.html#method.saturating_rdiv
+            #[inline]
+            pub fn overflowing_rdiv(self, rhs: Self, mode: RoundMode) -> (Self, bool) {
+                match self.rdiv(rhs, mode) {
+                    Ok(result) => (result, false),
+                    Err(_) if self.is_zero() => (Self::ZERO, true),
+                    Err(_) if (self.is_negative()) == (rhs.is_negative()) => (Self::MAX, true),
+                    Err(_) => (Self::MIN, true),
+                }
+            }
+
+            #[inline]
+            fn saturating_from_promoted(value: $promotion) -> Self {
+                match $layout::try_from(value) {
+                    Ok(inner) => Self::from_bits(inner),
+                    Err(_) if value < <$promotion as Zero>::ZERO => Self::MIN,
+                    Err(_) => Self::MAX,
+                }
+            }
+
+            /// Adds `rhs` to `self` wrapping the result into `[0, modulus)`,
+            /// i.e. computes the euclidean remainder of `(self + rhs) / modulus`.
+            /// Returns `Err(DomainViolation)` if `modulus` isn't positive.
+            ///
+            /// The result is always exact and never overflows, even if `self + rhs` does.
+            /// `_mode` is accepted for consistency with other rounding operations only.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Turns = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Turns = "0.75".parse()?;
+            /// let b: Turns = "0.5".parse()?;
+            /// assert_eq!(a.add_mod(b, "1".parse()?, Floor)?, "0.25".parse()?);
+            /// assert_eq!(a.add_mod("-1".parse()?, "1".parse()?, Floor)?, a);
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn add_mod(self, rhs: Self, modulus: Self, _mode: RoundMode) -> Result<Self> {
+                let modulus = modulus.inner;
+                if modulus <= $layout::ZERO {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                // Both are in `[0, modulus)`, but their sum can still overflow for huge moduli.
+                let a = self.inner.rem_euclid(modulus);
+                let b = rhs.inner.rem_euclid(modulus);
+                let inner = if a >= modulus - b { a - (modulus - b) } else { a + b };
+                Ok(Self::from_bits(inner))
+            }
+
+            /// Calculates the root mean square `sqrt(mean(x^2))` of `values`,
+            /// [rounding][RoundMode] the result. Squares are summed up using the promoted type,
+            /// so only the sum of squares of values close to the bounds can overflow.
+            /// Returns `Err(DomainViolation)` if `values` is empty.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let values: [Amount; 2] = ["3".parse()?, "4".parse()?];
+            /// assert_eq!(Amount::rms(&values, Floor)?, "3.535533905".parse()?);
+            /// assert_eq!(Amount::rms(&values, Ceil)?, "3.535533906".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rms(values: &[Self], mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                if values.is_empty() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                // Squares have `COEF^2` scale, so the root of their mean has `COEF` scale.
+                let mut sum = <$promotion as Zero>::ZERO;
+                for value in values {
+                    let value = $promotion::from(value.inner);
+                    sum = sum.checked_add(value * value).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                let len = $layout::try_from(values.len()).map_err(|_| ArithmeticError::Overflow)?;
+                let len: $promotion = $convert(len) as _;
+                let mut mean = sum / len;
+                let loss = sum - mean * len;
+                // The mean must be rounded in the same direction as the root.
+                // For `Nearest` it's rounded up from a quarter, because `sqrt(k^2 + k + 1/4) = k + 1/2`.
+                let is_rounded_up = match mode {
+                    RoundMode::Ceil | RoundMode::AwayFromZero => loss != <$promotion as Zero>::ZERO,
+                    RoundMode::Floor | RoundMode::TowardZero => false,
+                    RoundMode::Nearest => loss * ($convert($layout::from(4u8)) as $promotion) >= len,
+                };
+                if is_rounded_up {
+                    mean = mean.checked_add(<$promotion as One>::ONE).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                let inner = mean.rsqrt(mode)?;
+                inner
+                    .try_into()
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Calculates `t` such that `a + (b - a) * t == v`, i.e. `(v - a) / (b - a)`
+            /// [rounded][RoundMode]. Differences are calculated in the promoted type,
+            /// so they never overflow. Returns `Err(DivisionByZero)` if `a == b`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "10".parse()?;
+            /// let b: Amount = "20".parse()?;
+            /// assert_eq!(Amount::inverse_lerp(a, b, "12.5".parse()?, Floor)?, "0.25".parse()?);
+            /// assert_eq!(Amount::inverse_lerp(b, a, "12.5".parse()?, Floor)?, "0.75".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn inverse_lerp(a: Self, b: Self, v: Self, mode: RoundMode) -> Result<Self> {
+                if a.inner == b.inner {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // Differences are taken by magnitude to not underflow unsigned layouts.
+                let difference = |x: Self, y: Self| {
+                    let (x, y) = ($promotion::from(x.inner), $promotion::from(y.inner));
+                    if x < y { (y - x, true) } else { (x - y, false) }
+                };
+                let (numerator, is_numerator_negative) = difference(v, a);
+                let (denominator, is_denominator_negative) = difference(b, a);
+                let numerator = numerator * Self::COEF_PROMOTED;
+                let is_negative = is_numerator_negative != is_denominator_negative;
+
+                let zero = <$promotion as Zero>::ZERO;
+                let mut result = numerator / denominator;
+                let loss = numerator - result * denominator;
+                let sign = if is_negative { -1 } else { 1 };
+                if loss != zero && mode.is_away_from_zero(sign, loss, denominator) {
+                    result = result.checked_add(<$promotion as One>::ONE).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                let result = if is_negative { zero.checked_sub(result) } else { Some(result) }
+                    .ok_or(ArithmeticError::Overflow)?;
+                $layout::try_from(result)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Calculates the logarithm of `self` to the given `base` as `ln(self) / ln(base)`
+            /// with a single final [rounding][RoundMode].
+            ///
+            /// Both logarithms are approximated as in [`rln`][rln] and the quotient is rounded
+            /// within the bounds of their errors, so exact results like `rlog(100, 10)` are exact
+            /// in all modes, and others are correctly rounded unless they are extremely close to
+            /// a rounding boundary. The bounds widen for bases close to `ONE`, and if they span
+            /// more than one `EPSILON`, the approximate quotient is rounded.
+            ///
+            /// Returns `Err(DomainViolation)` if `self <= 0`, `base <= 0` or `base == ONE`,
+            /// and `Err(Overflow)` if the result doesn't fit or the base is too close to `ONE`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "100".parse()?;
+            /// assert_eq!(a.rlog("10".parse()?, Floor)?, "2".parse()?);
+            /// assert_eq!(a.rlog("0.1".parse()?, Ceil)?, "-2".parse()?);
+            /// assert_eq!(a.rlog("3".parse()?, Floor)?, "4.191806548".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            /// [rln]: #method.rln
+            pub fn rlog(self, base: Self, mode: RoundMode) -> Result<Self> {
+                if !self.is_positive() || !base.is_positive() || base.inner == Self::COEF {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                crate::ops::transcendental::log_ratio_scaled(
+                    self.magnitude()?,
+                    base.magnitude()?,
+                    Self::COEF_U128,
+                    mode,
+                )
+                .and_then(|inner| $layout::try_from(inner).ok())
+                .map(Self::from_bits)
+                .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// The same as [`rlog`][rlog].
+            ///
+            /// [rlog]: #method.rlog
+            #[inline]
+            pub fn log(self, base: Self, mode: RoundMode) -> Result<Self> {
+                self.rlog(base, mode)
+            }
+
+            /// Calculates `self^exp` as `2^(exp * log2(self))` using integer arithmetic only,
+            /// so results are deterministic and available in `no_std`.
+            ///
+            /// The relative error doesn't exceed `(|exp| + 32) * 2^-60` (about `1e-18` per unit
+            /// of `exp`) before the final [rounding][RoundMode], which is applied to the
+            /// approximation and thus isn't guaranteed to be directed for `Floor` and `Ceil`.
+            /// Use `cpowi` for integer exponents when exact results are needed.
+            ///
+            /// Returns `Err(DomainViolation)` for negative `self`, `Err(DivisionByZero)`
+            /// for zero `self` and negative `exp` and `Err(Overflow)` if the result is too big.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "4".parse()?;
+            /// assert_eq!(a.rpow("0.5".parse()?, Floor)?, "2".parse()?);
+            /// assert_eq!(a.rpow("-1.5".parse()?, Floor)?, "0.125".parse()?);
+            /// let rate: Amount = "1.05".parse()?;
+            /// assert_eq!(rate.rpow("2.5".parse()?, Nearest)?, "1.129726322".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rpow(self, exp: Self, mode: RoundMode) -> Result<Self> {
+                if self.is_negative() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+                if exp.is_zero() {
+                    return Ok(Self::ONE);
+                }
+                if self.is_zero() {
+                    return if exp.is_positive() {
+                        Ok(Self::ZERO)
+                    } else {
+                        Err(ArithmeticError::DivisionByZero)
+                    };
+                }
+
+                // `log2(self / COEF)` in binary fixed-point form.
+                let log = crate::ops::transcendental::log2(self.magnitude()?) as i128
+                    - crate::ops::transcendental::log2(Self::COEF_U128) as i128;
+
+                Self::exp2_mul(exp, log, mode)
+            }
+
+            /// Calculates the `n`-th root of `self` [rounded][RoundMode] according to `mode`.
+            /// Odd roots of negative numbers are negative, e.g. the cube root of `-8` is `-2`.
+            ///
+            /// The root is calculated using integer arithmetic only and is exact whenever
+            /// `self * COEF^n * 2^n` fits 256 bits, i.e. for cube roots of any layout with
+            /// precision up to 18 digits. Otherwise the relative error of the approximation
+            /// doesn't exceed `2^-55` (about `3e-17`) before the final rounding.
+            ///
+            /// Returns `Err(DomainViolation)` for zero `n` and for even roots of negative numbers.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "16".parse()?;
+            /// assert_eq!(a.rnth_root(4, Floor)?, "2".parse()?);
+            /// let b: Amount = "2".parse()?;
+            /// assert_eq!(b.rnth_root(5, Floor)?, "1.148698354".parse()?);
+            /// assert_eq!(b.rnth_root(5, Ceil)?, "1.148698355".parse()?);
+            /// let c: Amount = "-32".parse()?;
+            /// assert_eq!(c.rnth_root(5, Floor)?, "-2".parse()?);
+            /// assert!(c.rnth_root(4, Floor).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rnth_root(self, n: u32, mode: RoundMode) -> Result<Self> {
+                if n == 0 || (self.is_negative() && n % 2 == 0) {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+                if n == 1 || self.is_zero() {
+                    return Ok(self);
+                }
+
+                let sign = Self::signum_i32(self.inner);
+                let magnitude = crate::ops::transcendental::nth_root(
+                    self.magnitude()?,
+                    Self::COEF_U128,
+                    n,
+                    sign,
+                    mode,
+                )
+                .and_then(|inner| $layout::try_from(inner).ok())
+                .ok_or(ArithmeticError::Overflow)?;
+
+                // Odd roots of negative numbers are negative and can't overflow.
+                Ok(Self::from_bits(if sign < 0 { magnitude.wrapping_neg() } else { magnitude }))
+            }
+
+            /// Calculates the cube root of `self`, see [`rnth_root`][rnth_root] for details.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-27".parse()?;
+            /// assert_eq!(a.rcbrt(Ceil)?, "-3".parse()?);
+            /// let b: Amount = "2".parse()?;
+            /// assert_eq!(b.rcbrt(Floor)?, "1.259921049".parse()?);
+            /// assert_eq!(b.rcbrt(Ceil)?, "1.25992105".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rnth_root]: #method.rnth_root
+            pub fn rcbrt(self, mode: RoundMode) -> Result<Self> {
+                self.rnth_root(3, mode)
+            }
+
+            /// Calculates the sine of `self` radians using integer arithmetic only,
+            /// so results are deterministic across platforms and available in `no_std`.
+            ///
+            /// The absolute error doesn't exceed `2^-58` (about `3.5e-18`) before the final
+            /// [rounding][RoundMode]. Arguments are reduced modulo `π / 2` precisely enough for
+            /// any value below `2^68`, greater ones add the error up to `|self| * 2^-127`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1".parse()?;
+            /// assert_eq!(a.rsin(Floor)?, "0.841470984".parse()?);
+            /// assert_eq!(a.rsin(Ceil)?, "0.841470985".parse()?);
+            /// let b: Amount = "-0.5".parse()?;
+            /// assert_eq!(b.rsin(Nearest)?, "-0.479425539".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rsin(self, mode: RoundMode) -> Result<Self> {
+                let (sin, _) = crate::ops::transcendental::sin_cos(self.angle()?, Self::PRECISION as u32);
+                Self::from_binary(sin.unsigned_abs(), sin.signum() as i32, mode)
+            }
+
+            /// Calculates the cosine of `self` radians, see [`rsin`][rsin] for details.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1".parse()?;
+            /// assert_eq!(a.rcos(Floor)?, "0.540302305".parse()?);
+            /// assert_eq!(a.rcos(Ceil)?, "0.540302306".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rsin]: #method.rsin
+            pub fn rcos(self, mode: RoundMode) -> Result<Self> {
+                let (_, cos) = crate::ops::transcendental::sin_cos(self.angle()?, Self::PRECISION as u32);
+                Self::from_binary(cos.unsigned_abs(), cos.signum() as i32, mode)
+            }
+
+            /// Calculates the tangent of `self` radians as the ratio of the sine and the cosine,
+            /// see [`rsin`][rsin] for details. The absolute error of both is amplified
+            /// by about `1 / cos^2(self)`, so the result is less precise near odd multiples
+            /// of `π / 2`. Returns `Err(Overflow)` if the result doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1".parse()?;
+            /// assert_eq!(a.rtan(Floor)?, "1.557407724".parse()?);
+            /// assert_eq!(a.rtan(Ceil)?, "1.557407725".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rsin]: #method.rsin
+            pub fn rtan(self, mode: RoundMode) -> Result<Self> {
+                use crate::ops::transcendental::{ONE, LOG2_FRAC_BITS};
+
+                let (sin, cos) = crate::ops::transcendental::sin_cos(self.angle()?, Self::PRECISION as u32);
+                if cos == 0 {
+                    return Err(ArithmeticError::Overflow);
+                }
+                // `sin` can be `ONE` only if `cos` is negligible, so the shift mustn't overflow.
+                let numerator = sin.unsigned_abs().min(ONE - 1) << LOG2_FRAC_BITS;
+                let tan = numerator / cos.unsigned_abs();
+                let sign = if (sin < 0) == (cos < 0) { 1 } else { -1 };
+                Self::from_binary(tan, sign, mode)
+            }
+
+            /// Calculates the arctangent of `self` in radians, the result is in `[-π / 2, π / 2]`.
+            /// It's calculated using integer arithmetic only, so results are deterministic
+            /// across platforms and available in `no_std`.
+            ///
+            /// The absolute error doesn't exceed `2^-60` (about `1e-18`) before the final
+            /// [rounding][RoundMode]. Returns `Err(Overflow)` if the result doesn't fit
+            /// the layout, which is possible only for layouts with a tiny integral part.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1".parse()?;
+            /// assert_eq!(a.ratan(Floor)?, "0.785398163".parse()?);
+            /// assert_eq!(a.ratan(Ceil)?, "0.785398164".parse()?);
+            /// let b: Amount = "-2".parse()?;
+            /// assert_eq!(b.ratan(Nearest)?, "-1.107148718".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn ratan(self, mode: RoundMode) -> Result<Self> {
+                self.ratan2(Self::ONE, mode)
+            }
+
+            /// Calculates the angle in radians between the positive `x` axis and the point
+            /// `(x, self)`, the result is in `(-π, π]`. See [`ratan`][ratan] for details.
+            /// Returns `Err(DomainViolation)` if both `self` and `x` are zero.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Zero, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let y: Amount = "1".parse()?;
+            /// let x: Amount = "-1".parse()?;
+            /// assert_eq!(y.ratan2(x, Floor)?, "2.356194490".parse()?);
+            /// assert_eq!(Amount::ZERO.ratan2(x, Floor)?, "3.141592653".parse()?);
+            /// assert!(Amount::ZERO.ratan2(Amount::ZERO, Floor).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [ratan]: #method.ratan
+            pub fn ratan2(self, x: Self, mode: RoundMode) -> Result<Self> {
+                let angle = crate::ops::transcendental::atan2(self.angle()?, x.angle()?)
+                    .ok_or(ArithmeticError::DomainViolation)?;
+                Self::from_binary(angle.unsigned_abs(), angle.signum() as i32, mode)
+            }
+
+            /// Calculates the hyperbolic sine of `self` using integer arithmetic only,
+            /// so results are deterministic and available in `no_std`.
+            ///
+            /// The error doesn't exceed `2^-60` (about `1e-18`) for `|self| < 1` and `2^-54`
+            /// (about `6e-17`) relative to the result otherwise before the final
+            /// [rounding][RoundMode], which is applied to the approximation and thus isn't
+            /// guaranteed to be directed for `Floor` and `Ceil`. Returns `Err(Overflow)`
+            /// if the result doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1".parse()?;
+            /// assert_eq!(a.rsinh(Nearest)?, "1.175201194".parse()?);
+            /// let b: Amount = "-0.5".parse()?;
+            /// assert_eq!(b.rsinh(Nearest)?, "-0.521095305".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rsinh(self, mode: RoundMode) -> Result<Self> {
+                self.hyperbolic(crate::ops::transcendental::Hyperbolic::Sinh, mode)
+            }
+
+            /// Calculates the hyperbolic cosine of `self`, see [`rsinh`][rsinh] for details.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-1".parse()?;
+            /// assert_eq!(a.rcosh(Nearest)?, "1.543080635".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rsinh]: #method.rsinh
+            pub fn rcosh(self, mode: RoundMode) -> Result<Self> {
+                self.hyperbolic(crate::ops::transcendental::Hyperbolic::Cosh, mode)
+            }
+
+            /// Calculates the hyperbolic tangent of `self`, see [`rsinh`][rsinh] for details.
+            /// Unlike other hyperbolic functions it never overflows.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "0.5".parse()?;
+            /// assert_eq!(a.rtanh(Nearest)?, "0.462117157".parse()?);
+            /// let b: Amount = "-100".parse()?;
+            /// assert_eq!(b.rtanh(Floor)?, "-1".parse()?);
+            /// assert_eq!(b.rtanh(Ceil)?, "-0.999999999".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rsinh]: #method.rsinh
+            pub fn rtanh(self, mode: RoundMode) -> Result<Self> {
+                self.hyperbolic(crate::ops::transcendental::Hyperbolic::Tanh, mode)
+            }
+
+            /// Returns `self.inner` for trigonometric functions, which take `i128`.
+            /// Fails only for `u128` and `I256` values beyond `i128`.
+            #[inline]
+            fn angle(self) -> Result<i128> {
+                i128::try_from(self.inner).map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Returns `|self.inner|` for other transcendental functions, which take `u128`.
+            /// Fails only for `I256` values beyond `u128`.
+            #[inline]
+            fn magnitude(self) -> Result<u128> {
+                u128::try_from(self.inner.unsigned_abs()).map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Calculates the hyperbolic `function` of `self`.
+            fn hyperbolic(self, function: crate::ops::transcendental::Hyperbolic, mode: RoundMode) -> Result<Self> {
+                // Results for values beyond `i128` are the same as for `i128::MIN` or `i128::MAX`:
+                // `tanh` is one in magnitude and the others overflow.
+                let inner = i128::try_from(self.inner)
+                    .unwrap_or(if self.is_negative() { i128::MIN } else { i128::MAX });
+                crate::ops::transcendental::hyperbolic(inner, Self::PRECISION as u32, function, mode)
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Converts `sign * magnitude` from the binary fixed-point form of `log2` results.
+            fn from_binary(magnitude: u128, sign: i32, mode: RoundMode) -> Result<Self> {
+                crate::ops::transcendental::scale_binary(magnitude, sign, Self::COEF_U128, mode)
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Calculates `e^self` as `2^(self * log2(e))` using integer arithmetic only,
+            /// so results are deterministic and available in `no_std`.
+            ///
+            /// The relative error doesn't exceed `(|self| + 32) * 2^-60` before the final
+            /// [rounding][RoundMode], which is applied to the approximation and thus isn't
+            /// guaranteed to be directed for `Floor` and `Ceil`. Too small results are rounded
+            /// either to zero or to `EPSILON`. Returns `Err(Overflow)` if the result is too big.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{One, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::ONE.rexp(Nearest)?, "2.718281828".parse()?);
+            /// let rate: Amount = "-0.05".parse()?;
+            /// assert_eq!(rate.rexp(Nearest)?, "0.951229425".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rexp(self, mode: RoundMode) -> Result<Self> {
+                Self::exp2_mul(self, crate::ops::transcendental::LOG2_E as i128, mode)
+            }
+
+            /// Calculates the natural logarithm of `self` using integer arithmetic only,
+            /// so results are deterministic and available in `no_std`.
+            ///
+            /// The logarithm is approximated with the absolute error below `2^-117` (about `7e-36`),
+            /// so the result is correctly [rounded][RoundMode] unless the exact value is closer
+            /// than that to a rounding boundary. Values that close are taken to be on the boundary,
+            /// which keeps exact results like `log10(1000)` exact in all modes. For precisions
+            /// above 35 the error exceeds `EPSILON`.
+            ///
+            /// Returns `Err(DomainViolation)` for non-positive `self` and `Err(Overflow)`
+            /// if the result doesn't fit the layout (only for tiny values of high precisions).
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "10".parse()?;
+            /// assert_eq!(a.rln(Floor)?, "2.302585092".parse()?);
+            /// assert_eq!(a.rln(Ceil)?, "2.302585093".parse()?);
+            /// let b: Amount = "0.5".parse()?;
+            /// assert_eq!(b.rln(Nearest)?, "-0.693147181".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn rln(self, mode: RoundMode) -> Result<Self> {
+                self.log_scaled(crate::ops::transcendental::LN_2, mode)
+            }
+
+            /// Calculates the binary logarithm of `self`, see [`rln`][rln] for details.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "10".parse()?;
+            /// assert_eq!(a.rlog2(Floor)?, "3.321928094".parse()?);
+            /// assert_eq!(a.rlog2(Nearest)?, "3.321928095".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rln]: #method.rln
+            pub fn rlog2(self, mode: RoundMode) -> Result<Self> {
+                self.log_scaled(crate::ops::transcendental::FACTOR_ONE, mode)
+            }
+
+            /// Calculates the decimal logarithm of `self`, see [`rln`][rln] for details.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "2".parse()?;
+            /// assert_eq!(a.rlog10(Floor)?, "0.301029995".parse()?);
+            /// assert_eq!(a.rlog10(Ceil)?, "0.301029996".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [rln]: #method.rln
+            pub fn rlog10(self, mode: RoundMode) -> Result<Self> {
+                self.log_scaled(crate::ops::transcendental::LOG10_2, mode)
+            }
+
+            /// Calculates `log2(self) * factor` for `factor` in binary fixed-point form.
+            fn log_scaled(self, factor: u128, mode: RoundMode) -> Result<Self> {
+                if !self.is_positive() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                crate::ops::transcendental::log_scaled(self.magnitude()?, Self::COEF_U128, factor, mode)
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Calculates `2^(factor * log)`, where `log` is in binary fixed-point form.
+            fn exp2_mul(factor: Self, log: i128, mode: RoundMode) -> Result<Self> {
+                // `t = factor.inner * log / COEF`, the product can't fit 128 bits.
+                // Factors beyond `u128` are clamped as the exponent below.
+                let factor_magnitude = factor.magnitude().unwrap_or(u128::MAX);
+                let (high, low) =
+                    crate::ops::transcendental::widening_mul(factor_magnitude, log.unsigned_abs());
+                let (high, low) = crate::ops::transcendental::div_pow10(high, low, Self::PRECISION as u32);
+                // `2^256` is beyond any layout, so greater exponents can be clamped.
+                const MAX_EXPONENT: u128 = 256 << crate::ops::transcendental::LOG2_FRAC_BITS;
+                let t = if high == 0 { low.min(MAX_EXPONENT) } else { MAX_EXPONENT } as i128;
+                let t = if (factor.is_negative()) == (log < 0) { t } else { -t };
+
+                crate::ops::transcendental::exp2(t, Self::COEF_U128, mode)
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Checked sum of the sequence which reports the progress on overflow.
+            /// Returns `Err((partial_sum, index))` where `partial_sum` is the sum of elements
+            /// before the one at `index` that caused the overflow.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, One}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.5".parse()?;
+            /// assert_eq!(Amount::try_sum_detailed(vec![a, a]), Ok("3".parse()?));
+            /// assert_eq!(
+            ///     Amount::try_sum_detailed(vec![a, Amount::MAX, a]),
+            ///     Err((a, 1)),
+            /// );
+            /// # Ok(()) }
+            /// ```
+            pub fn try_sum_detailed<It>(
+                iter: It,
+            ) -> Result<FixedPoint<$layout, P>, (FixedPoint<$layout, P>, usize)>
+            where
+                It: IntoIterator<Item = FixedPoint<$layout, P>>,
+            {
+                iter.into_iter()
+                    .enumerate()
+                    .try_fold(Self::ZERO, |sum, (index, value)| {
+                        sum.cadd(value).map_err(|_| (sum, index))
+                    })
+            }
+
+            /// Rounds every value to `digits` decimal places so that the rounded values sum up
+            /// to the [rounded][RoundMode] total (the largest remainder method).
+            ///
+            /// Every value is floored at first, then the units left to reach the rounded total
+            /// are given to values with the largest remainders (earlier values win ties).
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let third: Amount = "33.333333333".parse()?;
+            /// let rest: Amount = "33.333333334".parse()?;
+            /// let rounded = Amount::round_preserving_sum(&[third, third, rest], 2, Floor)?;
+            /// // Naive rounding gives 33.33 + 33.33 + 33.33 = 99.99.
+            /// assert_eq!(rounded, vec!["33.33".parse()?, "33.33".parse()?, "33.34".parse()?]);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            pub fn round_preserving_sum(
+                values: &[FixedPoint<$layout, P>],
+                digits: u32,
+                mode: RoundMode,
+            ) -> Result<alloc::vec::Vec<FixedPoint<$layout, P>>> {
+                use alloc::vec::Vec;
+
+                if digits >= Self::PRECISION as u32 {
+                    return Ok(values.to_vec());
+                }
+
+                let unit =
+                    impl_fixed_point!(@from_int $layout, const_fn::pow10(Self::PRECISION - digits as i32));
+
+                let mut total = $layout::ZERO;
+                let mut floored_total = $layout::ZERO;
+                let mut rounded = Vec::with_capacity(values.len());
+                let mut remainders = Vec::with_capacity(values.len());
+
+                for (index, value) in values.iter().enumerate() {
+                    let floored = value.inner.rdiv(unit, RoundMode::Floor)?.cmul(unit)?;
+                    total = total.cadd(value.inner)?;
+                    floored_total = floored_total.cadd(floored)?;
+                    rounded.push(floored);
+                    remainders.push((index, value.inner - floored));
+                }
+
+                let rounded_total = total.rdiv(unit, mode)?.cmul(unit)?;
+                // `floored_total <= rounded_total <= floored_total + len * unit` always holds.
+                let missing_units = usize::try_from(rounded_total.csub(floored_total)? / unit)
+                    .map_err(|_| ArithmeticError::Overflow)?;
+
+                // The sort is stable, so earlier values win ties.
+                remainders.sort_by(|(_, a), (_, b)| b.cmp(a));
+                for &(index, _) in remainders.iter().take(missing_units) {
+                    rounded[index] = rounded[index].cadd(unit)?;
+                }
+
+                Ok(rounded.into_iter().map(Self::from_bits).collect())
+            }
+
+            /// Splits the value into `periods` equal [rounded][RoundMode] payments.
+            /// The last payment absorbs the rounding residual, so payments sum up to `self` exactly.
+            /// If rounding with `mode` makes the other payments exceed `self`, they're rounded
+            /// toward zero instead, so the last payment is never a refund.
+            /// Returns `Err(DivisionByZero)` if `periods` is zero.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let principal: Amount = "100".parse()?;
+            /// let payments = principal.amortize(3, Floor)?;
+            /// let payment: Amount = "33.333333333".parse()?;
+            /// assert_eq!(payments, vec![payment, payment, "33.333333334".parse()?]);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            pub fn amortize(
+                self,
+                periods: u32,
+                mode: RoundMode,
+            ) -> Result<alloc::vec::Vec<FixedPoint<$layout, P>>> {
+                use alloc::vec;
+
+                if periods == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let count = periods as usize;
+                let periods = $layout::try_from(periods).map_err(|_| ArithmeticError::Overflow)?;
+                let split = |mode| -> Result<(Self, Self)> {
+                    let payment = self.rdiv(periods, mode)?;
+                    Ok((payment, self.csub(payment.cmul(periods - <$layout as One>::ONE)?)?))
+                };
+                // Rounding toward zero can't overshoot, so the residual keeps the sign of `self`.
+                let (payment, last) = match split(mode) {
+                    Ok((payment, last)) if last.is_zero() || last.is_negative() == self.is_negative() => {
+                        (payment, last)
+                    }
+                    _ => split(RoundMode::TowardZero)?,
+                };
+
+                let mut payments = vec![payment; count];
+                payments[count - 1] = last;
+                Ok(payments)
+            }
+
+            /// Generates `[start, start * ratio, start * ratio^2, ...]` of length `count`.
+            /// Every multiplication is [rounded][RoundMode] separately, i.e. each element
+            /// is the previous one multiplied by `ratio`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let start: Amount = "100".parse()?;
+            /// let ratio: Amount = "1.1".parse()?;
+            /// let levels = Amount::geometric_sequence(start, ratio, 3, Floor)?;
+            /// assert_eq!(levels, vec![start, "110".parse()?, "121".parse()?]);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            pub fn geometric_sequence(
+                start: Self,
+                ratio: Self,
+                count: usize,
+                mode: RoundMode,
+            ) -> Result<alloc::vec::Vec<FixedPoint<$layout, P>>> {
+                use alloc::vec::Vec;
+
+                let mut sequence = Vec::with_capacity(count);
+                let mut current = start;
+
+                for i in 0..count {
+                    sequence.push(current);
+                    if i + 1 < count {
+                        current = current.rmul(ratio, mode)?;
+                    }
+                }
+
+                Ok(sequence)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Debug for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Display for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // Enough for all digits of the layout and the point.
+                let mut buffer = [0u8; max_decimal_digits(<$layout>::BITS) + 2];
+                let (len, zeros) = self.write_decimal(f, &mut buffer, None, '.');
+                pad_number(f, !self.is_negative(), &buffer[..len], zeros, &[])
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Display for Grouped<FixedPoint<$layout, P>> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // Enough for all digits of the layout, separators and the point.
+                let mut buffer = [0u8; 4 * max_decimal_digits(<$layout>::BITS)];
+                let (len, zeros) =
+                    self.value
+                        .write_decimal(f, &mut buffer, Some(self.separator), self.decimal_point);
+                pad_number(f, !self.value.is_negative(), &buffer[..len], zeros, &[])
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// The maximum length of the `Display` output without formatting flags.
+            pub const MAX_DISPLAY_LEN: usize = {
+                // The sign of signed layouts, the point and the zero written after it for integers.
+                let extra = impl_fixed_point!(@is_signed $kind) as usize + if Self::PRECISION == 0 { 2 } else { 1 };
+                max_decimal_digits(<$layout>::BITS - impl_fixed_point!(@is_signed $kind) as u32) + extra
+            };
+
+            /// Writes the value like `Display` does into any `fmt::Write` sink.
+            pub fn write_to(&self, w: &mut impl fmt::Write) -> fmt::Result {
+                write!(w, "{}", self)
+            }
+
+            /// Formats the value like `Display` does into a stack buffer, which fits any value
+            /// of the layout, so it doesn't allocate.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-12.5".parse()?;
+            /// assert_eq!(a.to_array_string(), "-12.5");
+            /// assert_eq!(Amount::MIN.to_array_string().len(), Amount::MAX_DISPLAY_LEN);
+            /// # Ok(()) }
+            /// ```
+            pub fn to_array_string(&self) -> ArrayString<{ max_decimal_digits(<$layout>::BITS - impl_fixed_point!(@is_signed $kind) as u32) + 3 }> {
+                let mut string = ArrayString::new();
+                // The capacity fits `MAX_DISPLAY_LEN` for any precision.
+                let _ = self.write_to(&mut string);
+                string
+            }
+
+            /// Returns an adapter to display the number with grouped thousands in the integral part
+            /// and a custom decimal point. Formatting flags work as for `Display`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-1234567.890123".parse()?;
+            /// assert_eq!(a.fmt_grouped(',', '.').to_string(), "-1,234,567.890123");
+            /// assert_eq!(format!("{:.2}", a.fmt_grouped(' ', ',')), "-1 234 567,89");
+            /// # Ok(()) }
+            /// ```
+            pub fn fmt_grouped(self, separator: char, decimal_point: char) -> Grouped<Self> {
+                Grouped {
+                    value: self,
+                    separator,
+                    decimal_point,
+                }
+            }
+
+            /// Writes the magnitude without the sign into `buffer` according to the precision and
+            /// the alternate flag of `f`. Returns the length and the number of trailing zeros
+            /// left to write because the precision exceeds `PRECISION`.
+            fn write_decimal(
+                &self,
+                f: &fmt::Formatter<'_>,
+                buffer: &mut [u8],
+                separator: Option<char>,
+                decimal_point: char,
+            ) -> (usize, usize) {
+                let (integral, fractional) = self.div_rem_coef();
+                let integral: ArrayString<{ max_decimal_digits(<$layout>::BITS) }> =
+                    ascii_digits(integral.unsigned_abs());
+                // The fractional part is less than `COEF`, which fits `u128`.
+                let mut fractional = u128::try_from(fractional.unsigned_abs()).unwrap();
+                let mut frac_width = Self::PRECISION as usize;
+                let mut zeros = 0;
+
+                match f.precision() {
+                    // Extra digits are truncated, missing ones are filled with zeros.
+                    Some(precision) if precision < frac_width => {
+                        fractional /= 10u128.pow((frac_width - precision) as u32);
+                        frac_width = precision;
+                    }
+                    Some(precision) => zeros = precision - frac_width,
+                    // The alternate form (`{:#}`) keeps trailing zeros up to `PRECISION` digits.
+                    None if f.alternate() => {}
+                    None => {
+                        if fractional == 0 {
+                            frac_width = 0;
+                        }
+                        while fractional > 0 && fractional % 10 == 0 {
+                            fractional /= 10;
+                            frac_width -= 1;
+                        }
+                    }
+                }
+
+                let mut pos = 0;
+                for (i, digit) in integral.bytes().enumerate() {
+                    if let Some(separator) = separator.filter(|_| i > 0 && (integral.len() - i) % 3 == 0) {
+                        pos += separator.encode_utf8(&mut buffer[pos..]).len();
+                    }
+                    buffer[pos] = digit;
+                    pos += 1;
+                }
+
+                if f.precision() != Some(0) {
+                    pos += decimal_point.encode_utf8(&mut buffer[pos..]).len();
+                    // Without the precision an integer is written as `1.0`.
+                    if frac_width > 0 || f.precision().is_none() {
+                        pos = write_digits(buffer, pos, fractional, frac_width);
+                    }
+                }
+
+                (pos, zeros)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Writes the value in normalized scientific notation. Like for integers, the optional
+            /// precision is the number of digits after the point, rounding half to even.
+            fn fmt_exp(&self, f: &mut fmt::Formatter<'_>, marker: u8) -> fmt::Result {
+                let magnitude: ArrayString<{ max_decimal_digits(<$layout>::BITS) }> =
+                    ascii_digits(self.inner.unsigned_abs());
+                let mut digits = [0u8; max_decimal_digits(<$layout>::BITS)];
+                let mut len = magnitude.len();
+                for (digit, ascii) in digits.iter_mut().zip(magnitude.bytes()) {
+                    *digit = ascii - b'0';
+                }
+
+                let mut exponent = if self.is_zero() { 0 } else { len as i32 - 1 - Self::PRECISION };
+                let significant = len - digits[1..len].iter().rev().take_while(|&&d| d == 0).count();
+                let kept = f.precision().map_or(significant, |precision| precision.saturating_add(1));
+
+                if kept < len {
+                    let (first_dropped, rest) = (digits[kept], &digits[kept + 1..len]);
+                    let is_odd = digits[kept - 1] % 2 == 1;
+                    let is_up = first_dropped > 5
+                        || first_dropped == 5 && (is_odd || rest.iter().any(|&d| d != 0));
+
+                    len = kept;
+                    if is_up {
+                        let mut index = kept;
+                        loop {
+                            if index == 0 {
+                                // All kept digits were nines: `9.99` becomes `1.00` with the next exponent.
+                                digits[0] = 1;
+                                exponent += 1;
+                                break;
+                            }
+                            index -= 1;
+                            if digits[index] == 9 {
+                                digits[index] = 0;
+                            } else {
+                                digits[index] += 1;
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                // Significant digits and the point.
+                let mut mantissa = [0u8; max_decimal_digits(<$layout>::BITS) + 1];
+                let mut pos = 0;
+                for (i, digit) in digits[..len].iter().enumerate() {
+                    if i == 1 {
+                        mantissa[pos] = b'.';
+                        pos += 1;
+                    }
+                    mantissa[pos] = b'0' + digit;
+                    pos += 1;
+                }
+
+                // Zeros requested by a precision larger than the number of digits.
+                let zeros = kept.saturating_sub(len);
+                if len == 1 && zeros > 0 {
+                    mantissa[pos] = b'.';
+                    pos += 1;
+                }
+
+                // The marker, the sign and up to 3 digits.
+                let mut suffix = [marker, b'-', 0, 0, 0];
+                let suffix_start = if exponent < 0 { 0 } else { 1 };
+                let suffix_end = write_digits(&mut suffix, 2, u128::from(exponent.unsigned_abs()), 1);
+                suffix[suffix_start] = marker;
+
+                pad_number(
+                    f,
+                    !self.is_negative(),
+                    &mantissa[..pos],
+                    zeros,
+                    &suffix[suffix_start..suffix_end],
+                )
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::LowerExp for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.fmt_exp(f, b'e')
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::UpperExp for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.fmt_exp(f, b'E')
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Creates `mantissa * 10^exponent`. Any exponent is accepted as long as the result
+            /// fits the layout and has at most `PRECISION` fractional digits.
+            /// Use [`from_decimal_rounded`][from_decimal_rounded] to round extra digits.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_decimal(15, -1)?, "1.5".parse()?);
+            /// assert_eq!(Amount::from_decimal(1500, -11)?, "0.000000015".parse()?);
+            /// assert!(Amount::from_decimal(15, -10).is_err());
+            /// assert!(Amount::from_decimal(1, 10).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [from_decimal_rounded]: #method.from_decimal_rounded
+            pub fn from_decimal(
+                mantissa: $layout,
+                exponent: i32,
+            ) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                let shift = exponent.saturating_add(Self::PRECISION);
+                let ten = $layout::from(10u8);
+
+                if shift < 0 {
+                    let is_exact = mantissa == $layout::ZERO
+                        || ten
+                            .checked_pow(shift.unsigned_abs())
+                            .map_or(false, |divisor| mantissa % divisor == $layout::ZERO);
+                    if !is_exact {
+                        return Err(ConvertError::new("requested precision is too high"));
+                    }
+                }
+
+                Self::from_decimal_rounded(mantissa, exponent, RoundMode::TowardZero)
+            }
+
+            /// Creates `mantissa * 10^exponent` [rounding][RoundMode] digits beyond `PRECISION`.
+            /// Returns `Err` if the result doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_decimal_rounded(15, -10, Floor)?, "0.000000001".parse()?);
+            /// assert_eq!(Amount::from_decimal_rounded(15, -10, Ceil)?, "0.000000002".parse()?);
+            /// assert_eq!(Amount::from_decimal_rounded(-15, -10, Nearest)?, "-0.000000002".parse()?);
+            /// assert_eq!(Amount::from_decimal_rounded(5, 9, Floor)?, "5000000000".parse()?);
+            /// assert!(Amount::from_decimal_rounded(1, 10, Floor).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn from_decimal_rounded(
+                mantissa: $layout,
+                exponent: i32,
+                mode: RoundMode,
+            ) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                let exponent = exponent.saturating_add(Self::PRECISION);
+                let ten = $layout::from(10u8);
+
+                if mantissa == $layout::ZERO {
+                    return Ok(Self::ZERO);
+                }
+
+                if exponent >= 0 {
+                    return ten
+                        .checked_pow(exponent as u32)
+                        .and_then(|multiplier| mantissa.checked_mul(multiplier))
+                        .map(Self::from_bits)
+                        .ok_or_else(|| ConvertError::new("too big mantissa"));
+                }
+
+                let power = exponent.unsigned_abs();
+                let inner = match ten.checked_pow(power) {
+                    Some(divisor) => mantissa
+                        .rdiv(divisor, mode)
+                        .map_err(|_| ConvertError::new("too big number"))?,
+                    // `|mantissa| < 10^power`, so the result is either zero or one `EPSILON`.
+                    None => {
+                        let sign = Self::signum_i32(mantissa);
+                        let is_half_or_more = || {
+                            ten.checked_pow(power - 1)
+                                .map_or(false, |tenth| (mantissa / tenth).abs() >= $layout::from(5u8))
+                        };
+                        if mode.is_away_from_zero_with(sign, is_half_or_more) {
+                            mantissa.signum()
+                        } else {
+                            $layout::ZERO
+                        }
+                    }
+                };
+
+                Ok(Self::from_bits(inner))
+            }
+
+            /// Parses a number like `FromStr`, but [rounds][RoundMode] fractional digits
+            /// beyond `PRECISION` instead of rejecting them. Scientific notation is supported too.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_str_with_mode("0.0000000015", Floor)?, "0.000000001".parse()?);
+            /// assert_eq!(Amount::from_str_with_mode("0.0000000015", Ceil)?, "0.000000002".parse()?);
+            /// assert_eq!(Amount::from_str_with_mode("-1.23456789049", Nearest)?, "-1.23456789".parse()?);
+            /// assert_eq!(Amount::from_str_with_mode("15e-10", Nearest)?, "0.000000002".parse()?);
+            /// assert!("0.0000000015".parse::<Amount>().is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn from_str_with_mode(str: &str, mode: RoundMode) -> Result<Self, ConvertError> {
+                let str = str.trim();
+
+                if let Some(index) = str.find(|c| c == 'e' || c == 'E') {
+                    return Self::from_str_scientific(&str[..index], &str[index + 1..], Some(mode));
+                }
+
+                let (truncated, is_negative, discarded) = Self::parse_truncated(str)?;
+                let sign = if is_negative { -1 } else { 1 };
+                let is_away_from_zero = discarded.map_or(false, |(first, is_nonzero)| {
+                    is_nonzero && mode.is_away_from_zero_with(sign, || first >= 5)
+                });
+                if !is_away_from_zero {
+                    return Ok(truncated);
+                }
+
+                let inner = truncated.inner;
+                let one = <$layout as One>::ONE;
+                if is_negative { inner.checked_sub(one) } else { inner.checked_add(one) }
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+
+            /// Parses a literal without an exponent truncating fractional digits beyond `PRECISION`.
+            /// Also returns whether the literal is negative and the first discarded digit with
+            /// whether any of discarded digits is nonzero.
+            fn parse_truncated(str: &str) -> Result<(Self, bool, Option<(u8, bool)>), ConvertError> {
+                let (is_negative, integral, fractional) = split_literal(str)?;
+                let integral = decimal_digits(integral)
+                    .ok_or_else(|| ConvertError::new("can't parse integral part"))?;
+                let fractional = decimal_digits(fractional).ok_or_else(|| {
+                    ConvertError::new("can't parse fractional part: must contain digits only")
+                })?;
+
+                // Accumulated with the sign of the literal to reach both `MIN` and `MAX`.
+                let accumulate = |acc: $layout, value: $layout| {
+                    if is_negative { acc.checked_sub(value) } else { acc.checked_add(value) }
+                };
+                let ten = $layout::from(10u8);
+                let mut inner = $layout::ZERO;
+                for digit in integral {
+                    inner = inner
+                        .checked_mul(ten)
+                        .and_then(|x| accumulate(x, $layout::from(digit)))
+                        .ok_or_else(|| ConvertError::new("too big integral part"))?;
+                }
+                inner = inner
+                    .checked_mul(Self::COEF)
+                    .ok_or_else(|| ConvertError::new("too big integral part"))?;
+
+                let mut scale = Self::COEF;
+                let mut discarded = None;
+                for digit in fractional {
+                    if scale > <$layout as One>::ONE {
+                        scale /= ten;
+                        inner = accumulate(inner, $layout::from(digit) * scale)
+                            .ok_or_else(|| ConvertError::new("too big number"))?;
+                    } else {
+                        let (first, is_nonzero) = discarded.unwrap_or((digit, false));
+                        discarded = Some((first, is_nonzero || digit != 0));
+                    }
+                }
+
+                Ok((Self::from_bits(inner), is_negative, discarded))
+            }
+
+            /// Parses scientific notation, e.g. `-1.5e-7`, with the exponent already split off.
+            /// Digits beyond `PRECISION` are rounded if `mode` is provided and rejected otherwise.
+            fn from_str_scientific(
+                significand: &str,
+                exponent: &str,
+                mode: Option<RoundMode>,
+            ) -> Result<Self, ConvertError> {
+                let exponent: i32 = exponent
+                    .parse()
+                    .map_err(|_| ConvertError::new("can't parse exponent"))?;
+
+                let (is_negative, integral, fractional) = split_literal(significand)?;
+                let integral = decimal_digits(integral)
+                    .ok_or_else(|| ConvertError::new("can't parse integral part"))?;
+                if decimal_digits(fractional).is_none() {
+                    return Err(ConvertError::new("can't parse fractional part: must contain digits only"));
+                }
+
+                // Trailing zeros don't change the value, but may overflow the mantissa.
+                let fractional = fractional.trim_end_matches(|c| c == '0' || c == '_');
+                let fractional_len = fractional.bytes().filter(u8::is_ascii_digit).count();
+
+                // Accumulated with the sign of the literal to reach both `MIN` and `MAX`.
+                let ten = $layout::from(10u8);
+                let mut mantissa = $layout::ZERO;
+                for digit in integral.chain(decimal_digits(fractional).into_iter().flatten()) {
+                    mantissa = mantissa
+                        .checked_mul(ten)
+                        .and_then(|m| {
+                            let digit = $layout::from(digit);
+                            if is_negative { m.checked_sub(digit) } else { m.checked_add(digit) }
+                        })
+                        .ok_or_else(|| ConvertError::new("too big mantissa"))?;
+                }
+
+                let exponent = i32::try_from(fractional_len)
+                    .ok()
+                    .and_then(|len| exponent.checked_sub(len))
+                    .ok_or_else(|| ConvertError::new("requested precision is too high"))?;
+
+                match mode {
+                    Some(mode) => Self::from_decimal_rounded(mantissa, exponent, mode),
+                    None => Self::from_decimal(mantissa, exponent),
+                }
+            }
+
+            /// Returns the normalized `(mantissa, exponent)` pair such that
+            /// `self = mantissa * 10^exponent`, i.e. the inverse of [`from_decimal`][from_decimal].
+            /// Trailing zeros of the mantissa are stripped while the exponent is less than
+            /// `max_exponent`, so the exponent lies in `-PRECISION..=max(max_exponent, -PRECISION)`.
+            /// Zero is represented with the exponent closest to `0` in this range.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-12.340".parse()?;
+            /// assert_eq!(a.to_decimal(10), (-1234, -2));
+            /// let b: Amount = "1200".parse()?;
+            /// assert_eq!(b.to_decimal(10), (12, 2));
+            /// assert_eq!(b.to_decimal(0), (1200, 0));
+            /// assert_eq!(Amount::from_decimal(12, 2)?, b);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [from_decimal]: #method.from_decimal
+            pub fn to_decimal(self, max_exponent: i32) -> ($layout, i32) {
+                let mut mantissa = self.inner;
+                let mut exponent = -Self::PRECISION;
+
+                if mantissa == $layout::ZERO {
+                    return ($layout::ZERO, max_exponent.min(0).max(exponent));
+                }
+
+                let ten = $layout::from(10u8);
+                while exponent < max_exponent && mantissa % ten == $layout::ZERO {
+                    mantissa /= ten;
+                    exponent += 1;
+                }
+
+                (mantissa, exponent)
+            }
+
+            /// Converts the value to another layout and precision at once:
+            /// rescales it to `Q` [rounding][RoundMode] if needed, then narrows to `J`.
+            /// Rescaling is done in `i128` (`u128` for unsigned layouts), so widening the layout
+            /// never fails because the intermediate value doesn't fit the current one.
+            /// Returns `Err` with the "too big number" reason if the result doesn't fit `J`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::{U2, U9, U18}, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            /// type Cents = FixedPoint<i32, U2>;
+            /// type Wide = FixedPoint<i128, U18>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.255".parse()?;
+            /// let floor: Cents = a.try_convert(Floor)?;
+            /// let ceil: Cents = a.try_convert(Ceil)?;
+            /// assert_eq!(floor, "1.25".parse()?);
+            /// assert_eq!(ceil, "1.26".parse()?);
+            ///
+            /// let b: Amount = "30000000".parse()?;
+            /// assert!(b.try_convert::<i32, U2>(Floor).is_err());
+            /// assert_eq!(b.try_convert::<i128, U18>(Floor)?, "30000000".parse::<Wide>()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn try_convert<J, Q>(self, mode: RoundMode) -> Result<FixedPoint<J, Q>, ConvertError>
+            where
+                J: TryFrom<impl_fixed_point!(@wide $layout, $kind)>,
+                Q: Precision,
+            {
+                type Wide = impl_fixed_point!(@wide $layout, $kind);
+                let ten = Wide::from(10u8);
+                let inner = Wide::from(self.inner);
+
+                let inner = if Q::I32 >= Self::PRECISION {
+                    ten.checked_pow((Q::I32 - Self::PRECISION) as u32)
+                        .and_then(|multiplier| inner.checked_mul(multiplier))
+                        .ok_or_else(|| ConvertError::new("too big number"))?
+                } else {
+                    // `Q < P`, so the divisor can't overflow if `COEF` doesn't.
+                    // Dividing by at least ten can't overflow either.
+                    let divisor = ten.pow((Self::PRECISION - Q::I32) as u32);
+                    inner
+                        .rdiv(divisor, mode)
+                        .map_err(|_| ConvertError::new("too big number"))?
+                };
+
+                J::try_from(inner)
+                    .map(FixedPoint::from_bits)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+
+            /// Like [`try_convert`], but fails instead of rounding.
+            /// Returns `Err` with the "requested precision is too low" reason if `Q` can't
+            /// represent the value exactly and with the "too big number" one if it doesn't fit `J`.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::{U2, U9}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            /// type Cents = FixedPoint<i32, U2>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "1.25".parse()?;
+            /// assert_eq!(a.try_convert_exact::<i32, U2>()?, "1.25".parse::<Cents>()?);
+            ///
+            /// let b: Amount = "1.255".parse()?;
+            /// assert!(b.try_convert_exact::<i32, U2>().is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [`try_convert`]: #method.try_convert
+            pub fn try_convert_exact<J, Q>(self) -> Result<FixedPoint<J, Q>, ConvertError>
+            where
+                J: TryFrom<impl_fixed_point!(@wide $layout, $kind)>,
+                Q: Precision,
+            {
+                if Q::I32 < Self::PRECISION {
+                    let divisor = Self::pow10((Self::PRECISION - Q::I32) as u32)
+                        .expect("`Q < P`, so it can't overflow if `COEF` doesn't");
+                    if self.inner % divisor != $layout::ZERO {
+                        return Err(ConvertError::new("requested precision is too low"));
+                    }
+                }
+
+                self.try_convert(RoundMode::TowardZero)
+            }
+
+            /// Interprets `value` as `value / 10^value_scale` and rescales it to `PRECISION`,
+            /// [rounding][RoundMode] if `value_scale` is greater than `PRECISION`.
+            /// Returns `Err` if the result doesn't fit the layout.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_scaled_int(1500, 3, Floor)?, "1.5".parse()?);
+            /// assert_eq!(Amount::from_scaled_int(15, 10, Ceil)?, "0.000000002".parse()?);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn from_scaled_int(
+                value: i128,
+                value_scale: u32,
+                mode: RoundMode,
+            ) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                let ten: i128 = 10;
+                let precision = Self::PRECISION as u32;
+
+                let inner = if value_scale <= precision {
+                    ten.checked_pow(precision - value_scale)
+                        .and_then(|multiplier| value.checked_mul(multiplier))
+                        .ok_or_else(|| ConvertError::new("too big number"))?
+                } else {
+                    match ten.checked_pow(value_scale - precision) {
+                        Some(divisor) => value
+                            .rdiv(divisor, mode)
+                            .map_err(|_| ConvertError::new("too big number"))?,
+                        // The divisor is greater than doubled `value`, so only directed rounding
+                        // can move it away from zero.
+                        None => {
+                            let sign = value.signum();
+                            if sign != 0 && mode.is_away_from_zero_with(sign as i32, || false) {
+                                sign
+                            } else {
+                                0
+                            }
+                        }
+                    }
+                };
+
+                $layout::try_from(inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| ConvertError::new("too big number"))
             }
 
-            // TODO: make this operation checked
-            pub fn rounding_to_i64(self) -> i64 {
-                let x = if self.inner > 0 {
-                    self.inner + Self::COEF / 2
-                } else {
-                    self.inner - Self::COEF / 2
-                };
-                (x / Self::COEF) as i64
+            /// Converts `f64` to the fixed-point number [rounding][RoundMode] it explicitly.
+            /// The exact binary value is rounded, so e.g. `1.1` (which is slightly greater
+            /// in binary) is rounded up to `1.100000001` for 9 decimal places with `Ceil`.
+            /// Returns `Err` for non-finite and out of range values.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_f64_rounded(0.0000000015, Floor)?, "0.000000001".parse()?);
+            /// assert_eq!(Amount::from_f64_rounded(0.0000000015, Ceil)?, "0.000000002".parse()?);
+            /// assert!(Amount::from_f64_rounded(f64::NAN, Floor).is_err());
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            pub fn from_f64_rounded(value: f64, mode: RoundMode) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                if !value.is_finite() {
+                    return Err(ConvertError::new("not finite"));
+                }
+
+                let (truncated, fraction) = scale_f64(value, Self::PRECISION as u32)
+                    .ok_or_else(|| ConvertError::new("too big number"))?;
+
+                let sign = if value.is_sign_negative() { -1 } else { 1 };
+                let is_away_from_zero = fraction.map_or(false, |fraction| {
+                    mode.is_away_from_zero_with(sign, || fraction != core::cmp::Ordering::Less)
+                });
+                let magnitude = if is_away_from_zero { add_to_scaled(truncated, 1) } else { truncated };
+
+                Self::from_f64_magnitude(value, magnitude)
             }
 
+            /// The same as [`from_f64_rounded`][from_f64_rounded].
+            ///
+            /// [from_f64_rounded]: #method.from_f64_rounded
             #[inline]
-            pub fn abs(self) -> Result<Self> {
-                if self.inner < 0 {
-                    self.cneg()
-                } else {
-                    Ok(self)
-                }
+            pub fn from_f64(value: f64, mode: RoundMode) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                Self::from_f64_rounded(value, mode)
             }
-        }
 
-        $(#[$attr])?
-        impl<P: Precision> fmt::Debug for FixedPoint<$layout, P> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", self)
+            /// Converts `f64` to the fixed-point number only if it's exactly representable,
+            /// i.e. it has at most `PRECISION` fractional digits in its exact binary value.
+            /// Note that most decimal literals like `0.1` aren't exact in binary.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::try_from_f64_exact(-2.375)?, "-2.375".parse()?);
+            /// assert!(Amount::try_from_f64_exact(0.1).is_err());
+            /// assert!(Amount::try_from_f64_exact(f64::INFINITY).is_err());
+            /// # Ok(()) }
+            /// ```
+            pub fn try_from_f64_exact(value: f64) -> Result<FixedPoint<$layout, P>, ConvertError> {
+                if !value.is_finite() {
+                    return Err(ConvertError::new("not finite"));
+                }
+
+                match scale_f64(value, Self::PRECISION as u32) {
+                    Some((magnitude, None)) => Self::from_f64_magnitude(value, magnitude),
+                    Some((_, Some(_))) => Err(ConvertError::new("requested precision is too low")),
+                    None => Err(ConvertError::new("too big number")),
+                }
             }
-        }
 
-        $(#[$attr])?
-        impl<P: Precision> fmt::Display for FixedPoint<$layout, P> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                let sign = self.inner.signum();
-                let integral = (self.inner / Self::COEF).abs();
-                let mut fractional = (self.inner % Self::COEF).abs();
-                let mut frac_width = if fractional > 0 {
-                    Self::PRECISION as usize
+            /// Makes the number from the `(high, low)` magnitude of its bits and the sign of `value`.
+            fn from_f64_magnitude(value: f64, (high, low): (u128, u128)) -> Result<Self, ConvertError> {
+                if high == 0 && low == 0 {
+                    return Ok(Self::ZERO);
+                }
+
+                // The magnitude of `MIN` is one more than `MAX` for signed layouts,
+                // so the magnitude is reduced by one before negation.
+                let (high, low) = if low == 0 { (high - 1, u128::MAX) } else { (high, low - 1) };
+                let low = $layout::try_from(low).ok();
+                let reduced = if high == 0 {
+                    low
                 } else {
-                    0
+                    // `2^128` fits no primitive layout, so `high * 2^128` is built by halves.
+                    let half_shift = $layout::try_from(1u128 << 64).ok();
+                    $layout::try_from(high)
+                        .ok()
+                        .zip(half_shift)
+                        .and_then(|(high, half_shift)| high.checked_mul(half_shift)?.checked_mul(half_shift))
+                        .zip(low)
+                        .and_then(|(high, low)| high.checked_add(low))
                 };
 
-                while fractional > 0 && fractional % 10 == 0 {
+                let one = <$layout as One>::ONE;
+                reduced
+                    .and_then(|reduced| {
+                        if value.is_sign_negative() {
+                            reduced.checked_neg()?.checked_sub(one)
+                        } else {
+                            reduced.checked_add(one)
+                        }
+                    })
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+
+            /// Decomposes the value into [decimal parts][DecimalParts].
+            /// If `PRECISION` exceeds 19 digits and the fractional part doesn't fit into `u64`,
+            /// the least significant digits are truncated. The integral part of `I256` values
+            /// saturates at `u128::MAX`.
+            ///
+            /// ```ignore
+            /// use fixnum::{DecimalParts, FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-12.034".parse()?;
+            /// assert_eq!(a.to_parts(), DecimalParts {
+            ///     negative: true,
+            ///     integral: 12,
+            ///     fractional: 34,
+            ///     fractional_len: 3,
+            /// });
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [DecimalParts]: ./struct.DecimalParts.html
+            pub fn to_parts(self) -> DecimalParts {
+                let (integral, fractional) = self.div_rem_coef();
+                let integral = u128::try_from(integral.unsigned_abs()).unwrap_or(u128::MAX);
+                // The fractional part is less than `COEF`, which fits `u128`.
+                let mut fractional = u128::try_from(fractional.unsigned_abs()).unwrap();
+                let mut fractional_len = if fractional > 0 { Self::PRECISION as u8 } else { 0 };
+
+                while fractional > 0 && (fractional % 10 == 0 || fractional > u64::MAX as u128) {
                     fractional /= 10;
-                    frac_width -= 1;
+                    fractional_len -= 1;
                 }
 
-                write!(
-                    f,
-                    "{}{}.{:0width$}",
-                    if sign < 0 { "-" } else { "" },
+                DecimalParts {
+                    negative: self.is_negative(),
                     integral,
-                    fractional,
-                    width = frac_width
-                )
+                    fractional: fractional as u64,
+                    fractional_len,
+                }
             }
-        }
 
-        $(#[$attr])?
-        impl<P: Precision> FixedPoint<$layout, P> {
-            pub fn from_decimal(
-                mantissa: $layout,
-                exponent: i32,
-            ) -> Result<FixedPoint<$layout, P>, ConvertError> {
-                if exponent < -Self::PRECISION || exponent > 10 {
-                    return Err(ConvertError::new("unsupported exponent"));
+            /// Creates `mantissa * 10^exponent` like [`from_decimal`][from_decimal],
+            /// but saturates at the numeric bounds ([`MIN`][MIN], [`MAX`][MAX]) instead of
+            /// failing on overflow. Digits beyond `PRECISION` are truncated (rounded towards zero),
+            /// so too small exponents result in zero.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, Zero}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Amount::from_decimal_saturating(15, -1), "1.5".parse()?);
+            /// assert_eq!(Amount::from_decimal_saturating(15, 20), Amount::MAX);
+            /// assert_eq!(Amount::from_decimal_saturating(-15, 20), Amount::MIN);
+            /// assert_eq!(Amount::from_decimal_saturating(15, -11), Amount::ZERO);
+            /// # Ok(()) }
+            /// ```
+            ///
+            /// [from_decimal]: #method.from_decimal
+            /// [MAX]: ./ops/trait.Bounded.html#associatedconstant.MAX
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            pub fn from_decimal_saturating(mantissa: $layout, exponent: i32) -> FixedPoint<$layout, P> {
+                let ten = $layout::from(10u8);
+                let exponent = exponent.saturating_add(Self::PRECISION);
+
+                if exponent < 0 {
+                    let divisor = ten.checked_pow(exponent.unsigned_abs());
+                    return Self::from_bits(divisor.map_or($layout::ZERO, |divisor| mantissa / divisor));
                 }
 
-                let ten: $layout = 10;
-                let multiplier = ten.pow((exponent + Self::PRECISION) as u32);
+                ten.checked_pow(exponent as u32)
+                    .and_then(|multiplier| mantissa.checked_mul(multiplier))
+                    .map_or_else(
+                        || {
+                            if mantissa.is_negative() {
+                                Self::MIN
+                            } else if mantissa == $layout::ZERO {
+                                Self::ZERO
+                            } else {
+                                Self::MAX
+                            }
+                        },
+                        Self::from_bits,
+                    )
+            }
 
-                mantissa
-                    .checked_mul(multiplier)
-                    .map(Self::from_bits)
-                    .map_or_else(|| Err(ConvertError::new("too big mantissa")), Ok)
+            /// Creates a value from basis points (`1 bp = 0.0001`).
+            /// Returns `Err(DomainViolation)` if `PRECISION` is too low to represent it exactly.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Ratio = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// assert_eq!(Ratio::from_basis_points(50)?, "0.005".parse()?);
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub fn from_basis_points(bps: $layout) -> Result<FixedPoint<$layout, P>> {
+                Self::from_fraction_of_pow10(bps, 4)
+            }
+
+            /// Converts the value to [rounded][RoundMode] basis points (`1 bp = 0.0001`).
+            /// Returns `Err(Overflow)` if the result doesn't fit into the layout.
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn to_basis_points(self, mode: RoundMode) -> Result<$layout> {
+                self.to_fraction_of_pow10(4, mode)
+            }
+
+            /// Creates a value from parts per million (`1 ppm = 0.000001`).
+            /// Returns `Err(DomainViolation)` if `PRECISION` is too low to represent it exactly.
+            #[inline]
+            pub fn from_ppm(ppm: $layout) -> Result<FixedPoint<$layout, P>> {
+                Self::from_fraction_of_pow10(ppm, 6)
+            }
+
+            /// Converts the value to [rounded][RoundMode] parts per million (`1 ppm = 0.000001`).
+            /// Returns `Err(Overflow)` if the result doesn't fit into the layout.
+            ///
+            /// [RoundMode]: ./ops/enum.RoundMode.html
+            #[inline]
+            pub fn to_ppm(self, mode: RoundMode) -> Result<$layout> {
+                self.to_fraction_of_pow10(6, mode)
+            }
+
+            /// Computes `parts / 10^exponent`.
+            fn from_fraction_of_pow10(parts: $layout, exponent: i32) -> Result<FixedPoint<$layout, P>> {
+                if Self::PRECISION >= exponent {
+                    let multiplier =
+                        impl_fixed_point!(@from_int $layout, const_fn::pow10(Self::PRECISION - exponent));
+                    return parts.cmul(multiplier).map(Self::from_bits);
+                }
+
+                let divisor =
+                    impl_fixed_point!(@from_int $layout, const_fn::pow10(exponent - Self::PRECISION));
+                if parts % divisor != $layout::ZERO {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                Ok(Self::from_bits(parts / divisor))
+            }
+
+            /// Computes `self * 10^exponent` rounded to an integer.
+            fn to_fraction_of_pow10(self, exponent: i32, mode: RoundMode) -> Result<$layout> {
+                if Self::PRECISION >= exponent {
+                    let divisor =
+                        impl_fixed_point!(@from_int $layout, const_fn::pow10(Self::PRECISION - exponent));
+                    return self.inner.rdiv(divisor, mode);
+                }
+
+                let multiplier =
+                    impl_fixed_point!(@from_int $layout, const_fn::pow10(exponent - Self::PRECISION));
+                self.inner.cmul(multiplier)
             }
         }
 
         impl<P: Precision> From<FixedPoint<$layout, P>> for f64 {
             fn from(value: FixedPoint<$layout, P>) -> Self {
                 let coef = FixedPoint::<$layout, P>::COEF;
-                let integral = (value.inner / coef) as f64;
-                let fractional = ((value.inner % coef) as f64) / (coef as f64);
+                let integral = impl_fixed_point!(@to_f64 $layout, value.inner / coef);
+                let fractional = impl_fixed_point!(@to_f64 $layout, value.inner % coef)
+                    / impl_fixed_point!(@to_f64 $layout, coef);
                 integral + fractional
             }
         }
 
-        #[cfg(feature = "std")]
-        impl<P: Precision> TryFrom<f64> for FixedPoint<$layout, P> {
-            type Error = ConvertError;
-
-            // TODO: it's a baseline implementation. See #18.
-            fn try_from(value: f64) -> Result<Self, Self::Error> {
-                use std::io::Write;
-
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Converts a float keeping at most `digits` significant decimal digits of it,
+            /// which are guaranteed to be preserved by the original float type.
+            ///
+            /// The result matches formatting the float with the kept number of fractional
+            /// digits (which rounds half to even) and parsing it back.
+            fn from_float(value: f64, digits: u32) -> Result<Self, ConvertError> {
                 if !value.is_finite() {
                     return Err(ConvertError::new("not finite"));
                 }
 
-                let mut buffer = [0u8; 64];
+                let too_big = || ConvertError::new("too big number");
+
+                // `ceil(log10(|value|))` for `|value| >= 1`, zero otherwise.
+                let (integral, fraction) = scale_f64(value, 0).ok_or_else(too_big)?;
+                // Integral parts beyond 128 bits have more digits than any float keeps.
+                let integral = if integral.0 == 0 { integral.1 } else { u128::MAX };
+                let mut order = 0;
+                while order < 39 && integral >= 10u128.pow(order) {
+                    order += 1;
+                }
+                if fraction.is_none() && order > 0 && integral == 10u128.pow(order - 1) {
+                    order -= 1;
+                }
+
+                let prec = digits.saturating_sub(order).min(Self::PRECISION as u32);
+                let (truncated, fraction) = scale_f64(value, prec).ok_or_else(too_big)?;
+                let rounded = match fraction {
+                    Some(core::cmp::Ordering::Greater) => add_to_scaled(truncated, 1),
+                    Some(core::cmp::Ordering::Equal) => add_to_scaled(truncated, truncated.1 & 1),
+                    _ => truncated,
+                };
 
-                let abs = value.abs();
+                let rounded = Self::from_f64_magnitude(value, rounded)?;
+                Self::pow10(Self::PRECISION as u32 - prec)
+                    .and_then(|multiplier| rounded.inner.checked_mul(multiplier))
+                    .map(Self::from_bits)
+                    .ok_or_else(too_big)
+            }
+        }
 
-                // f64 seems to have at least 15 significant decimal digits.
-                let prec = 15usize.saturating_sub(abs.log10().ceil() as usize)
-                    .min(Self::PRECISION as usize)
-                    .max(0);
+        impl<P: Precision> TryFrom<f64> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
 
-                write!(&mut buffer[..], "{:.*}", prec, value)
-                    .map_err(|_| ConvertError::new("too big number"))?;
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                Self::from_float(value, f64::DIGITS)
+            }
+        }
 
-                let s = std::str::from_utf8(&buffer)
-                    .map_err(|_| ConvertError::new("not finite"))?
-                    .trim_end_matches('\0');
+        /// Keeps the same number of significant digits as `f32` guarantees, so e.g. `0.1f32`
+        /// is converted to `0.1` despite being `0.100000001490116...` in binary.
+        impl<P: Precision> TryFrom<f32> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
 
-                s.parse().map_err(|_| ConvertError::new("not finite"))
+            fn try_from(value: f32) -> Result<Self, Self::Error> {
+                Self::from_float(f64::from(value), f32::DIGITS)
             }
         }
 
@@ -687,65 +3760,376 @@ macro_rules! impl_fixed_point {
         impl<P: Precision> FromStr for FixedPoint<$layout, P> {
             type Err = ConvertError;
 
+            /// Parses a decimal literal like `-1_234.5`, `+1.`, `.5` or `1.5e-7`.
+            /// Underscores are allowed between digits. Digits beyond `PRECISION` are rejected.
             fn from_str(str: &str) -> Result<Self, Self::Err> {
                 let str = str.trim();
-                let coef = Self::COEF;
-
-                let index = match str.find('.') {
-                    Some(index) => index,
-                    None => {
-                        let integral: $layout = str.parse().map_err(|_| {
-                            ConvertError::new("can't parse integral part of the str")
-                        })?;
-                        return integral
-                            .checked_mul(coef)
-                            .ok_or(ConvertError::new("too big integral part"))
-                            .map(Self::from_bits);
-                    }
-                };
-
-                let integral: $layout = str[0..index]
-                    .parse()
-                    .map_err(|_| ConvertError::new("can't parse integral part"))?;
-                let fractional_str = &str[index + 1..];
 
-                if !fractional_str.chars().all(|c| c.is_digit(10)) {
-                    return Err(ConvertError::new("can't parse fractional part: must contain digits only"));
+                if let Some(index) = str.find(|c| c == 'e' || c == 'E') {
+                    return Self::from_str_scientific(&str[..index], &str[index + 1..], None);
                 }
 
-                if fractional_str.len() > Self::PRECISION.abs() as usize {
-                    return Err(ConvertError::new("requested precision is too high"));
+                match Self::parse_truncated(str)? {
+                    (value, _, None) => Ok(value),
+                    (_, _, Some(_)) => Err(ConvertError::new("requested precision is too high")),
                 }
-
-                let ten: $layout = 10;
-                let exp = ten.pow(fractional_str.len() as u32);
-
-                if exp > coef {
-                    return Err(ConvertError::new("requested precision is too high"));
+            }
+        }
+    };
+    (@signed $layout:tt, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns `1` if `self` is positive, `-1` if it's negative and `0` otherwise.
+            ///
+            /// ```ignore
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{One, Zero}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// let a: Amount = "-0.000000001".parse()?;
+            /// assert_eq!(a.signum(), "-1".parse()?);
+            /// assert_eq!(Amount::ZERO.signum(), Amount::ZERO);
+            /// assert!(a.is_negative() && !a.is_positive() && !a.is_zero());
+            /// # Ok(()) }
+            /// ```
+            #[inline]
+            pub const fn signum(self) -> Self {
+                if self.is_negative() {
+                    Self::from_bits(impl_fixed_point!(@from_int $layout, -const_fn::pow10(Self::PRECISION)))
+                } else if self.is_zero() {
+                    Self::ZERO
+                } else {
+                    Self::ONE
                 }
+            }
 
-                let fractional: $layout = fractional_str.parse().map_err(|_| {
-                    ConvertError::new("can't parse fractional part")
-                })?;
-
-                let final_integral = integral.checked_mul(coef).ok_or(ConvertError::new("too big integral"))?;
-                let signum = if str.as_bytes()[0] == b'-' { -1 } else { 1 };
-                let final_fractional = signum * coef / exp * fractional;
+            /// Checks whether `self` is less than zero.
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                self.inner.is_negative()
+            }
+        }
+    };
+    (@unsigned $layout:tt, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns `1` if `self` is positive and `0` otherwise.
+            #[inline]
+            pub const fn signum(self) -> Self {
+                Self::from_bits(if self.inner > 0 { Self::COEF } else { 0 })
+            }
 
-                final_integral
-                    .checked_add(final_fractional)
-                    .map(Self::from_bits)
-                    .ok_or(ConvertError::new("too big number"))
+            /// Always returns `false`: unsigned values are never negative.
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                false
             }
         }
     };
+    // The widest primitive integer of the same signedness as the layout, or `I256` itself.
+    (@wide I256, signed) => { I256 };
+    (@wide $layout:tt, signed) => { i128 };
+    (@wide $layout:tt, unsigned) => { u128 };
+    (@is_signed signed) => { true };
+    (@is_signed unsigned) => { false };
+    // `I256` has neither literals nor `const` operators, so it builds constants by methods.
+    (@from_int I256, $value:expr) => { I256::from_i128($value) };
+    (@from_int $layout:tt, $value:expr) => { $value as $layout };
+    (@const_mul I256, $lhs:expr, $rhs:literal) => { $lhs.const_mul($rhs) };
+    (@const_mul $layout:tt, $lhs:expr, $rhs:literal) => { $lhs * $rhs };
+    (@is_zero I256, $value:expr) => { $value.is_zero() };
+    (@is_zero $layout:tt, $value:expr) => { $value == 0 };
+    // Truncates the integer to `i64` like `as i64` does.
+    (@as_i64 I256, $value:expr) => {{
+        let bytes = $value.to_le_bytes();
+        i64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }};
+    (@as_i64 $layout:tt, $value:expr) => { ($value) as i64 };
+    (@to_f64 I256, $value:expr) => { ($value).to_f64() };
+    (@to_f64 $layout:tt, $value:expr) => { ($value) as f64 };
 }
 
-#[cfg(any(feature = "i64", feature = "i32", feature = "i16"))]
+#[cfg(any(
+    feature = "i64",
+    feature = "i32",
+    feature = "i16",
+    feature = "u64",
+    feature = "u32"
+))]
 const fn identity<T>(x: T) -> T {
     x
 }
 
+/// Returns the number of decimal digits of `2^bits`, i.e. of the largest magnitude
+/// of an integer with `bits` bits besides the sign bit.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+const fn max_decimal_digits(bits: u32) -> usize {
+    // `log10(2) ~ 0.30103` is precise enough for up to 256 bits.
+    bits as usize * 30_103 / 100_000 + 1
+}
+
+/// Splits a decimal literal into the sign and the integral and fractional digits.
+/// Either part may be empty (e.g. `1.` and `.5`), but not both.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn split_literal(str: &str) -> Result<(bool, &str, &str), ConvertError> {
+    let (is_negative, unsigned) = match str.as_bytes().first() {
+        Some(b'-') => (true, &str[1..]),
+        Some(b'+') => (false, &str[1..]),
+        _ => (false, str),
+    };
+
+    let (integral, fractional) = match unsigned.find('.') {
+        Some(point) => (&unsigned[..point], &unsigned[point + 1..]),
+        None => (unsigned, ""),
+    };
+
+    if integral.is_empty() && fractional.is_empty() {
+        return Err(ConvertError::new("can't parse integral part"));
+    }
+
+    Ok((is_negative, integral, fractional))
+}
+
+/// Returns values of decimal digits in `str`, which may also contain underscores between
+/// digits, or `None` if there's anything else.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn decimal_digits(str: &str) -> Option<impl Iterator<Item = u8> + '_> {
+    let bytes = str.as_bytes();
+    let is_digit_at = |i: usize| matches!(bytes.get(i), Some(c) if c.is_ascii_digit());
+    let is_valid = bytes.iter().enumerate().all(|(i, c)| {
+        c.is_ascii_digit() || *c == b'_' && i > 0 && is_digit_at(i - 1) && is_digit_at(i + 1)
+    });
+
+    if !is_valid {
+        return None;
+    }
+
+    Some(
+        bytes
+            .iter()
+            .filter(|c| c.is_ascii_digit())
+            .map(|c| c - b'0'),
+    )
+}
+
+/// Writes decimal digits of `value` into `buffer` from `pos` with leading zeros up to `width`.
+/// Returns the position after the last digit.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn write_digits(buffer: &mut [u8], pos: usize, mut value: u128, width: usize) -> usize {
+    let mut end = pos;
+    while value > 0 || end - pos < width.max(1) {
+        buffer[end] = b'0' + (value % 10) as u8;
+        value /= 10;
+        end += 1;
+    }
+    buffer[pos..end].reverse();
+    end
+}
+
+/// Writes decimal digits of the unsigned `value` as ASCII. `N` must fit all of them.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn ascii_digits<const N: usize>(value: impl fmt::Display) -> ArrayString<N> {
+    use fmt::Write as _;
+
+    let mut digits = ArrayString::new();
+    let _ = write!(digits, "{}", value);
+    digits
+}
+
+/// Writes ASCII `digits`, then `zeros` zeros and `suffix` using `Formatter::pad_integral`,
+/// so the sign, the width and the fill are respected. If too many zeros are requested
+/// to fit the buffer, the number is written ignoring the width.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn pad_number(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    digits: &[u8],
+    zeros: usize,
+    suffix: &[u8],
+) -> fmt::Result {
+    let as_str = |bytes| core::str::from_utf8(bytes).map_err(|_| fmt::Error);
+
+    let mut buffer = [0u8; 128];
+    let len = digits.len() + zeros + suffix.len();
+    if len <= buffer.len() {
+        buffer[..digits.len()].copy_from_slice(digits);
+        buffer[digits.len()..digits.len() + zeros].fill(b'0');
+        buffer[digits.len() + zeros..len].copy_from_slice(suffix);
+        return f.pad_integral(is_nonnegative, "", as_str(&buffer[..len])?);
+    }
+
+    if !is_nonnegative {
+        f.write_str("-")?;
+    } else if f.sign_plus() {
+        f.write_str("+")?;
+    }
+    f.write_str(as_str(digits)?)?;
+    for _ in 0..zeros {
+        f.write_str("0")?;
+    }
+    f.write_str(as_str(suffix)?)
+}
+
+/// Computes `|value| * 10^power` for a finite float using only integer arithmetic.
+/// Returns the integral part as `(high, low)` halves of 256 bits and how the discarded
+/// fraction compares with one half (`None` if nothing is discarded) or `None` if the integral
+/// part doesn't fit 256 bits.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn scale_f64(value: f64, power: u32) -> Option<((u128, u128), Option<core::cmp::Ordering>)> {
+    use core::cmp::Ordering;
+
+    const MANTISSA_BITS: u32 = 52;
+
+    debug_assert!(value.is_finite() && power <= 38);
+
+    // `|value| = mantissa * 2^exponent`.
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> MANTISSA_BITS) & 0x7ff) as i32;
+    let fraction = u128::from(bits & ((1 << MANTISSA_BITS) - 1));
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << MANTISSA_BITS, biased_exponent - 1075)
+    };
+
+    // `|value| * 10^power = mantissa * 5^power * 2^(exponent + power)`. The product of the
+    // first two factors takes up to 142 bits, so it's kept as `(high, low)` halves.
+    let pow5 = 5u128.pow(power);
+    let low_product = mantissa * (pow5 & u128::from(u64::MAX));
+    let high_product = mantissa * (pow5 >> 64);
+    let (low, carry) = low_product.overflowing_add(high_product << 64);
+    let high = (high_product >> 64) + u128::from(carry);
+
+    let shift = exponent + power as i32;
+    if shift >= 0 {
+        if low == 0 {
+            return Some(((0, 0), None));
+        }
+        let leading_zeros = if high == 0 {
+            128 + low.leading_zeros()
+        } else {
+            high.leading_zeros()
+        };
+        if leading_zeros < shift as u32 {
+            return None;
+        }
+        let integral = match shift {
+            0 => (high, low),
+            1..=127 => (high << shift | low >> (128 - shift), low << shift),
+            _ => (low << (shift - 128), 0),
+        };
+        return Some((integral, None));
+    }
+
+    let shift = shift.unsigned_abs();
+    let integral = match shift {
+        1..=127 => (high >> shift, high << (128 - shift) | low >> shift),
+        128..=255 => (0, high >> (shift - 128)),
+        _ => (0, 0),
+    };
+
+    let has_half = match shift - 1 {
+        bit @ 0..=127 => low >> bit & 1 == 1,
+        bit @ 128..=255 => high >> (bit - 128) & 1 == 1,
+        _ => false,
+    };
+    let has_less_than_half = match shift - 1 {
+        0 => false,
+        bits @ 1..=127 => low & ((1 << bits) - 1) != 0,
+        128 => low != 0,
+        bits @ 129..=255 => low != 0 || high & ((1 << (bits - 128)) - 1) != 0,
+        _ => low != 0 || high != 0,
+    };
+
+    let fraction = match (has_half, has_less_than_half) {
+        (false, false) => None,
+        (false, true) => Some(Ordering::Less),
+        (true, false) => Some(Ordering::Equal),
+        (true, true) => Some(Ordering::Greater),
+    };
+    Some((integral, fraction))
+}
+
+/// Adds `addend` to the integral part returned by `scale_f64`, which is far below `2^256`.
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+fn add_to_scaled((high, low): (u128, u128), addend: u128) -> (u128, u128) {
+    let (low, carry) = low.overflowing_add(addend);
+    (high + u128::from(carry), low)
+}
+
 #[cfg(feature = "i16")]
 impl_fixed_point!(
     #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
@@ -753,6 +4137,7 @@ impl_fixed_point!(
     promoted_to = i32;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = signed;
 );
 #[cfg(feature = "i32")]
 impl_fixed_point!(
@@ -761,6 +4146,7 @@ impl_fixed_point!(
     promoted_to = i64;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = signed;
 );
 #[cfg(feature = "i64")]
 impl_fixed_point!(
@@ -769,6 +4155,7 @@ impl_fixed_point!(
     promoted_to = i128;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = signed;
 );
 #[cfg(feature = "i128")]
 impl_fixed_point!(
@@ -777,4 +4164,130 @@ impl_fixed_point!(
     promoted_to = I256;
     convert = I256::from_i128;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = signed;
+);
+#[cfg(feature = "i256")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i256")))]
+    inner = I256;
+    promoted_to = I512;
+    convert = I512::from_i256;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, I256];
+    kind = signed;
+);
+#[cfg(feature = "u32")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]
+    inner = u32;
+    promoted_to = u64;
+    convert = identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = unsigned;
+);
+#[cfg(feature = "u64")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]
+    inner = u64;
+    promoted_to = u128;
+    convert = identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = unsigned;
+);
+#[cfg(feature = "u128")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]
+    inner = u128;
+    promoted_to = U256;
+    convert = U256::from_u128;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    kind = unsigned;
+);
+
+#[allow(unused_macros)]
+macro_rules! impl_layout_conversions {
+    ($(#[$attr:meta])? $narrow:ident => $wide:ident) => {
+        // Both types share `P` and therefore `COEF`,
+        // so changing the representation doesn't change the value.
+        $(#[$attr])?
+        impl<P: Precision> From<FixedPoint<$narrow, P>> for FixedPoint<$wide, P> {
+            #[inline]
+            fn from(value: FixedPoint<$narrow, P>) -> Self {
+                Self::from_bits(value.inner.into())
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> TryFrom<FixedPoint<$wide, P>> for FixedPoint<$narrow, P> {
+            type Error = ConvertError;
+
+            #[inline]
+            fn try_from(value: FixedPoint<$wide, P>) -> Result<Self, Self::Error> {
+                $narrow::try_from(value.inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingDiv<FixedPoint<$narrow, P>> for FixedPoint<$wide, P> {
+            type Output = FixedPoint<$wide, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: FixedPoint<$narrow, P>, mode: RoundMode) -> Result<Self::Output> {
+                self.rdiv(FixedPoint::<$wide, P>::from(rhs), mode)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "i16", feature = "i32"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i16", feature = "i32"))))]
+    i16 => i32
+);
+#[cfg(all(feature = "i16", feature = "i64"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i16", feature = "i64"))))]
+    i16 => i64
+);
+#[cfg(all(feature = "i32", feature = "i64"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i32", feature = "i64"))))]
+    i32 => i64
+);
+#[cfg(all(feature = "i16", feature = "i128"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i16", feature = "i128"))))]
+    i16 => i128
+);
+#[cfg(all(feature = "i32", feature = "i128"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i32", feature = "i128"))))]
+    i32 => i128
+);
+#[cfg(all(feature = "i64", feature = "i128"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i64", feature = "i128"))))]
+    i64 => i128
+);
+#[cfg(all(feature = "i16", feature = "i256"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i16", feature = "i256"))))]
+    i16 => I256
+);
+#[cfg(all(feature = "i32", feature = "i256"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i32", feature = "i256"))))]
+    i32 => I256
+);
+#[cfg(all(feature = "i64", feature = "i256"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i64", feature = "i256"))))]
+    i64 => I256
+);
+#[cfg(all(feature = "i128", feature = "i256"))]
+impl_layout_conversions!(
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "i128", feature = "i256"))))]
+    i128 => I256
 );
@@ -127,6 +127,21 @@ macro_rules! impl_op {
             }
         }
     };
+    ($lhs:ty [rrem] $rhs:ty = $res:tt) => {
+        impl $crate::ops::RoundingRem<$rhs> for $lhs {
+            type Output = $res;
+            type Error = $crate::ArithmeticError;
+
+            #[inline]
+            fn rrem(
+                self,
+                rhs: $rhs,
+                mode: $crate::ops::RoundMode,
+            ) -> Result<$res, $crate::ArithmeticError> {
+                $crate::impl_op!(@checked_method (l = self, r = rhs) => l.rrem(r, mode), $res)
+            }
+        }
+    };
     (@method ($l:ident = $lhs:expr, $r:ident = $rhs:expr) => $op:expr, $res:tt) => {{
         use $crate::_priv::*;
         fn up<I, O: Operand<I>>(operand: O, _: impl FnOnce(I) -> $res) -> O::Promotion {
@@ -147,6 +162,261 @@ macro_rules! impl_op {
     }};
 }
 
+/// Macro to define a wrapper type around [`FixedPoint`][FixedPoint].
+///
+/// Generates a tuple struct with `Debug`, `Default`, `Clone`, `Copy`, `PartialEq`, `Eq`,
+/// `PartialOrd` and `Ord` derived, `From` conversions in both directions and forwarding
+/// implementations of [`Zero`][Zero], [`One`][One] and [`Bounded`][Bounded].
+/// Additional attributes (e.g. docs or derives) are passed through.
+/// Operations are provided by [`impl_op!`][impl_op].
+///
+/// ```
+/// use fixnum::{define_fixed_wrapper, impl_op, typenum::U9, FixedPoint};
+///
+/// type Fp64 = FixedPoint<i64, U9>;
+///
+/// define_fixed_wrapper! {
+///     /// Price of a single item.
+///     #[derive(Hash)]
+///     pub struct Price(Fp64);
+/// }
+///
+/// impl_op!(Price [cadd] Price = Price);
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::ops::*;
+/// let price = Price::ONE.cadd(Price::from(Fp64::ONE))?;
+/// assert_eq!(Fp64::from(price), "2".parse()?);
+/// # Ok(()) }
+/// ```
+///
+/// [Bounded]: ./ops/trait.Bounded.html
+/// [FixedPoint]: ./struct.FixedPoint.html
+/// [impl_op]: ./macro.impl_op.html
+/// [One]: ./ops/trait.One.html
+/// [Zero]: ./ops/trait.Zero.html
+#[macro_export]
+macro_rules! define_fixed_wrapper {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner_vis:vis $inner:ty);
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        $vis struct $name($inner_vis $inner);
+
+        impl From<$inner> for $name {
+            #[inline]
+            fn from(inner: $inner) -> Self {
+                Self(inner)
+            }
+        }
+
+        impl From<$name> for $inner {
+            #[inline]
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl $crate::ops::Zero for $name {
+            const ZERO: Self = Self(<$inner as $crate::ops::Zero>::ZERO);
+        }
+
+        impl $crate::ops::One for $name {
+            const ONE: Self = Self(<$inner as $crate::ops::One>::ONE);
+        }
+
+        impl $crate::ops::Bounded for $name {
+            const MIN: Self = Self(<$inner as $crate::ops::Bounded>::MIN);
+            const MAX: Self = Self(<$inner as $crate::ops::Bounded>::MAX);
+        }
+    };
+}
+
+/// Asserts that two [`FixedPoint`][FixedPoint] values are equal, optionally within a tolerance.
+///
+/// Unlike `assert_eq!`, the panic message contains raw bits of both values and
+/// the difference between them in ULPs (units of [`EPSILON`][EPSILON]).
+///
+/// ```
+/// use fixnum::{assert_fixnum_eq, typenum::U9, FixedPoint};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a: Amount = "0.3".parse()?;
+/// let b: Amount = "0.300000001".parse()?;
+/// assert_fixnum_eq!(a, a);
+/// assert_fixnum_eq!(a, b, Amount::EPSILON);
+/// # Ok(()) }
+/// ```
+///
+/// [EPSILON]: ./struct.FixedPoint.html#associatedconstant.EPSILON
+/// [FixedPoint]: ./struct.FixedPoint.html
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! assert_fixnum_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_fixnum_eq!($left, $right, $crate::ops::Zero::ZERO)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !left.approx_eq(*right, *tolerance) {
+                    panic!(
+                        "assertion failed: `left ≈ right` (tolerance: {})\n  \
+                         left: {} (bits: {})\n \
+                         right: {} (bits: {})\n  \
+                         diff: {} ULP",
+                        tolerance,
+                        left,
+                        left.as_bits(),
+                        right,
+                        right.as_bits(),
+                        left.as_bits().abs_diff(*right.as_bits()),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Macro to name the `typenum` type of the precision by an integer literal from `0` to `38`.
+/// Other literals fail to compile.
+///
+/// ```
+/// use fixnum::{precision, FixedPoint};
+///
+/// type Amount = FixedPoint<i64, precision!(9)>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let amount: Amount = "0.000000001".parse()?;
+/// assert_eq!(Amount::PRECISION, 9);
+/// # Ok(()) }
+/// ```
+#[macro_export]
+macro_rules! precision {
+    (0) => {
+        $crate::typenum::U0
+    };
+    (1) => {
+        $crate::typenum::U1
+    };
+    (2) => {
+        $crate::typenum::U2
+    };
+    (3) => {
+        $crate::typenum::U3
+    };
+    (4) => {
+        $crate::typenum::U4
+    };
+    (5) => {
+        $crate::typenum::U5
+    };
+    (6) => {
+        $crate::typenum::U6
+    };
+    (7) => {
+        $crate::typenum::U7
+    };
+    (8) => {
+        $crate::typenum::U8
+    };
+    (9) => {
+        $crate::typenum::U9
+    };
+    (10) => {
+        $crate::typenum::U10
+    };
+    (11) => {
+        $crate::typenum::U11
+    };
+    (12) => {
+        $crate::typenum::U12
+    };
+    (13) => {
+        $crate::typenum::U13
+    };
+    (14) => {
+        $crate::typenum::U14
+    };
+    (15) => {
+        $crate::typenum::U15
+    };
+    (16) => {
+        $crate::typenum::U16
+    };
+    (17) => {
+        $crate::typenum::U17
+    };
+    (18) => {
+        $crate::typenum::U18
+    };
+    (19) => {
+        $crate::typenum::U19
+    };
+    (20) => {
+        $crate::typenum::U20
+    };
+    (21) => {
+        $crate::typenum::U21
+    };
+    (22) => {
+        $crate::typenum::U22
+    };
+    (23) => {
+        $crate::typenum::U23
+    };
+    (24) => {
+        $crate::typenum::U24
+    };
+    (25) => {
+        $crate::typenum::U25
+    };
+    (26) => {
+        $crate::typenum::U26
+    };
+    (27) => {
+        $crate::typenum::U27
+    };
+    (28) => {
+        $crate::typenum::U28
+    };
+    (29) => {
+        $crate::typenum::U29
+    };
+    (30) => {
+        $crate::typenum::U30
+    };
+    (31) => {
+        $crate::typenum::U31
+    };
+    (32) => {
+        $crate::typenum::U32
+    };
+    (33) => {
+        $crate::typenum::U33
+    };
+    (34) => {
+        $crate::typenum::U34
+    };
+    (35) => {
+        $crate::typenum::U35
+    };
+    (36) => {
+        $crate::typenum::U36
+    };
+    (37) => {
+        $crate::typenum::U37
+    };
+    (38) => {
+        $crate::typenum::U38
+    };
+}
+
 /// Macro to create fixed-point const "literals".
 ///
 /// ```
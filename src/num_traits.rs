@@ -0,0 +1,250 @@
+//! `core::ops` and [`num-traits`](https://docs.rs/num-traits) implementations for `FixedPoint`.
+//!
+//! Unlike the rest of the crate, these operators can't report errors or take a rounding mode:
+//! they panic on overflow (as integer operators in debug builds) and round toward zero
+//! (as integer division).
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{
+    ops::{
+        self, CheckedAdd as _, CheckedSub as _, RoundMode, RoundingDiv as _, RoundingMul as _,
+        RoundingRem as _,
+    },
+    ArithmeticError, ConvertError, FixedPoint, Precision,
+};
+
+macro_rules! impl_num_traits {
+    ($layout:ty) => {
+        impl_num_traits!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        /// Panics on overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Add for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self.cadd(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        /// Panics on overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Sub for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self.csub(rhs).expect("attempt to subtract with overflow")
+            }
+        }
+
+        /// Rounds toward zero, panics on overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Mul for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.rmul(rhs, RoundMode::TowardZero).expect("attempt to multiply with overflow")
+            }
+        }
+
+        /// Rounds toward zero, panics on overflow and division by zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Div for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                match self.rdiv(rhs, RoundMode::TowardZero) {
+                    Ok(value) => value,
+                    Err(ArithmeticError::DivisionByZero) => panic!("attempt to divide by zero"),
+                    Err(_) => panic!("attempt to divide with overflow"),
+                }
+            }
+        }
+
+        /// Has the sign of `self` (as `f64` remainder), panics on division by zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Rem for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, rhs: Self) -> Self {
+                match self.rrem(rhs, RoundMode::TowardZero) {
+                    Ok(value) => value,
+                    Err(ArithmeticError::DivisionByZero) => {
+                        panic!("attempt to calculate the remainder with a divisor of zero")
+                    }
+                    Err(_) => panic!("attempt to calculate the remainder with overflow"),
+                }
+            }
+        }
+
+        /// Panics on overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> Neg for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self.cneg().expect("attempt to negate with overflow")
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::Zero for FixedPoint<$layout, P> {
+            #[inline]
+            fn zero() -> Self {
+                <Self as ops::Zero>::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.inner == 0
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::One for FixedPoint<$layout, P> {
+            #[inline]
+            fn one() -> Self {
+                <Self as ops::One>::ONE
+            }
+        }
+
+        /// Only radix 10 is supported.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision + Ord> num_traits::Num for FixedPoint<$layout, P> {
+            type FromStrRadixErr = ConvertError;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ConvertError> {
+                if radix != 10 {
+                    return Err(ConvertError::new("unsupported radix"));
+                }
+                str.parse()
+            }
+        }
+
+        /// `abs` panics on overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision + Ord> num_traits::Signed for FixedPoint<$layout, P> {
+            #[inline]
+            fn abs(&self) -> Self {
+                Self::abs(*self).expect("attempt to negate with overflow")
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                if *self <= *other {
+                    <Self as ops::Zero>::ZERO
+                } else {
+                    *self - *other
+                }
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                Self::signum(*self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                Self::is_positive(*self)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                Self::is_negative(*self)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::Bounded for FixedPoint<$layout, P> {
+            #[inline]
+            fn min_value() -> Self {
+                <Self as ops::Bounded>::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                <Self as ops::Bounded>::MAX
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::CheckedAdd for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                self.cadd(*rhs).ok()
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::CheckedSub for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                self.csub(*rhs).ok()
+            }
+        }
+
+        /// Rounds toward zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::CheckedMul for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                self.rmul(*rhs, RoundMode::TowardZero).ok()
+            }
+        }
+
+        /// Rounds toward zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision> num_traits::CheckedDiv for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                self.rdiv(*rhs, RoundMode::TowardZero).ok()
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        $(#[$attr])?
+        impl<P: Precision + Ord> num_traits::Saturating for FixedPoint<$layout, P> {
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self {
+                ops::CheckedAdd::saturating_add(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                ops::CheckedSub::saturating_sub(self, rhs)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_num_traits!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_num_traits!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_num_traits!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_num_traits!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
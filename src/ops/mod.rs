@@ -1,6 +1,19 @@
+use core::ops::{Add, Sub};
+
 use crate::ArithmeticError;
 
 pub(crate) mod sqrt;
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+pub mod transcendental;
 
 use sqrt::Sqrt;
 
@@ -214,6 +227,51 @@ pub trait CheckedMul<Rhs = Self> {
 pub enum RoundMode {
     Ceil = 1,
     Floor = -1,
+    /// Rounds to the nearest value, halfway cases are rounded away from zero.
+    Nearest = 0,
+    /// Rounds towards zero, i.e. truncates the value regardless of its sign.
+    TowardZero = 2,
+    /// Rounds away from zero regardless of the sign of the value.
+    AwayFromZero = 3,
+}
+
+impl RoundMode {
+    /// Decides whether the quotient truncated towards zero must be moved away from zero.
+    /// `sign` is the sign of the exact quotient and `loss` is the nonzero remainder
+    /// of the division by `divisor`.
+    #[inline]
+    pub(crate) fn is_away_from_zero<T>(self, sign: i32, loss: T, divisor: T) -> bool
+    where
+        T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>,
+    {
+        self.is_away_from_zero_with(sign, || {
+            // `|loss| >= |divisor| - |loss|` without negating `MIN`, so unsigned `T` works too.
+            // `|loss| < |divisor|`, so none of the operations below overflows.
+            match (loss > T::ZERO, divisor > T::ZERO) {
+                (true, true) => loss >= divisor - loss,
+                (false, false) => loss <= divisor - loss,
+                (true, false) => T::ZERO - loss <= divisor + loss,
+                (false, true) => T::ZERO - loss >= divisor + loss,
+            }
+        })
+    }
+
+    /// Same as `is_away_from_zero`, but the discarded part is compared with the half
+    /// of the unit by `is_half_or_more`, which is called only for `Nearest`.
+    #[inline]
+    pub(crate) fn is_away_from_zero_with(
+        self,
+        sign: i32,
+        is_half_or_more: impl FnOnce() -> bool,
+    ) -> bool {
+        match self {
+            RoundMode::Ceil => sign > 0,
+            RoundMode::Floor => sign < 0,
+            RoundMode::Nearest => is_half_or_more(),
+            RoundMode::TowardZero => false,
+            RoundMode::AwayFromZero => true,
+        }
+    }
 }
 
 pub trait RoundingMul<Rhs = Self> {
@@ -320,6 +378,80 @@ pub trait RoundingDiv<Rhs = Self> {
     /// [FixedPoint]: ../struct.FixedPoint.html
     /// [RoundMode]: ./enum.RoundMode.html
     fn rdiv(self, rhs: Rhs, mode: RoundMode) -> Result<Self::Output, Self::Error>;
+
+    /// Saturating rounded division. Computes `self / rhs`, saturating at the numeric bounds
+    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
+    /// Division by zero saturates by the sign of `self`: `MAX` for positive, `MIN` for negative
+    /// and `ZERO` for `0 / 0`.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Zero, Bounded, RoundMode::*, RoundingDiv}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let a: Amount = "1".parse()?;
+    /// let b: Amount = "3".parse()?;
+    /// assert_eq!(a.saturating_rdiv(b, Ceil), "0.333333334".parse()?);
+    ///
+    /// let c: Amount = "0.1".parse()?;
+    /// // MAX / (SaturatingFloor) 0.1 = MAX
+    /// assert_eq!(Amount::MAX.saturating_rdiv(c, Floor), Amount::MAX);
+    /// // MAX / (SaturatingFloor) -0.1 = MIN
+    /// assert_eq!(Amount::MAX.saturating_rdiv(c.cneg()?, Floor), Amount::MIN);
+    ///
+    /// assert_eq!(b.cneg()?.saturating_rdiv(Amount::ZERO, Floor), Amount::MIN);
+    /// assert_eq!(Amount::ZERO.saturating_rdiv(Amount::ZERO, Floor), Amount::ZERO);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
+    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    fn saturating_rdiv(self, rhs: Rhs, mode: RoundMode) -> Self::Output
+    where
+        Self: PartialOrd + Zero + Sized,
+        Rhs: PartialOrd + Zero,
+        Self::Output: Bounded + Zero,
+    {
+        let is_lhs_zero = self == Self::ZERO;
+        let is_lhs_negative = self < Self::ZERO;
+        let is_rhs_negative = rhs < Rhs::ZERO;
+        match self.rdiv(rhs, mode) {
+            Ok(result) => result,
+            Err(_) if is_lhs_zero => Self::Output::ZERO,
+            Err(_) if is_lhs_negative == is_rhs_negative => Self::Output::MAX,
+            Err(_) => Self::Output::MIN,
+        }
+    }
+}
+
+pub trait RoundingRem<Rhs = Self> {
+    type Output;
+    type Error;
+
+    /// Checked remainder of the division, consistent with [`rdiv`][rdiv]:
+    /// the quotient is [rounded][RoundMode] to an integer `q` and the result is `self - q * rhs`.
+    /// Thus `Floor` gives the remainder with the sign of `rhs`, `TowardZero` — with the sign
+    /// of `self` (like `%` for integers). Returns `Err` on attempt to divide by zero or if
+    /// the remainder is out of the layout.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{RoundingRem, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let a: Amount = "-7.5".parse()?;
+    /// let b: Amount = "2".parse()?;
+    /// assert_eq!(a.rrem(b, TowardZero)?, "-1.5".parse()?);
+    /// assert_eq!(a.rrem(b, Floor)?, "0.5".parse()?);
+    /// assert_eq!(a.rrem(2, Ceil)?, "-1.5".parse()?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [rdiv]: ./trait.RoundingDiv.html#tymethod.rdiv
+    /// [RoundMode]: ./enum.RoundMode.html
+    fn rrem(self, rhs: Rhs, mode: RoundMode) -> Result<Self::Output, Self::Error>;
 }
 
 pub trait RoundingSqrt: Sized {
@@ -349,6 +481,41 @@ pub trait RoundingSqrt: Sized {
     fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error>;
 }
 
+pub trait RoundingDot: Sized {
+    type Error;
+
+    /// Checked dot product of two slices, see [`dot`][dot].
+    ///
+    /// [dot]: ./fn.dot.html
+    fn rdot(xs: &[Self], ys: &[Self], mode: RoundMode) -> Result<Self, Self::Error>;
+}
+
+/// Checked dot product `xs[0] * ys[0] + xs[1] * ys[1] + ...`.
+/// Products are accumulated in the promoted type and the sum is [rounded][RoundMode] only
+/// once, so the result doesn't suffer from per-term rounding errors.
+/// Returns `Err(DomainViolation)` if slices have different lengths and `Err(Overflow)`
+/// if the result (or the promoted accumulator) is out of range.
+///
+/// ```ignore
+/// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::{dot, RoundMode::*}};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a: Amount = "0.000000001".parse()?;
+/// let b: Amount = "0.5".parse()?;
+/// // Each term alone would be rounded to zero by `rmul`.
+/// assert_eq!(dot(&[a, a], &[b, b], Floor)?, a);
+/// assert_eq!(dot(&[a, a], &[b], Floor), Err(ArithmeticError::DomainViolation));
+/// # Ok(()) }
+/// ```
+///
+/// [RoundMode]: ./enum.RoundMode.html
+#[inline]
+pub fn dot<T: RoundingDot>(xs: &[T], ys: &[T], mode: RoundMode) -> Result<T, T::Error> {
+    T::rdot(xs, ys, mode)
+}
+
 // Impls for primitives.
 
 macro_rules! impl_for_ints {
@@ -356,6 +523,34 @@ macro_rules! impl_for_ints {
         $( impl_for_ints!(@single $int); )*
     };
     (@single $int:ty) => {
+        impl_for_ints!(@common $int);
+
+        impl RoundingDiv for $int {
+            type Output = $int;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self::Output, Self::Error> {
+                if rhs == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let mut result = self / rhs;
+                let loss = self - result * rhs;
+
+                if loss != 0 {
+                    let sign = self.signum() * rhs.signum();
+
+                    if mode.is_away_from_zero(sign as i32, loss, rhs) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(result)
+            }
+        }
+    };
+    (@common $int:ty) => {
         impl Zero for $int {
             const ZERO: Self = 0;
         }
@@ -414,6 +609,38 @@ macro_rules! impl_for_ints {
             }
         }
 
+        impl RoundingSqrt for $int {
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error> {
+                let lo = self.sqrt()?;
+                Ok(match mode {
+                    RoundMode::Floor | RoundMode::TowardZero => lo,
+                    RoundMode::Ceil | RoundMode::AwayFromZero => if lo * lo == self { lo } else {
+                        lo + <$int>::ONE
+                    },
+                    // `(lo + 1/2)^2 = lo^2 + lo + 1/4`, so a tie is impossible for integers.
+                    RoundMode::Nearest => if self - lo * lo > lo {
+                        lo + <$int>::ONE
+                    } else {
+                        lo
+                    },
+                })
+            }
+        }
+    };
+}
+
+impl_for_ints!(i8, i16, i32, i64, i128);
+
+macro_rules! impl_for_uints {
+    ($( $int:ty ),+ $(,)?) => {
+        $( impl_for_uints!(@single $int); )*
+    };
+    (@single $int:ty) => {
+        impl_for_ints!(@common $int);
+
         impl RoundingDiv for $int {
             type Output = $int;
             type Error = ArithmeticError;
@@ -427,33 +654,53 @@ macro_rules! impl_for_ints {
                 let mut result = self / rhs;
                 let loss = self - result * rhs;
 
-                if loss != 0 {
-                    let sign = self.signum() * rhs.signum();
-
-                    if mode as i32 == sign as i32 {
-                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
-                    }
+                // The quotient is never negative, so the `Floor` and `TowardZero` are the same.
+                if loss != 0 && mode.is_away_from_zero_with(1, || loss >= rhs - loss) {
+                    result = result.checked_add(1).ok_or(ArithmeticError::Overflow)?;
                 }
 
                 Ok(result)
             }
         }
+    };
+}
 
-        impl RoundingSqrt for $int {
-            type Error = ArithmeticError;
+impl_for_uints!(u8, u16, u32, u64, u128);
 
-            #[inline]
-            fn rsqrt(self, mode: RoundMode) -> Result<Self, Self::Error> {
-                let lo = self.sqrt()?;
-                Ok(match mode {
-                    RoundMode::Floor => lo,
-                    RoundMode::Ceil => if lo * lo == self { lo } else {
-                        lo + <$int>::ONE
-                    },
-                })
+/// Sign-related methods of signed integers for unsigned ones, which are never negative.
+/// It lets signed and unsigned layouts of `FixedPoint` share the code.
+#[cfg(any(feature = "u32", feature = "u64", feature = "u128"))]
+pub(crate) trait UnsignedExt: Copy {
+    fn signum(self) -> Self;
+
+    #[inline]
+    fn is_negative(self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn unsigned_abs(self) -> Self {
+        self
+    }
+}
+
+macro_rules! impl_unsigned_ext {
+    ($( $int:ty ),+ $(,)?) => {
+        $(
+            #[cfg(any(feature = "u32", feature = "u64", feature = "u128"))]
+            impl UnsignedExt for $int {
+                #[inline]
+                fn signum(self) -> Self {
+                    (self != 0) as $int
+                }
             }
-        }
+        )*
     };
 }
 
-impl_for_ints!(i8, i16, i32, i64, i128); // TODO: unsigned?
+impl_unsigned_ext!(u32, u64, u128);
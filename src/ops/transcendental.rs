@@ -0,0 +1,783 @@
+//! Logarithms and other transcendental functions calculated using integer arithmetic only,
+//! so results are deterministic and available in `no_std`.
+//!
+//! `FixedPoint` has the same methods inherently, the trait is for code generic over numbers.
+
+use core::{cmp::Ordering, convert::TryFrom};
+
+use crate::ops::RoundMode;
+
+pub trait RoundingLog: Sized {
+    type Error;
+
+    /// Checked [rounding][RoundMode] natural logarithm.
+    /// Returns `Err` for non-positive argument.
+    ///
+    /// ```ignore
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{transcendental::RoundingLog, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let a: Amount = "10".parse()?;
+    /// assert_eq!(RoundingLog::rln(a, Floor)?, "2.302585092".parse()?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [RoundMode]: ../enum.RoundMode.html
+    fn rln(self, mode: RoundMode) -> Result<Self, Self::Error>;
+
+    /// Checked [rounding][RoundMode] binary logarithm, see [`rln`][rln].
+    ///
+    /// [RoundMode]: ../enum.RoundMode.html
+    /// [rln]: #tymethod.rln
+    fn rlog2(self, mode: RoundMode) -> Result<Self, Self::Error>;
+
+    /// Checked [rounding][RoundMode] decimal logarithm, see [`rln`][rln].
+    ///
+    /// [RoundMode]: ../enum.RoundMode.html
+    /// [rln]: #tymethod.rln
+    fn rlog10(self, mode: RoundMode) -> Result<Self, Self::Error>;
+
+    /// Checked [rounding][RoundMode] logarithm to the given `base`.
+    /// Returns `Err` for non-positive argument or base and for the base equal to one.
+    ///
+    /// [RoundMode]: ../enum.RoundMode.html
+    fn rlog(self, base: Self, mode: RoundMode) -> Result<Self, Self::Error>;
+}
+
+/// Number of fractional bits of the binary fixed-point logarithm returned by `log2`.
+pub(crate) const LOG2_FRAC_BITS: u32 = 64;
+
+/// `log2(e)` in the binary fixed-point form of `log2` results, rounded to nearest.
+pub(crate) const LOG2_E: u128 = 0x1_7154_7652_b82f_e177;
+
+/// `1` in the binary fixed-point form of `log2` results.
+pub(crate) const ONE: u128 = 1 << LOG2_FRAC_BITS;
+
+/// Number of fractional bits of the binary fixed-point logarithm returned by `log2_wide`.
+/// Integral parts of logarithms of 128-bit integers take 7 bits, so they still fit `u128`.
+const WIDE_FRAC_BITS: u32 = 120;
+
+/// The bound of the absolute error of differences of `log2_wide` results
+/// and their products with factors, in units of the last place.
+const WIDE_ERROR: u128 = 8;
+
+/// Number of fractional bits of factors of `log_scaled`.
+const FACTOR_FRAC_BITS: u32 = 127;
+
+/// `1` as a factor of `log_scaled`.
+pub(crate) const FACTOR_ONE: u128 = 1 << FACTOR_FRAC_BITS;
+
+/// `ln(2)` as a factor of `log_scaled`, rounded to nearest.
+pub(crate) const LN_2: u128 = 0x58b9_0bfb_e8e7_bcd5_e4f1_d9cc_01f9_7b58;
+
+/// `log10(2)` as a factor of `log_scaled`, rounded to nearest.
+pub(crate) const LOG10_2: u128 = 0x2688_26a1_3ef3_fde6_23e2_566b_02df_245e;
+
+/// `2 / π` with 128 fractional bits, truncated.
+const TWO_OVER_PI: u128 = 0xa2f9_836e_4e44_1529_fc27_57d1_f534_ddc0;
+
+/// `π` in the binary fixed-point form of `log2` results, rounded to nearest.
+const PI: u128 = 0x3_243f_6a88_85a3_08d3;
+
+/// `π / 2` in the binary fixed-point form of `log2` results, rounded to nearest.
+const HALF_PI: u128 = 0x1_921f_b544_42d1_846a;
+
+/// `π / 6` in the binary fixed-point form of `log2` results, rounded to nearest.
+const SIXTH_PI: u128 = 0x860a_91c1_6b9b_2c23;
+
+/// `sqrt(3)` in the binary fixed-point form of `log2` results, rounded to nearest.
+const SQRT_3: u128 = 0x1_bb67_ae85_84ca_a73b;
+
+/// `tan(π / 12) = 2 - sqrt(3)` in the binary fixed-point form of `log2` results, rounded to nearest.
+const TAN_TWELFTH_PI: u128 = 0x4498_517a_7b35_58c5;
+
+/// Binary logarithm of a positive integer in binary fixed-point form
+/// with `LOG2_FRAC_BITS` fractional bits, i.e. `log2(x) * 2^64`.
+/// The result is truncated, the error doesn't exceed a few units of the last place.
+pub(crate) fn log2(x: u128) -> u128 {
+    debug_assert!(x > 0);
+
+    let integral = 127 - x.leading_zeros();
+    // Normalize the mantissa to `[1, 2)` with 63 fractional bits.
+    let mut mantissa = if integral >= 63 {
+        x >> (integral - 63)
+    } else {
+        x << (63 - integral)
+    };
+
+    let mut result = u128::from(integral) << LOG2_FRAC_BITS;
+
+    // Squaring the mantissa doubles its logarithm, so each overflow of `2` is the next bit.
+    for bit in (0..LOG2_FRAC_BITS).rev() {
+        mantissa = (mantissa * mantissa) >> 63;
+        if mantissa >> 64 != 0 {
+            mantissa >>= 1;
+            result |= 1 << bit;
+        }
+    }
+
+    result
+}
+
+/// Binary logarithm of a positive integer in binary fixed-point form
+/// with `WIDE_FRAC_BITS` fractional bits. The result is truncated, the absolute error
+/// is below `2^-119`: the bits are exact for the mantissa perturbed by `2^-126` at most.
+fn log2_wide(x: u128) -> u128 {
+    debug_assert!(x > 0);
+
+    let integral = 127 - x.leading_zeros();
+    // The mantissa in `[1, 2)` with 127 fractional bits, it's exact.
+    let mut mantissa = x << x.leading_zeros();
+    let mut result = u128::from(integral) << WIDE_FRAC_BITS;
+
+    for bit in (0..WIDE_FRAC_BITS).rev() {
+        // The square is in `[1, 4)` with 254 fractional bits.
+        let (high, low) = widening_mul(mantissa, mantissa);
+        if high >> 127 == 1 {
+            mantissa = high;
+            result |= 1 << bit;
+        } else {
+            mantissa = (high << 1) | (low >> 127);
+        }
+    }
+
+    result
+}
+
+/// `log2(x / scale)` as a sign and a magnitude with `WIDE_FRAC_BITS` fractional bits.
+/// The sign is zero only for `x == scale`.
+fn log2_ratio(x: u128, scale: u128) -> (i32, u128) {
+    let (x, scale) = (log2_wide(x), log2_wide(scale));
+    match x.cmp(&scale) {
+        Ordering::Less => (-1, scale - x),
+        Ordering::Equal => (0, 0),
+        Ordering::Greater => (1, x - scale),
+    }
+}
+
+/// Rounds a magnitude known to lie between `lower` and `upper` bounds by `round`,
+/// which must be monotonic. If the bounds are rounded to adjacent values, the exact value
+/// is taken to be on the rounding boundary between them, so exact results like `log10(100)`
+/// aren't rounded in the wrong direction. If they are rounded even further apart,
+/// the approximation `approx` is rounded instead.
+fn round_bounded<T>(
+    sign: i32,
+    mode: RoundMode,
+    lower: T,
+    approx: T,
+    upper: T,
+    round: impl Fn(T) -> Option<u128>,
+) -> Option<u128> {
+    let rounded = round(approx)?;
+    match (round(lower), round(upper)) {
+        (Some(lower), Some(upper)) if lower == upper => Some(lower),
+        // `Nearest` rounds halfway cases away from zero as well as `upper`.
+        (Some(lower), Some(upper)) if upper - lower == 1 => {
+            Some(if mode.is_away_from_zero_with(sign, || false) {
+                lower
+            } else {
+                upper
+            })
+        }
+        _ => Some(rounded),
+    }
+}
+
+/// Calculates `magnitude * scale` [rounded][RoundMode] to an integer as a number of `sign`,
+/// where `magnitude` has `WIDE_FRAC_BITS` fractional bits.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+fn round_wide(magnitude: u128, sign: i32, scale: u128, mode: RoundMode) -> Option<u128> {
+    const MASK: u128 = (1 << WIDE_FRAC_BITS) - 1;
+
+    let (high, low) = widening_mul(magnitude, scale);
+    if high >> WIDE_FRAC_BITS != 0 {
+        return None;
+    }
+    let integral = (high << (128 - WIDE_FRAC_BITS)) | (low >> WIDE_FRAC_BITS);
+    let fractional = low & MASK;
+
+    if fractional != 0
+        && mode.is_away_from_zero_with(sign, || fractional >> (WIDE_FRAC_BITS - 1) == 1)
+    {
+        integral.checked_add(1)
+    } else {
+        Some(integral)
+    }
+}
+
+/// Calculates `log2(x / scale) * factor * scale` [rounded][RoundMode] to an integer,
+/// where `factor` has `FACTOR_FRAC_BITS` fractional bits and doesn't exceed `FACTOR_ONE`.
+/// So `LN_2` and `LOG10_2` give natural and decimal logarithms respectively.
+///
+/// The logarithm is approximated with the absolute error below `2^-117` and rounded
+/// by [`round_bounded`][round_bounded], so the result is correctly rounded unless the exact
+/// value is closer than that to a rounding boundary without being on it.
+/// Returns `None` if the result doesn't fit in `i128`.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+/// [round_bounded]: fn.round_bounded.html
+pub(crate) fn log_scaled(x: u128, scale: u128, factor: u128, mode: RoundMode) -> Option<i128> {
+    debug_assert!(factor <= FACTOR_ONE);
+
+    let (sign, log) = log2_ratio(x, scale);
+    if sign == 0 {
+        return Some(0);
+    }
+
+    // The logarithm to the desired base, less than `2^127`.
+    let (high, low) = widening_mul(log, factor);
+    let log = (high << (128 - FACTOR_FRAC_BITS)) | (low >> FACTOR_FRAC_BITS);
+
+    let magnitude = round_bounded(
+        sign,
+        mode,
+        log.saturating_sub(WIDE_ERROR),
+        log,
+        log + WIDE_ERROR,
+        |log| round_wide(log, sign, scale, mode),
+    )?;
+
+    let magnitude = i128::try_from(magnitude).ok()?;
+    Some(if sign < 0 { -magnitude } else { magnitude })
+}
+
+/// Calculates `log(x / scale) / log(base / scale) * scale` [rounded][RoundMode] to an integer,
+/// i.e. the logarithm to the base `base / scale`. Both logarithms are approximated as in
+/// [`log_scaled`][log_scaled] and the quotient is rounded within the bounds of their errors.
+/// Returns `None` if the result doesn't fit in `i128` or the base is too close to `scale`
+/// to distinguish its logarithm from zero.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+/// [log_scaled]: fn.log_scaled.html
+pub(crate) fn log_ratio_scaled(x: u128, base: u128, scale: u128, mode: RoundMode) -> Option<i128> {
+    let (x_sign, x_log) = log2_ratio(x, scale);
+    let (base_sign, base_log) = log2_ratio(base, scale);
+    if base_log <= WIDE_ERROR {
+        return None;
+    }
+    if x_sign == 0 {
+        return Some(0);
+    }
+
+    let sign = x_sign * base_sign;
+    let quotient = |(numerator, denominator): (u128, u128)| {
+        let (high, low) = widening_mul(numerator, scale);
+        let (quotient, remainder) = div_wide(high, low, denominator)?;
+        if remainder != 0
+            && mode.is_away_from_zero_with(sign, || remainder >= denominator - remainder)
+        {
+            quotient.checked_add(1)
+        } else {
+            Some(quotient)
+        }
+    };
+
+    let magnitude = round_bounded(
+        sign,
+        mode,
+        (x_log.saturating_sub(WIDE_ERROR), base_log + WIDE_ERROR),
+        (x_log, base_log),
+        (x_log + WIDE_ERROR, base_log - WIDE_ERROR),
+        quotient,
+    )?;
+
+    let magnitude = i128::try_from(magnitude).ok()?;
+    Some(if sign < 0 { -magnitude } else { magnitude })
+}
+
+/// Divides the 256-bit number `(high, low)` by `divisor` as `(quotient, remainder)`.
+/// Returns `None` if the quotient doesn't fit in `u128`.
+fn div_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    if high >= divisor {
+        return None;
+    }
+
+    // Bitwise long division, the remainder is always less than the divisor.
+    let (mut quotient, mut remainder) = (0, high);
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    Some((quotient, remainder))
+}
+
+/// Calculates `sign * magnitude * scale` [rounded][RoundMode] to an integer, where `magnitude`
+/// is in the binary fixed-point form with `LOG2_FRAC_BITS` fractional bits.
+/// Returns `None` if the result doesn't fit in `i128`.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+pub(crate) fn scale_binary(
+    magnitude: u128,
+    sign: i32,
+    scale: u128,
+    mode: RoundMode,
+) -> Option<i128> {
+    const MASK: u128 = u64::MAX as u128;
+
+    // `magnitude * scale` still has `LOG2_FRAC_BITS` fractional bits.
+    let (high, low) = widening_mul(magnitude, scale);
+    if high >> 64 != 0 {
+        return None;
+    }
+    let integral = (high << 64) | (low >> 64);
+    let fractional = low & MASK;
+
+    let integral = if fractional != 0 && mode.is_away_from_zero_with(sign, || fractional >> 63 == 1)
+    {
+        integral.checked_add(1)?
+    } else {
+        integral
+    };
+
+    let integral = i128::try_from(integral).ok()?;
+    Some(if sign < 0 { -integral } else { integral })
+}
+
+/// Full 256-bit product of two `u128` as `(high, low)` halves.
+pub(crate) fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    // Can't overflow: it's at most `3 * (2^64 - 1)`.
+    let middle = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    let low = (lo_lo & MASK) | (middle << 64);
+    let high = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Divides the 256-bit number `(high, low)` by `10^exponent` truncating the result.
+pub(crate) fn div_pow10(high: u128, low: u128, mut exponent: u32) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let mut limbs = [high >> 64, high & MASK, low >> 64, low & MASK];
+    while exponent > 0 {
+        // `10^19` is the greatest power of ten fitting `u64`, so the long division is simple.
+        let step = exponent.min(19);
+        let divisor = 10u128.pow(step);
+        let mut remainder = 0;
+        for limb in &mut limbs {
+            let current = (remainder << 64) | *limb;
+            *limb = current / divisor;
+            remainder = current % divisor;
+        }
+        exponent -= step;
+    }
+
+    ((limbs[0] << 64) | limbs[1], (limbs[2] << 64) | limbs[3])
+}
+
+/// Calculates `scale * 2^(t / 2^64)` [rounded][RoundMode] to an integer,
+/// where `t` is in the same binary fixed-point form as results of `log2`.
+/// Returns `None` if the result doesn't fit in `u128`.
+///
+/// `2^frac(t)` is calculated as a product of `2^(2^-i)` for every set bit of the fraction,
+/// each factor is obtained by consecutive square roots of `2`. All operations truncate
+/// the last bit of 63 fractional ones, so the relative error doesn't exceed `2^-56`.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+pub(crate) fn exp2(t: i128, scale: u128, mode: RoundMode) -> Option<u128> {
+    use crate::ops::sqrt::Sqrt;
+
+    const ONE: u128 = 1 << 63;
+
+    let integral = t >> LOG2_FRAC_BITS;
+    let fractional = t as u64;
+
+    // Both `mantissa` and `factor` are in `[1, 2]` with 63 fractional bits.
+    let mut mantissa = ONE;
+    let mut factor = ONE << 1;
+    for bit in (0..LOG2_FRAC_BITS).rev() {
+        factor = (factor << 63).sqrt().ok()?;
+        if fractional >> bit & 1 == 1 {
+            mantissa = (mantissa * factor) >> 63;
+        }
+    }
+
+    // `scale * mantissa * 2^(integral - 63)`.
+    let (high, low) = widening_mul(scale, mantissa);
+    let shift = 63 - integral;
+
+    if shift <= 0 {
+        let shift = u32::try_from(-shift).ok()?;
+        return if high == 0 && shift < 128 && low.leading_zeros() >= shift {
+            Some(low << shift)
+        } else {
+            None
+        };
+    }
+
+    // Shifts `(high, low)` right keeping track of the discarded part.
+    let bit = |index: i128| -> bool {
+        match index {
+            0..=127 => low >> index & 1 == 1,
+            128..=255 => high >> (index - 128) & 1 == 1,
+            _ => false,
+        }
+    };
+    let (result, is_exact) = match shift {
+        1..=127 => {
+            if high >> shift != 0 {
+                return None;
+            }
+            let result = (low >> shift) | (high << (128 - shift));
+            (result, low << (128 - shift) == 0)
+        }
+        128..=255 => {
+            let discarded = high.checked_shl((256 - shift) as u32).unwrap_or(0);
+            (high >> (shift - 128), low == 0 && discarded == 0)
+        }
+        _ => (0, high == 0 && low == 0),
+    };
+
+    if !is_exact && mode.is_away_from_zero_with(1, || bit(shift - 1)) {
+        result.checked_add(1)
+    } else {
+        Some(result)
+    }
+}
+
+/// Returns the mode rounding the magnitude of a number of the given `sign` like `mode` rounds the number.
+fn magnitude_mode(mode: RoundMode, sign: i32) -> RoundMode {
+    match mode {
+        RoundMode::Floor if sign < 0 => RoundMode::Ceil,
+        RoundMode::Ceil if sign < 0 => RoundMode::Floor,
+        _ => mode,
+    }
+}
+
+/// Multiplies the 256-bit number `(high, low)` by `m`, returns `None` on overflow.
+fn checked_mul_wide((high, low): (u128, u128), m: u128) -> Option<(u128, u128)> {
+    let (carry, low) = widening_mul(low, m);
+    let (overflow, high) = widening_mul(high, m);
+    if overflow != 0 {
+        return None;
+    }
+    Some((high.checked_add(carry)?, low))
+}
+
+/// Calculates `base^exp` as the 256-bit number `(high, low)`, returns `None` on overflow.
+fn checked_pow_wide(base: u128, exp: u32) -> Option<(u128, u128)> {
+    if base <= 1 {
+        return Some((0, if exp == 0 { 1 } else { base }));
+    }
+    (0..exp).try_fold((0, 1), |acc, _| checked_mul_wide(acc, base))
+}
+
+/// Calculates `(x / scale)^(1 / n) * scale` [rounded][RoundMode] to an integer for `n ≥ 2`,
+/// where `sign` is the sign of the final result used for rounding of the magnitude.
+/// Returns `None` if the result doesn't fit in `u128`.
+///
+/// The root is approximated as `2^(log2(x / scale) / n)` and then corrected by bisection,
+/// so the result is exact if both `x * scale^(n - 1)` and `x * scale^(n - 1) * 2^n` fit
+/// 256 bits. Otherwise the approximation is rounded, its relative error doesn't exceed `2^-55`.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+pub(crate) fn nth_root(x: u128, scale: u128, n: u32, sign: i32, mode: RoundMode) -> Option<u128> {
+    debug_assert!(x > 0 && n >= 2);
+
+    let log = log2(x) as i128 - log2(scale) as i128;
+    let t = log / i128::from(n);
+
+    // The root is exactly `r` such that `r^n ≤ x * scale^(n - 1) < (r + 1)^n`.
+    let exact = checked_pow_wide(scale, n - 1)
+        .and_then(|power| checked_mul_wide(power, x))
+        .and_then(|power| {
+            Some((
+                power,
+                (0..n).try_fold(power, |acc, _| checked_mul_wide(acc, 2))?,
+            ))
+        });
+
+    let (power, doubled_power) = match exact {
+        Some(powers) => powers,
+        None => {
+            return exp2(t, scale, magnitude_mode(mode, sign));
+        }
+    };
+
+    let fits = |r: u128| matches!(checked_pow_wide(r, n), Some(p) if p <= power);
+
+    let approx = exp2(t, scale, RoundMode::Floor).unwrap_or(u128::MAX);
+    let margin = (approx >> 50) + 2;
+    let mut lo = approx.saturating_sub(margin);
+    let mut hi = approx.saturating_add(margin);
+    // The approximation is precise enough, but the bisection mustn't rely on it.
+    while lo > 0 && !fits(lo) {
+        lo /= 2;
+    }
+    while hi < u128::MAX && fits(hi) {
+        hi = hi.saturating_mul(2);
+    }
+
+    // Invariant: `fits(lo) && !fits(hi)`.
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if fits(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    let is_exact = checked_pow_wide(lo, n) == Some(power);
+    // Halfway is reached if `(2 * lo + 1)^n ≤ x * scale^(n - 1) * 2^n`.
+    let is_half_or_more = || {
+        let power = lo
+            .checked_mul(2)
+            .and_then(|r| r.checked_add(1))
+            .and_then(|r| checked_pow_wide(r, n));
+        matches!(power, Some(p) if p <= doubled_power)
+    };
+
+    if !is_exact && mode.is_away_from_zero_with(sign, is_half_or_more) {
+        lo.checked_add(1)
+    } else {
+        Some(lo)
+    }
+}
+
+/// Product of two numbers in the binary fixed-point form of `log2` results, truncated.
+fn mul_binary(a: u128, b: u128) -> u128 {
+    let (high, low) = widening_mul(a, b);
+    (high << 64) | (low >> 64)
+}
+
+/// Calculates `sin(theta)` and `cos(theta)` or, if `is_hyperbolic`, `sinh(theta)` and `cosh(theta)`
+/// by Taylor series for `0 ≤ theta < 2`, all are in the binary fixed-point form of `log2` results.
+fn sin_cos_series(theta: u128, is_hyperbolic: bool) -> (i128, i128) {
+    let theta_squared = mul_binary(theta, theta);
+    let (mut sin, mut cos) = (theta as i128, ONE as i128);
+    let (mut sin_term, mut cos_term) = (theta, ONE);
+    let mut k = 1;
+    // Terms decrease at least twice at every step since `theta < 2`.
+    while sin_term != 0 || cos_term != 0 {
+        sin_term = mul_binary(sin_term, theta_squared) / (2 * k * (2 * k + 1));
+        cos_term = mul_binary(cos_term, theta_squared) / ((2 * k - 1) * 2 * k);
+        if k % 2 == 1 && !is_hyperbolic {
+            sin -= sin_term as i128;
+            cos -= cos_term as i128;
+        } else {
+            sin += sin_term as i128;
+            cos += cos_term as i128;
+        }
+        k += 1;
+    }
+    (sin, cos)
+}
+
+/// Calculates `sin(x / 10^precision)` and `cos(x / 10^precision)` as signed numbers
+/// in the binary fixed-point form of `log2` results.
+///
+/// The argument is reduced modulo `π / 2` using `2 / π` with 128 fractional bits, then both
+/// functions are calculated by Taylor series on `[0, π / 2)`. The absolute error of results
+/// doesn't exceed `2^-58` if `|x / 10^precision| < 2^68`, the reduction of greater arguments
+/// adds the error up to `|x / 10^precision| * 2^-127`.
+pub(crate) fn sin_cos(x: i128, precision: u32) -> (i128, i128) {
+    // `x * 2 / π` with 128 fractional bits: the integral part is the number of the quadrant.
+    let (high, low) = widening_mul(x.unsigned_abs(), TWO_OVER_PI);
+    let (quadrant, fractional) = div_pow10(high, low, precision);
+    let (theta, _) = widening_mul(fractional, HALF_PI);
+
+    let (sin, cos) = sin_cos_series(theta, false);
+
+    // Truncation errors mustn't take values out of the range.
+    let sin = sin.max(0).min(ONE as i128);
+    let cos = cos.max(0).min(ONE as i128);
+
+    let (sin, cos) = match quadrant & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+
+    if x < 0 {
+        (-sin, cos)
+    } else {
+        (sin, cos)
+    }
+}
+
+/// Calculates `n / d` in the binary fixed-point form of `log2` results by long division
+/// for `n ≤ d ≤ 2^127`, truncating the result.
+fn div_binary(n: u128, d: u128) -> u128 {
+    debug_assert!(n <= d && d <= 1 << 127);
+
+    if n == d {
+        return ONE;
+    }
+
+    let (mut quotient, mut remainder) = (0, n);
+    for _ in 0..LOG2_FRAC_BITS {
+        // `remainder < d ≤ 2^127`, so doubling it can't overflow.
+        remainder <<= 1;
+        quotient <<= 1;
+        if remainder >= d {
+            remainder -= d;
+            quotient |= 1;
+        }
+    }
+    quotient
+}
+
+/// Calculates `atan(t)` for `0 ≤ t ≤ 1`, both are in the binary fixed-point form of `log2` results.
+fn atan(t: u128) -> u128 {
+    debug_assert!(t <= ONE);
+
+    // `atan(t) = π / 6 + atan((sqrt(3) * t - 1) / (sqrt(3) + t))` maps `t` to `[-tan(π / 12), tan(π / 12)]`.
+    let (t, offset, is_negative) = if t > TAN_TWELFTH_PI {
+        let product = mul_binary(SQRT_3, t);
+        let (numerator, is_negative) = if product >= ONE {
+            (product - ONE, false)
+        } else {
+            (ONE - product, true)
+        };
+        // `numerator < sqrt(3) - 1`, so the shift can't overflow.
+        (
+            (numerator << LOG2_FRAC_BITS) / (SQRT_3 + t),
+            SIXTH_PI,
+            is_negative,
+        )
+    } else {
+        (t, 0, false)
+    };
+
+    // `atan(t) = t - t^3 / 3 + t^5 / 5 - ...` converges fast since `t < 0.27`.
+    let t_squared = mul_binary(t, t);
+    let mut power = t;
+    let mut series = t as i128;
+    let mut k = 1;
+    while power != 0 {
+        power = mul_binary(power, t_squared);
+        let term = (power / (2 * k + 1)) as i128;
+        series += if k % 2 == 1 { -term } else { term };
+        k += 1;
+    }
+    let series = series.max(0) as u128;
+
+    if is_negative {
+        offset - series
+    } else {
+        offset + series
+    }
+}
+
+/// Calculates `atan2(y, x)`, the angle of the point `(x, y)` in `(-π, π]`, as a signed number
+/// in the binary fixed-point form of `log2` results. The absolute error doesn't exceed `2^-60`.
+/// Returns `None` if both arguments are zero.
+pub(crate) fn atan2(y: i128, x: i128) -> Option<i128> {
+    if x == 0 && y == 0 {
+        return None;
+    }
+
+    let (y_abs, x_abs) = (y.unsigned_abs(), x.unsigned_abs());
+    let angle = if y_abs <= x_abs {
+        atan(div_binary(y_abs, x_abs))
+    } else {
+        HALF_PI - atan(div_binary(x_abs, y_abs))
+    };
+    let angle = if x < 0 { PI - angle } else { angle } as i128;
+
+    Some(if y < 0 { -angle } else { angle })
+}
+
+/// Hyperbolic functions calculated by `hyperbolic`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Hyperbolic {
+    Sinh,
+    Cosh,
+    Tanh,
+}
+
+/// Calculates the hyperbolic `function` of `x / 10^precision` scaled by `10^precision`
+/// and [rounded][RoundMode] to an integer. Returns `None` if the result doesn't fit in `i128`.
+///
+/// For `|x| < 1` both `sinh` and `cosh` are calculated by Taylor series, otherwise they're
+/// combined from `e^|x|` and `e^-|x|` calculated by `exp2`. Starting from `|x| = 32`, `e^-|x|`
+/// is negligible, so `sinh` and `cosh` are `e^|x| / 2` and `tanh` is `1 - EPSILON`.
+/// The absolute error doesn't exceed `2^-60` for `|x| < 1`, the relative one doesn't exceed
+/// `2^-54` otherwise.
+///
+/// [RoundMode]: ../enum.RoundMode.html
+pub(crate) fn hyperbolic(
+    x: i128,
+    precision: u32,
+    function: Hyperbolic,
+    mode: RoundMode,
+) -> Option<i128> {
+    let scale = 10u128.pow(precision);
+    let sign = if function == Hyperbolic::Cosh {
+        1
+    } else {
+        x.signum() as i32
+    };
+    let magnitude = x.unsigned_abs();
+
+    // `|x| * log2(e)` in binary fixed-point form.
+    let exponent = || {
+        // `2^256` is beyond any layout, so greater exponents can be clamped.
+        const MAX_EXPONENT: u128 = 256 << LOG2_FRAC_BITS;
+        let (high, low) = widening_mul(magnitude, LOG2_E);
+        let (high, low) = div_pow10(high, low, precision);
+        if high == 0 {
+            low.min(MAX_EXPONENT)
+        } else {
+            MAX_EXPONENT
+        }
+    };
+
+    if magnitude / scale >= 32 {
+        if function == Hyperbolic::Tanh {
+            return scale_binary(ONE - 1, sign, scale, mode);
+        }
+        let result = exp2(
+            exponent() as i128 - ONE as i128,
+            scale,
+            magnitude_mode(mode, sign),
+        )?;
+        let result = i128::try_from(result).ok()?;
+        return Some(if sign < 0 { -result } else { result });
+    }
+
+    // `|x| < 32`, so it fits with `LOG2_FRAC_BITS` fractional bits.
+    let (_, theta) = div_pow10(
+        magnitude >> LOG2_FRAC_BITS,
+        magnitude << LOG2_FRAC_BITS,
+        precision,
+    );
+
+    let (sinh, cosh) = if theta < ONE {
+        let (sinh, cosh) = sin_cos_series(theta, true);
+        (sinh as u128, cosh as u128)
+    } else {
+        // `e^|x| ≥ 1`, so its inverse can be calculated by `div_binary`.
+        let exp = exp2(exponent() as i128, ONE, RoundMode::Floor)?;
+        let inverse = div_binary(ONE, exp);
+        ((exp - inverse) / 2, (exp + inverse) / 2)
+    };
+
+    let result = match function {
+        Hyperbolic::Sinh => sinh,
+        Hyperbolic::Cosh => cosh,
+        // `tanh(x) < 1` while truncation errors could give exactly `1`.
+        Hyperbolic::Tanh => div_binary(sinh, cosh).min(ONE - 1),
+    };
+
+    scale_binary(result, sign, scale, mode)
+}
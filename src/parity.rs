@@ -1,6 +1,8 @@
 use core::result::Result;
 
-use parity_scale_codec::{Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, Output};
+use parity_scale_codec::{
+    Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
 use static_assertions::{assert_eq_align, assert_eq_size};
 
 use crate::FixedPoint;
@@ -43,6 +45,17 @@ macro_rules! impl_codec {
             }
         }
 
+        #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
+        $(#[$attr])?
+        impl<P> MaxEncodedLen for FixedPoint<$layout, P> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                <$representation>::max_encoded_len()
+            }
+        }
+
+        /// Allows `#[codec(compact)]` fields, which encode the inner integer compactly.
+        /// Negative values are reinterpreted as big unsigned integers, so they take the most space.
         #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
         $(#[$attr])?
         impl<P> CompactAs for FixedPoint<$layout, P> {
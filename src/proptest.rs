@@ -0,0 +1,181 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for `FixedPoint`.
+//!
+//! A value is generated as an integral part and a fractional one, so failing cases shrink
+//! toward zero first and then toward whole numbers.
+//!
+//! ```ignore
+//! use fixnum::{FixedPoint, typenum::U9, ops::CheckedAdd, proptest::fixed_point_in};
+//! use proptest::prelude::*;
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! fn amount(s: &str) -> Amount {
+//!     s.parse().unwrap()
+//! }
+//!
+//! proptest! {
+//!     #[test]
+//!     fn add_is_commutative(a: Amount, b in fixed_point_in(amount("-1")..=amount("1"))) {
+//!         prop_assert_eq!(a.cadd(b), b.cadd(a));
+//!     }
+//! }
+//! ```
+
+use core::{fmt, ops::RangeInclusive};
+
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+use crate::{ops::Bounded, FixedPoint, Precision};
+
+/// Strategy generating `FixedPoint` values in the range, see [the module docs](./index.html).
+#[derive(Clone, Copy)]
+pub struct FixedPointStrategy<I, P> {
+    low: FixedPoint<I, P>,
+    high: FixedPoint<I, P>,
+}
+
+/// Shrinks the integral part first, then the fractional one.
+struct FixedPointValueTree<I, P>
+where
+    RangeInclusive<I>: Strategy,
+{
+    integral: <RangeInclusive<I> as Strategy>::Tree,
+    fractional: <RangeInclusive<I> as Strategy>::Tree,
+    low: FixedPoint<I, P>,
+    high: FixedPoint<I, P>,
+    integral_simplified: bool,
+    last_fractional: bool,
+}
+
+/// Generates values in the whole range from `MIN` to `MAX`.
+pub fn any_fixed_point<I, P>() -> FixedPointStrategy<I, P>
+where
+    FixedPoint<I, P>: Bounded,
+    FixedPointStrategy<I, P>: Strategy<Value = FixedPoint<I, P>>,
+{
+    FixedPointStrategy {
+        low: FixedPoint::MIN,
+        high: FixedPoint::MAX,
+    }
+}
+
+/// Generates values in the inclusive range.
+///
+/// # Panics
+/// Panics if the range is empty.
+pub fn fixed_point_in<I, P>(range: RangeInclusive<FixedPoint<I, P>>) -> FixedPointStrategy<I, P>
+where
+    I: PartialOrd,
+    FixedPointStrategy<I, P>: Strategy<Value = FixedPoint<I, P>>,
+{
+    let (low, high) = range.into_inner();
+    assert!(low.inner <= high.inner, "empty range of FixedPoint");
+    FixedPointStrategy { low, high }
+}
+
+macro_rules! impl_proptest {
+    ($layout:ty) => {
+        impl_proptest!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: Precision> fmt::Debug for FixedPointStrategy<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("FixedPointStrategy")
+                    .field("low", &self.low)
+                    .field("high", &self.high)
+                    .finish()
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> Strategy for FixedPointStrategy<$layout, P> {
+            type Tree = Box<dyn ValueTree<Value = Self::Value>>;
+            type Value = FixedPoint<$layout, P>;
+
+            fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                let coef = FixedPoint::<$layout, P>::COEF;
+                let (low, high) = (self.low.inner, self.high.inner);
+                let (low_integral, high_integral) = (low.div_euclid(coef), high.div_euclid(coef));
+
+                // Integers shrink toward zero, staying inside the range.
+                let integral = (low_integral..=high_integral).new_tree(runner)?;
+                let from = match integral.current() {
+                    current if current == low_integral => low.rem_euclid(coef),
+                    _ => 0,
+                };
+                let to = match integral.current() {
+                    current if current == high_integral => high.rem_euclid(coef),
+                    _ => coef - 1,
+                };
+                let fractional = (from..=to).new_tree(runner)?;
+
+                Ok(Box::new(FixedPointValueTree {
+                    integral,
+                    fractional,
+                    low: self.low,
+                    high: self.high,
+                    integral_simplified: false,
+                    last_fractional: false,
+                }))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> ValueTree for FixedPointValueTree<$layout, P> {
+            type Value = FixedPoint<$layout, P>;
+
+            fn current(&self) -> Self::Value {
+                let integral = self.integral.current() * FixedPoint::<$layout, P>::COEF;
+                // The fractional range is chosen for the initial integral part,
+                // so shrunk values can leave the range.
+                let inner = integral.saturating_add(self.fractional.current());
+                FixedPoint::from_bits(inner.max(self.low.inner).min(self.high.inner))
+            }
+
+            fn simplify(&mut self) -> bool {
+                if !self.integral_simplified {
+                    if self.integral.simplify() {
+                        self.last_fractional = false;
+                        return true;
+                    }
+                    self.integral_simplified = true;
+                }
+
+                self.last_fractional = true;
+                self.fractional.simplify()
+            }
+
+            fn complicate(&mut self) -> bool {
+                if self.last_fractional {
+                    self.fractional.complicate()
+                } else {
+                    self.integral.complicate()
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> Arbitrary for FixedPoint<$layout, P> {
+            type Parameters = ();
+            type Strategy = FixedPointStrategy<$layout, P>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                any_fixed_point()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_proptest!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_proptest!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_proptest!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_proptest!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
@@ -0,0 +1,63 @@
+use core::marker::PhantomData;
+
+use rand::{
+    distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    Rng,
+};
+
+use crate::FixedPoint;
+
+/// Uniform sampler of `FixedPoint` values used by `rng.gen_range(low..=high)`
+/// and `Uniform::new(low, high)`.
+///
+/// Every representable value in the range (a multiple of `EPSILON`) is equally likely.
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+#[derive(Clone, Copy, Debug)]
+pub struct UniformFixedPoint<I: SampleUniform, P> {
+    inner: I::Sampler,
+    _marker: PhantomData<P>,
+}
+
+impl<I: SampleUniform, P> UniformFixedPoint<I, P> {
+    fn from_inner(inner: I::Sampler) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<I: SampleUniform, P> SampleUniform for FixedPoint<I, P> {
+    type Sampler = UniformFixedPoint<I, P>;
+}
+
+impl<I: SampleUniform, P> UniformSampler for UniformFixedPoint<I, P> {
+    type X = FixedPoint<I, P>;
+
+    #[inline]
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::from_inner(I::Sampler::new(&low.borrow().inner, &high.borrow().inner))
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::from_inner(I::Sampler::new_inclusive(
+            &low.borrow().inner,
+            &high.borrow().inner,
+        ))
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        FixedPoint::from_bits(self.inner.sample(rng))
+    }
+}
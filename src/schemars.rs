@@ -0,0 +1,36 @@
+use alloc::{borrow::Cow, format};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{FixedPoint, Precision};
+
+/// Describes the decimal string emitted by the default `Serialize` for JSON
+/// (and [`serde::as_string`](./serde/as_string/index.html)), e.g.
+/// `{ "type": "string", "pattern": "^-?[0-9]+(\\.[0-9]{1,9})?$" }` for `PRECISION = 9`.
+#[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+impl<I, P: Precision> JsonSchema for FixedPoint<I, P> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "FixedPoint".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("fixnum::FixedPoint<{}>", P::I32).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let pattern = if P::I32 > 0 {
+            format!(r"^-?[0-9]+(\.[0-9]{{1,{}}})?$", P::I32)
+        } else {
+            r"^-?[0-9]+$".into()
+        };
+
+        json_schema!({
+            "type": "string",
+            "pattern": pattern,
+        })
+    }
+}
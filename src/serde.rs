@@ -3,14 +3,17 @@
 //! change the implementation.
 //!
 //! By default `FixedPoint` is serialized using `as_string` for human readable formats
-//! and `as_repr` for other ones.
+//! and `repr` for other ones. All of them are lossless except [`float`](float/index.html)
+//! and long float literals accepted by [`as_string`](as_string/index.html).
+//!
+//! Everything works under `no_std` without `alloc`: strings are formatted into buffers
+//! on the stack.
 
-use std::{
+use core::{
     convert::TryFrom,
-    fmt::{self, Display},
-    io::{Cursor, Write as _},
+    fmt::{self, Display, Write as _},
     marker::PhantomData,
-    str::{self, FromStr},
+    str::FromStr,
 };
 
 use serde::{
@@ -18,8 +21,19 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::{errors::ConvertError, FixedPoint};
+use crate::{errors::ConvertError, ops::RoundMode, ArrayString, FixedPoint, Precision};
+
+// The maximum length of formatted values can be calculated as `len(str(-2**bits)) + 2`,
+// where `2` is reserved for `.` and a zero after integral part if `PRECISION` is zero.
+// Unsigned layouts have no sign, but one more digit: `len(str(2**bits - 1)) + 2` is the same.
+const MAX_LEN: usize = if cfg!(any(feature = "i128", feature = "u128")) {
+    42
+} else {
+    22
+};
 
+/// Emits a decimal string (e.g. `"1.5"`) if the format is human readable like JSON,
+/// and the inner integer otherwise like in `bincode`.
 impl<I, P> Serialize for FixedPoint<I, P>
 where
     I: Serialize,
@@ -33,15 +47,18 @@ where
         if serializer.is_human_readable() {
             as_string::serialize(self, serializer)
         } else {
-            as_repr::serialize(self, serializer)
+            repr::serialize(self, serializer)
         }
     }
 }
 
+/// Expects the same representation as emitted by `Serialize` depending on whether the format
+/// is human readable. Numbers are accepted as well by self-describing human readable formats.
 impl<'de, I, P> Deserialize<'de> for FixedPoint<I, P>
 where
     I: Deserialize<'de>,
-    Self: FromStr,
+    P: Precision,
+    Self: FromStr + TryFrom<i64> + TryFrom<u64>,
 {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -51,13 +68,19 @@ where
         if deserializer.is_human_readable() {
             as_string::deserialize(deserializer)
         } else {
-            as_repr::deserialize(deserializer)
+            repr::deserialize(deserializer)
         }
     }
 }
 
-/// (De)serializer `FixedPoint` as inner representation.
-pub mod as_repr {
+/// (De)serializes `FixedPoint` as the inner integer, i.e. the value multiplied by `10^PRECISION`,
+/// e.g. `1500000000` for `1.5` of `FixedPoint<i64, U9>`.
+///
+/// It's a stable and allocation-free wire format for compact binary formats like `bincode` or
+/// `postcard`. The precision isn't stored: it's implied by the type parameter, so changing `P`
+/// changes the meaning of serialized values. Deserialization rejects types, which precision
+/// doesn't fit in the layout (`10^PRECISION` overflows it).
+pub mod repr {
     use super::*;
 
     #[inline]
@@ -69,6 +92,39 @@ pub mod as_repr {
         fp.inner.serialize(serializer)
     }
 
+    pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
+    where
+        I: Deserialize<'de>,
+        P: Precision,
+        D: Deserializer<'de>,
+    {
+        let bits = 8 * core::mem::size_of::<I>() as u32;
+        // `10^PRECISION <= 2^(bits - 1) - 1`, equality is impossible for a positive precision.
+        let is_valid =
+            P::I32 >= 0 && f64::from(P::I32) * core::f64::consts::LOG2_10 < f64::from(bits - 1);
+        if !is_valid {
+            return Err(D::Error::custom("requested precision is too high"));
+        }
+
+        I::deserialize(deserializer).map(FixedPoint::from_bits)
+    }
+}
+
+/// (De)serializes `FixedPoint` as inner representation without checking the precision.
+///
+/// Prefer [`repr`](../repr/index.html) instead.
+pub mod as_repr {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<I, P, S>(fp: &FixedPoint<I, P>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Serialize,
+        S: Serializer,
+    {
+        repr::serialize(fp, serializer)
+    }
+
     #[inline]
     pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
     where
@@ -80,7 +136,15 @@ pub mod as_repr {
 }
 
 /// (De)serializes `FixedPoint` as a string.
+///
+/// Deserialization also accepts numbers for self-describing formats: integers are converted
+/// losslessly and floats are parsed from their shortest round-trip decimal representation
+/// (e.g. `0.123456789` in JSON). The latter has at most 17 significant digits, so longer
+/// literals are rounded by `f64` before they reach `FixedPoint`. Enable the
+/// `arbitrary_precision` feature of `serde_json` to get the original literal instead.
 pub mod as_string {
+    use serde::ser::Error as _;
+
     use super::*;
 
     pub fn serialize<I, P, S>(fp: &FixedPoint<I, P>, serializer: S) -> Result<S::Ok, S::Error>
@@ -89,35 +153,33 @@ pub mod as_string {
         FixedPoint<I, P>: Display,
     {
         // Serialize as a string in case of human readable formats.
-        // The maximum length can be calculated as `len(str(-2**bits)) + 1`,
-        // where `1` is reserved for `.` after integral part.
-        const MAX_LEN: usize = if cfg!(feature = "i128") { 41 } else { 21 };
-
-        let mut buf = [0; MAX_LEN];
-        let mut cursor = Cursor::new(&mut buf[..]);
-        let _ = write!(cursor, "{}", fp);
-        let p = cursor.position() as usize;
+        let mut buf = ArrayString::<MAX_LEN>::new();
+        write!(buf, "{}", fp).map_err(S::Error::custom)?;
 
-        // The Display instance for numbers produces valid utf-8.
-        let s = unsafe { str::from_utf8_unchecked(&buf[..p]) };
-
-        serializer.serialize_str(s)
+        serializer.serialize_str(&buf)
     }
 
     pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
     where
         D: Deserializer<'de>,
-        FixedPoint<I, P>: FromStr,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
     {
-        // Deserialize as a string in case of human readable formats.
-        deserializer.deserialize_str(FixedPointVisitor::<I, P>(PhantomData))
+        let visitor = FixedPointVisitor::<I, P>(PhantomData);
+
+        // Human readable formats are self-describing, so numbers can be accepted too.
+        // Others (e.g. `bincode`) don't support `deserialize_any` at all.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_str(visitor)
+        }
     }
 
     struct FixedPointVisitor<I, P>(PhantomData<(I, P)>);
 
     impl<'de, I, P> de::Visitor<'de> for FixedPointVisitor<I, P>
     where
-        FixedPoint<I, P>: FromStr,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
     {
         type Value = FixedPoint<I, P>;
 
@@ -126,14 +188,173 @@ pub mod as_string {
         }
 
         fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
-            // TODO: parse scientific form.
             // TODO: parse big ones with loss instead of an error.
             value
                 .parse()
                 .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
         }
 
-        // TODO: visit_f64
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            FixedPoint::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            FixedPoint::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            // `Display` for `f64` produces the shortest representation that round-trips,
+            // so it restores the original literal (e.g. `0.123456789`) if it fits in `f64`.
+            // Parsing that string avoids the loss of going through binary arithmetic.
+            let mut buf = ArrayString::<64>::new();
+            write!(buf, "{}", value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Float(value), &self))?;
+
+            buf.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Float(value), &self))
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            // `serde_json` with `arbitrary_precision` passes numbers as a single-entry map
+            // holding the original literal, which is parsed as is.
+            if map.next_key_seed(NumberToken)?.is_none() {
+                return Err(A::Error::invalid_length(0, &self));
+            }
+            map.next_value_seed(self)
+        }
+    }
+
+    impl<'de, I, P> de::DeserializeSeed<'de> for FixedPointVisitor<I, P>
+    where
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
+    {
+        type Value = FixedPoint<I, P>;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    /// The key of the map representing a number in `serde_json` with `arbitrary_precision`.
+    struct NumberToken;
+
+    const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+    impl<'de> de::DeserializeSeed<'de> for NumberToken {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de> de::Visitor<'de> for NumberToken {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<(), E> {
+            if value == NUMBER_TOKEN {
+                Ok(())
+            } else {
+                Err(E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+    }
+}
+
+/// (De)serializes `FixedPoint` strictly as a decimal string, e.g. `"1.5"`, in any format.
+///
+/// Unlike [`as_string`](../as_string/index.html), numbers are rejected on deserialization,
+/// so amounts can't silently go through floats on the other side of an API:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "fixnum::serde::str")]
+///     amount: FixedPoint<i64, U9>,
+/// }
+///
+/// let payment: Payment = serde_json::from_str(r#"{"amount":"10.5"}"#)?;
+/// assert_eq!(serde_json::to_string(&payment)?, r#"{"amount":"10.5"}"#);
+/// assert!(serde_json::from_str::<Payment>(r#"{"amount":10.5}"#).is_err());
+/// ```
+pub mod str {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<I, P, S>(fp: &FixedPoint<I, P>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        FixedPoint<I, P>: Display,
+    {
+        as_string::serialize(fp, serializer)
+    }
+
+    #[inline]
+    pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
+    where
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr,
+    {
+        deserializer.deserialize_str(StrVisitor::<I, P>(PhantomData))
+    }
+
+    struct StrVisitor<I, P>(PhantomData<(I, P)>);
+
+    impl<'de, I, P> de::Visitor<'de> for StrVisitor<I, P>
+    where
+        FixedPoint<I, P>: FromStr,
+    {
+        type Value = FixedPoint<I, P>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a string representing a fixed-point number")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &core::primitive::str) -> Result<Self::Value, E> {
+            value
+                .parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// (De)serializes `FixedPoint` as a string with exactly `PRECISION` fractional digits,
+/// e.g. `"1.500000000"` for `FixedPoint<i64, U9>`, using the alternate form of `Display` (`{:#}`).
+///
+/// Deserialization is the same as for [`as_string`](../as_string/index.html).
+pub mod fixed_digits {
+    use serde::ser::Error as _;
+
+    use super::*;
+
+    pub fn serialize<I, P, S>(fp: &FixedPoint<I, P>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        FixedPoint<I, P>: Display,
+    {
+        // Padding doesn't exceed the length of the longest value.
+        let mut buf = ArrayString::<MAX_LEN>::new();
+        write!(buf, "{:#}", fp).map_err(S::Error::custom)?;
+
+        serializer.serialize_str(&buf)
+    }
+
+    #[inline]
+    pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
+    where
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
+    {
+        as_string::deserialize(deserializer)
     }
 }
 
@@ -164,3 +385,175 @@ pub mod as_f64 {
             .map_err(|err| D::Error::invalid_value(de::Unexpected::Float(f), &err.as_str()))
     }
 }
+
+/// (De)serializes `FixedPoint` as a JSON-like number, **losing precision**.
+///
+/// Intended only for interop with APIs insisting on numbers, prefer [`str`](../str/index.html)
+/// otherwise:
+/// * Serialization emits the nearest `f64` (ties to even), so only about 15 significant digits
+///   are guaranteed to survive.
+/// * Deserialization takes the shortest decimal representation of the parsed `f64` (the one
+///   that round-trips, e.g. `0.3` rather than `0.29999999999999998...`) and rounds its digits
+///   beyond `PRECISION` to the nearest, with ties away from zero. Submodules `floor`, `ceil`,
+///   `toward_zero` and `away_from_zero` use the corresponding [`RoundMode`][RoundMode] instead,
+///   and [`deserialize_rounded`](fn.deserialize_rounded.html) accepts any mode.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Quote {
+///     #[serde(with = "fixnum::serde::float")]
+///     price: FixedPoint<i64, U9>,
+///     #[serde(with = "fixnum::serde::float::floor")]
+///     volume: FixedPoint<i64, U9>,
+/// }
+///
+/// let quote: Quote = serde_json::from_str(r#"{"price":0.1234567895,"volume":1.9999999999}"#)?;
+/// assert_eq!(quote.price, "0.12345679".parse()?);
+/// assert_eq!(quote.volume, "1.999999999".parse()?);
+/// assert_eq!(serde_json::to_string(&quote)?, r#"{"price":0.12345679,"volume":1.999999999}"#);
+/// ```
+///
+/// [RoundMode]: ../../ops/enum.RoundMode.html
+pub mod float {
+    use serde::ser::Error as _;
+
+    use super::*;
+
+    pub fn serialize<I, P, S>(fp: &FixedPoint<I, P>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        FixedPoint<I, P>: Display,
+    {
+        // Parsing the exact decimal representation rounds to the nearest `f64` correctly,
+        // unlike the arithmetic on the integral and fractional parts used by `Into<f64>`.
+        let mut buf = ArrayString::<MAX_LEN>::new();
+        write!(buf, "{}", fp).map_err(S::Error::custom)?;
+        let value = buf.parse::<f64>().map_err(S::Error::custom)?;
+
+        serializer.serialize_f64(value)
+    }
+
+    #[inline]
+    pub fn deserialize<'de, I, P, D>(deserializer: D) -> Result<FixedPoint<I, P>, D::Error>
+    where
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: RoundingFromStr,
+    {
+        deserialize_rounded(deserializer, RoundMode::Nearest)
+    }
+
+    /// Deserializes a number rounding it according to `mode`.
+    pub fn deserialize_rounded<'de, I, P, D>(
+        deserializer: D,
+        mode: RoundMode,
+    ) -> Result<FixedPoint<I, P>, D::Error>
+    where
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: RoundingFromStr,
+    {
+        let value = f64::deserialize(deserializer)?;
+        let unexpected =
+            || D::Error::invalid_value(de::Unexpected::Float(value), &"a finite number");
+
+        if !value.is_finite() {
+            return Err(unexpected());
+        }
+
+        // The exponential form is short for any `f64`, e.g. `1.2345e-300`.
+        let mut buf = ArrayString::<32>::new();
+        write!(buf, "{:e}", value).map_err(|_| unexpected())?;
+
+        FixedPoint::rounding_from_str(&buf, mode)
+            .map_err(|err| D::Error::invalid_value(de::Unexpected::Float(value), &err.as_str()))
+    }
+
+    macro_rules! impl_mode {
+        ($name:ident, $mode:ident, $doc:literal) => {
+            #[doc = $doc]
+            ///
+            /// See [`float`](../index.html) for details.
+            pub mod $name {
+                use super::*;
+
+                pub use super::serialize;
+
+                #[inline]
+                pub fn deserialize<'de, I, P, D>(
+                    deserializer: D,
+                ) -> Result<FixedPoint<I, P>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                    FixedPoint<I, P>: RoundingFromStr,
+                {
+                    deserialize_rounded(deserializer, RoundMode::$mode)
+                }
+            }
+        };
+    }
+
+    impl_mode!(floor, Floor, "Deserializes with `RoundMode::Floor`.");
+    impl_mode!(ceil, Ceil, "Deserializes with `RoundMode::Ceil`.");
+    impl_mode!(
+        toward_zero,
+        TowardZero,
+        "Deserializes with `RoundMode::TowardZero`."
+    );
+    impl_mode!(
+        away_from_zero,
+        AwayFromZero,
+        "Deserializes with `RoundMode::AwayFromZero`."
+    );
+}
+
+/// Parsing with rounding of digits beyond `PRECISION`, see
+/// [`FixedPoint::from_str_with_mode`](../struct.FixedPoint.html#method.from_str_with_mode).
+/// Used by [`float`](float/index.html) to be generic over layouts.
+pub trait RoundingFromStr: Sized {
+    fn rounding_from_str(str: &core::primitive::str, mode: RoundMode)
+        -> Result<Self, ConvertError>;
+}
+
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "i256",
+    feature = "u32",
+    feature = "u64",
+    feature = "u128"
+))]
+macro_rules! impl_rounding_from_str {
+    ($layout:ty) => {
+        impl_rounding_from_str!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: Precision> RoundingFromStr for FixedPoint<$layout, P> {
+            #[inline]
+            fn rounding_from_str(
+                str: &core::primitive::str,
+                mode: RoundMode,
+            ) -> Result<Self, ConvertError> {
+                Self::from_str_with_mode(str, mode)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_rounding_from_str!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_rounding_from_str!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_rounding_from_str!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_rounding_from_str!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
+#[cfg(feature = "i256")]
+impl_rounding_from_str!(crate::I256, #[cfg_attr(docsrs, doc(cfg(feature = "i256")))]);
+#[cfg(feature = "u32")]
+impl_rounding_from_str!(u32, #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]);
+#[cfg(feature = "u64")]
+impl_rounding_from_str!(u64, #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]);
+#[cfg(feature = "u128")]
+impl_rounding_from_str!(u128, #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]);
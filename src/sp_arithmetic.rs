@@ -0,0 +1,64 @@
+//! [`sp-arithmetic`](https://docs.rs/sp-arithmetic) implementations for `FixedPoint`,
+//! so it can be used in pallets generic over `FixedPointNumber`.
+//!
+//! Like fixed point numbers of `sp-arithmetic`, operations round toward zero.
+//! `Saturating` comes from the blanket implementation over `num-traits`.
+
+use sp_arithmetic::{FixedPointNumber, PerThing, PerU16, Perbill, Percent, Permill, Perquintill};
+
+use crate::{FixedPoint, Precision};
+
+macro_rules! impl_fixed_point_number {
+    ($layout:ty, $(#[$attr:meta])?) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "sp-arithmetic")))]
+        $(#[$attr])?
+        impl<P: Precision + Ord> FixedPointNumber for FixedPoint<$layout, P> {
+            type Inner = $layout;
+
+            const DIV: $layout = Self::COEF;
+            const SIGNED: bool = true;
+
+            #[inline]
+            fn from_inner(inner: $layout) -> Self {
+                Self::from_bits(inner)
+            }
+
+            #[inline]
+            fn into_inner(self) -> $layout {
+                self.into_bits()
+            }
+        }
+
+        impl_from_per_thing!(
+            $layout,
+            [$(#[$attr])?],
+            [Percent, PerU16, Permill, Perbill, Perquintill]
+        );
+    };
+}
+
+macro_rules! impl_from_per_thing {
+    ($layout:ty, $attrs:tt, [$($per_thing:ty),*]) => {
+        $(impl_from_per_thing!($layout, $attrs, $per_thing);)*
+    };
+    ($layout:ty, [$(#[$attr:meta])?], $per_thing:ty) => {
+        /// Saturates if `1` doesn't fit.
+        #[cfg_attr(docsrs, doc(cfg(feature = "sp-arithmetic")))]
+        $(#[$attr])?
+        impl<P: Precision + Ord> From<$per_thing> for FixedPoint<$layout, P> {
+            #[inline]
+            fn from(value: $per_thing) -> Self {
+                Self::saturating_from_rational(value.deconstruct(), <$per_thing>::ACCURACY)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_fixed_point_number!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_fixed_point_number!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_fixed_point_number!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_fixed_point_number!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
@@ -0,0 +1,10 @@
+// Ensure that common constants fail compilation if they don't fit the layout:
+// `100` is out of range for `FixedPoint<i16, U4>`, whose maximum is `3.2767`.
+
+use fixnum::{typenum::U4, FixedPoint};
+
+const VALUE: FixedPoint<i16, U4> = FixedPoint::<i16, U4>::HUNDRED;
+
+fn main() {
+    let _ = VALUE;
+}
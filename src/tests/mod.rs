@@ -1,3 +1,9 @@
+// Most tests run for `i64` and `i128` layouts only, e.g. `test_fixed_point!` cases.
+#![cfg_attr(
+    not(any(feature = "i64", feature = "i128")),
+    allow(dead_code, unused_imports, unused_macros)
+)]
+
 #[cfg(feature = "std")]
 use core::f64;
 use core::i64;
@@ -8,6 +14,40 @@ use crate::{RoundMode::*, *};
 
 mod macros;
 
+#[test]
+fn common_constants() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::TWO, fp!(2));
+            assert_eq!(FixedPoint::TEN, fp!(10));
+            assert_eq!(FixedPoint::HUNDRED, fp!(100));
+            assert_eq!(FixedPoint::HALF, fp!(0.5));
+            assert_eq!(FixedPoint::HALF.cadd(FixedPoint::HALF)?, FixedPoint::ONE);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i32", feature = "i64"))]
+fn grid_queries() -> Result<()> {
+    use typenum::{U4, U9};
+
+    type Fp64 = crate::FixedPoint<i64, U9>;
+    type Fp32 = crate::FixedPoint<i32, U4>;
+
+    assert_eq!(Fp64::epsilon(), "0.000000001".parse()?);
+    assert_eq!(Fp64::min_positive(), Fp64::EPSILON);
+    assert_eq!(Fp64::max_below_one(), "0.999999999".parse()?);
+    assert_eq!(Fp64::max_below_one().cadd(Fp64::epsilon())?, Fp64::ONE);
+
+    assert_eq!(Fp32::epsilon(), "0.0001".parse()?);
+    assert_eq!(Fp32::min_positive(), Fp32::EPSILON);
+    assert_eq!(Fp32::max_below_one(), "0.9999".parse()?);
+    assert_eq!(Fp32::max_below_one().cadd(Fp32::epsilon())?, Fp32::ONE);
+    Ok(())
+}
+
 #[test]
 fn from_decimal() -> Result<()> {
     test_fixed_point! {
@@ -18,1010 +58,5147 @@ fn from_decimal() -> Result<()> {
             (5_000_000_000, -9, fp!(5));
             (1, 0, fp!(1));
             (1, 1, fp!(10));
+            (5_000_000_000_000_000_000, -18, fp!(5));
         },
         fp128 {
-            (5_000_000_000_000_000_000, -18, fp!(5));
+            (1, 20, fp!(100000000000000000000));
+            (1500, -20, fp!(0.000000000000000015));
+        },
+    };
+    test_fixed_point! {
+        case (numerator | Layout, denominator | i32) => {
+            assert!(FixedPoint::from_decimal(numerator, denominator).is_err());
+        },
+        all {
+            (1, 100);
+            (Layout::MAX, 1);
+            (1, -100);
+            (Layout::MAX, i32::MIN);
+        },
+        fp64 {
+            (1, 10);
+            (15, -10);
         },
     };
     Ok(())
 }
 
 #[test]
-#[cfg(feature = "std")]
-fn display() -> Result<()> {
+fn from_decimal_rounded() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, expected | &str) => {
-            assert_eq!(format!("{}", x), String::from(expected));
-
-            #[cfg(feature = "serde")]
-            assert_eq!(serde_json::to_string(&x).unwrap(), format!("\"{}\"", expected));
+        case (mantissa | Layout, exponent | i32, mode | RoundMode, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_decimal_rounded(mantissa, exponent, mode)?, expected);
         },
         all {
-            (fp!(0), "0.0");
-            (fp!(10.042), "10.042");
-            (fp!(-10.042), "-10.042");
-            (fp!(0.000000001), "0.000000001");
-            (fp!(-0.000000001), "-0.000000001");
-            (fp!(9223372036.854775807), "9223372036.854775807");
-            (fp!(-9223372036.854775808), "-9223372036.854775808");
+            (0, -100, Ceil, fp!(0));
+            (0, 100, Floor, fp!(0));
+            (15, -1, Floor, fp!(1.5));
+            (15, 1, Ceil, fp!(150));
+            (1, -100, Floor, fp!(0));
+            (1, -100, Nearest, fp!(0));
+            (1, -100, Ceil, FixedPoint::EPSILON);
+            (-1, -100, Floor, FixedPoint::EPSILON.cneg()?);
+            (-1, -100, Ceil, fp!(0));
+            (1, i32::MIN, AwayFromZero, FixedPoint::EPSILON);
+            (Layout::MAX, i32::MIN, TowardZero, fp!(0));
         },
-        fp128 {
-            (fp!(0.000000000000000001), "0.000000000000000001");
-            (fp!(-0.000000000000000001), "-0.000000000000000001");
-            (fp!(170141183460469231731.687303715884105727), "170141183460469231731.687303715884105727");
-            (fp!(-170141183460469231731.687303715884105728), "-170141183460469231731.687303715884105728");
+        fp64 {
+            (15, -10, Floor, fp!(0.000000001));
+            (15, -10, Ceil, fp!(0.000000002));
+            (15, -10, Nearest, fp!(0.000000002));
+            (-15, -10, Nearest, fp!(-0.000000002));
+            (14, -10, Nearest, fp!(0.000000001));
+            (-15, -10, TowardZero, fp!(-0.000000001));
+            (-15, -10, AwayFromZero, fp!(-0.000000002));
+            // `10^19` doesn't fit `i64`, but the mantissa is still compared with its half.
+            (Layout::MAX, -28, Nearest, fp!(0.000000001));
+            (Layout::MAX, -29, Nearest, fp!(0));
+            (Layout::MIN, -28, Nearest, fp!(-0.000000001));
+            (4_999_999_999_999_999_999, -28, Nearest, fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (mantissa | Layout, exponent | i32) => {
+            assert!(FixedPoint::from_decimal_rounded(mantissa, exponent, Floor).is_err());
+        },
+        all {
+            (1, 100);
+            (-1, i32::MAX);
+            (Layout::MAX, 1);
         },
     };
     Ok(())
 }
 
 #[test]
-#[allow(overflowing_literals)]
-fn from_good_str() -> Result<()> {
+fn to_decimal() -> Result<()> {
     test_fixed_point! {
-        case (input | &str, expected | Layout) => {
-            let expected = FixedPoint::from_bits(expected);
-            let input: FixedPoint = input.parse()?;
-            assert_eq!(input, expected);
-
-            #[cfg(all(feature = "std", feature = "serde"))]
-            assert_eq!(
-                serde_json::from_str::<FixedPoint>(&format!("\"{}\"", input)).unwrap(),
-                expected
-            );
+        case (x | FixedPoint, max_exponent | i32, mantissa | Layout, exponent | i32) => {
+            assert_eq!(x.to_decimal(max_exponent), (mantissa, exponent));
+            assert_eq!(x.cneg()?.to_decimal(max_exponent), (-mantissa, exponent));
+            assert_eq!(FixedPoint::from_decimal(mantissa, exponent)?, x);
+        },
+        all {
+            (fp!(0), 10, 0, 0);
+            (fp!(0), -3, 0, -3);
+            (fp!(1), 10, 1, 0);
+            (fp!(12.34), 10, 1234, -2);
+            (fp!(1200), 10, 12, 2);
+            (fp!(1200), 1, 120, 1);
+            (fp!(1200), 0, 1200, 0);
+            (fp!(0.5), -3, 500, -3);
+            (FixedPoint::EPSILON, 10, 1, -FixedPoint::PRECISION);
+            (FixedPoint::MAX, 10, Layout::MAX, -FixedPoint::PRECISION);
         },
         fp64 {
-            ("1", 1000000000);
-            ("1.1", 1100000000);
-            ("1.02", 1020000000);
-            ("-1.02", -1020000000);
-            ("+1.02", 1020000000);
-            ("0.1234", 123400000);
-            ("-0.1234", -123400000);
-            ("123456789.123456789", 123456789123456789);
-            ("9223372036.854775807", 9223372036854775807);
-            ("-9223372036.854775808", -9223372036854775808);
+            (fp!(0), -100, 0, -9);
+            (fp!(1.5), -100, 1_500_000_000, -9);
         },
         fp128 {
-            ("1", 1000000000000000000);
-            ("1.1", 1100000000000000000);
-            ("1.02", 1020000000000000000);
-            ("-1.02", -1020000000000000000);
-            ("+1.02", 1020000000000000000);
-            ("0.1234", 123400000000000000);
-            ("-0.1234", -123400000000000000);
-            ("123456789.123456789", 123456789123456789000000000);
-            ("9223372036.854775807", 9223372036854775807000000000);
-            ("-9223372036.854775808", -9223372036854775808000000000);
-            ("170141183460469231731.687303715884105727",
-             170141183460469231731687303715884105727);
-            ("-170141183460469231731.687303715884105728",
-             -170141183460469231731687303715884105728);
+            (fp!(0), -100, 0, -18);
+            (fp!(1.5), -100, 1_500_000_000_000_000_000, -18);
         },
     };
     Ok(())
 }
 
 #[test]
-fn from_bad_str() -> Result<()> {
+fn basis_points() -> Result<()> {
     test_fixed_point! {
-        case (bad_str | &str) => {
-            let result: Result<FixedPoint, ConvertError> = bad_str.parse();
-            assert!(result.is_err(), "must not parse '{}'", bad_str);
-
-            #[cfg(all(feature = "std", feature = "serde"))]
-            assert!(serde_json::from_str::<FixedPoint>(&format!("\"{}\"", bad_str)).is_err());
+        case (bps | Layout, ppm | Layout, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_basis_points(bps)?, expected);
+            assert_eq!(FixedPoint::from_ppm(ppm)?, expected);
+            assert_eq!(expected.to_basis_points(Floor)?, bps);
+            assert_eq!(expected.to_basis_points(Ceil)?, bps);
+            assert_eq!(expected.to_ppm(Floor)?, ppm);
+            assert_eq!(expected.to_ppm(Ceil)?, ppm);
         },
         all {
-            ("");
-            ("7.02e5");
-            ("a.12");
-            ("12.a");
-            ("13.9999999999999999999999999999999999999999999999999999999999999");
-            ("100000000000000000000000");
-            ("170141183460469231731687303715.884105728");
-            ("13.0000000000000000001");
-            ("13.1000000000000000001");
-            ("9223372036.8547758204856183567");
-        },
-        fp64 {
-            ("13.0000000001");
-            ("13.1000000001");
-            ("9223372036.854775808");
+            (50, 5_000, fp!(0.005));
+            (-50, -5_000, fp!(-0.005));
+            (1, 100, fp!(0.0001));
+            (10_000, 1_000_000, fp!(1));
+            (0, 0, fp!(0));
         },
     };
     Ok(())
 }
 
 #[test]
-#[cfg(all(feature = "std", feature = "serde"))]
-fn serde_with() -> Result<()> {
+fn basis_points_rounded() -> Result<()> {
     test_fixed_point! {
-        case (input | f64, expected | FixedPoint) => {
-            #[derive(::serde::Serialize, ::serde::Deserialize)]
-            struct Struct {
-                #[serde(with = "crate::serde::as_f64")]
-                number: FixedPoint,
-            }
-
-            let actual = serde_json::from_str::<Struct>(&format!(r#"{{"number":{}}}"#, input)).unwrap().number;
-            assert_eq!(expected, actual);
+        case (x | FixedPoint, bps_floor | Layout, bps_ceil | Layout, ppm_floor | Layout, ppm_ceil | Layout) => {
+            assert_eq!(x.to_basis_points(Floor)?, bps_floor);
+            assert_eq!(x.to_basis_points(Ceil)?, bps_ceil);
+            assert_eq!(x.to_ppm(Floor)?, ppm_floor);
+            assert_eq!(x.to_ppm(Ceil)?, ppm_ceil);
         },
         all {
-            (1., fp!(1.0));
-            (1.1, fp!(1.1));
-            (1.02, fp!(1.02));
-            (-1.02, fp!(-1.02));
-            (0.1234, fp!(0.1234));
-            (-0.1234, fp!(-0.1234));
+            (fp!(0.00012345), 1, 2, 123, 124);
+            (fp!(-0.00012345), -2, -1, -124, -123);
+            (fp!(0.000000001), 0, 1, 0, 1);
         },
     };
     Ok(())
 }
 
 #[test]
-#[allow(clippy::assertions_on_constants)]
-fn exp_and_coef_should_agree() -> Result<()> {
+#[cfg(feature = "i64")]
+fn basis_points_inexact() -> Result<()> {
+    type Percent = crate::FixedPoint<i64, typenum::U2>;
+
+    assert_eq!(Percent::from_basis_points(100)?, Percent::from_bits(1));
+    assert_eq!(Percent::from_basis_points(-300)?, Percent::from_bits(-3));
+    assert_eq!(
+        Percent::from_basis_points(150),
+        Err(ArithmeticError::DomainViolation)
+    );
+    assert_eq!(
+        Percent::from_ppm(10_001),
+        Err(ArithmeticError::DomainViolation)
+    );
+    assert_eq!(Percent::from_bits(1).to_basis_points(Floor)?, 100);
+    assert_eq!(
+        Percent::MAX.to_basis_points(Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
+
+#[test]
+fn basis_points_overflow() -> Result<()> {
     test_fixed_point! {
         case () => {
-            assert!(FixedPoint::PRECISION > 0);
-            const TEN: Layout = 10;
-            assert_eq!(FixedPoint::COEF, TEN.pow(FixedPoint::PRECISION as u32));
+            assert_eq!(FixedPoint::from_basis_points(Layout::MAX), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::from_ppm(Layout::MIN), Err(ArithmeticError::Overflow));
         },
     };
     Ok(())
 }
 
 #[test]
-fn cmul_overflow() -> Result<()> {
+fn from_decimal_saturating() -> Result<()> {
     test_fixed_point! {
-        case () => {
-            let result = FixedPoint::MAX.cmul(Layout::MAX);
-            assert_eq!(result, Err(ArithmeticError::Overflow));
-
-            let result = FixedPoint::MAX.cmul(Layout::MIN);
-            assert_eq!(result, Err(ArithmeticError::Overflow));
+        case (mantissa | Layout, exponent | i32, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_decimal_saturating(mantissa, exponent), expected);
+        },
+        all {
+            (5_000_000_000, -9, fp!(5));
+            (1, 0, fp!(1));
+            (1, 1, fp!(10));
+            (-15, -1, fp!(-1.5));
+            (0, 100, fp!(0));
+            (0, -100, fp!(0));
+            (1, 100, FixedPoint::MAX);
+            (-1, 100, FixedPoint::MIN);
+            (Layout::MAX, 1, FixedPoint::MAX);
+            (Layout::MIN, 1, FixedPoint::MIN);
+            (Layout::MAX, i32::MAX, FixedPoint::MAX);
+            (Layout::MIN, i32::MIN, fp!(0));
+            (15, -100, fp!(0));
+        },
+        fp64 {
+            (15, -10, fp!(0.000000001));
+            (-15, -10, fp!(-0.000000001));
+            (19, -11, fp!(0));
         },
     };
     Ok(())
 }
 
 #[test]
-fn rmul_exact() -> Result<()> {
+fn from_decimal_saturating_agrees() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            // Check the result
-            assert_eq!(a.rmul(b, Floor)?, expected);
-            // Check the commutative property
-            assert_eq!(b.rmul(a, Floor)?, expected);
-            // Check that round mode doesn't matter
-            assert_eq!(a.rmul(b, Ceil)?, expected);
-            assert_eq!(b.rmul(a, Ceil)?, expected);
-        },
-        all {
-            (fp!(525), fp!(10), fp!(5250));
-            (fp!(-525), fp!(10), fp!(-5250));
-            (fp!(-525), fp!(-10), fp!(5250));
-            (fp!(525), fp!(-10), fp!(-5250));
-            (fp!(525), fp!(0.0001), fp!(0.0525));
-            (fp!(-525), fp!(0.0001), fp!(-0.0525));
-            (fp!(-525), fp!(-0.0001), fp!(0.0525));
-            (FixedPoint::MAX, FixedPoint::ONE, FixedPoint::MAX);
-            (FixedPoint::MIN, FixedPoint::ONE, FixedPoint::MIN);
-            (FixedPoint::ONE, fp!(0.000000001), fp!(0.000000001));
-            (fp!(-1), fp!(-0.000000001), fp!(0.000000001));
-            (
-                FixedPoint::from_bits(Layout::MAX / 10 * 10),
-                fp!(0.1),
-                FixedPoint::from_bits(Layout::MAX / 10),
-            );
-            (
-                FixedPoint::from_bits(Layout::MIN / 10 * 10),
-                fp!(0.1),
-                FixedPoint::from_bits(Layout::MIN / 10),
+        case (mantissa | Layout, exponent | i32) => {
+            assert_eq!(
+                FixedPoint::from_decimal_saturating(mantissa, exponent),
+                FixedPoint::from_decimal(mantissa, exponent)?,
             );
         },
-        fp128 {
-            (fp!(13043817825.332782), fp!(13043817825.332782), fp!(170141183460469226191.989043859524));
+        all {
+            (123_456, -3);
+            (-123_456, -9);
+            (42, 5);
+            (-42, 8);
+            (Layout::MAX, -FixedPoint::PRECISION);
         },
     };
     Ok(())
 }
 
 #[test]
-fn rmul_round() -> Result<()> {
+#[cfg(feature = "std")]
+fn display() -> Result<()> {
     test_fixed_point! {
-        case (
-            a | FixedPoint,
-            b | FixedPoint,
-            expected_floor | FixedPoint,
-            expected_ceil | FixedPoint,
-        ) => {
-            // Check the result
-            assert_eq!(a.rmul(b, Floor)?, expected_floor);
-            assert_eq!(a.rmul(b, Ceil)?, expected_ceil);
-            // Check the commutative property
-            assert_eq!(b.rmul(a, Floor)?, expected_floor);
-            assert_eq!(b.rmul(a, Ceil)?, expected_ceil);
-            // Arguments' negation doesn't change the result
-            assert_eq!(b.cneg()?.rmul(a.cneg()?, Floor)?, expected_floor);
-            assert_eq!(b.cneg()?.rmul(a.cneg()?, Ceil)?, expected_ceil);
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(format!("{}", x), String::from(expected));
+
+            #[cfg(feature = "serde")]
+            assert_eq!(serde_json::to_string(&x).unwrap(), format!("\"{}\"", expected));
         },
-        fp64 {
-            (fp!(0.1), fp!(0.000000001), fp!(0), fp!(0.000000001));
-            (fp!(-0.1), fp!(0.000000001), fp!(-0.000000001), fp!(0));
-            (fp!(0.000000001), fp!(0.000000001), fp!(0), fp!(0.000000001));
-            (fp!(-0.000000001), fp!(0.000000001), fp!(-0.000000001), fp!(0));
+        all {
+            (fp!(0), "0.0");
+            (fp!(10.042), "10.042");
+            (fp!(-10.042), "-10.042");
+            (fp!(0.000000001), "0.000000001");
+            (fp!(-0.000000001), "-0.000000001");
+            (fp!(9223372036.854775807), "9223372036.854775807");
+            (fp!(-9223372036.854775808), "-9223372036.854775808");
         },
         fp128 {
-            (fp!(0.1), fp!(0.000000000000000001), FixedPoint::ZERO, fp!(0.000000000000000001));
-            (fp!(-0.1), fp!(0.000000000000000001), fp!(-0.000000000000000001), FixedPoint::ZERO);
-            (fp!(0.000000000000000001), fp!(0.000000000000000001), FixedPoint::ZERO, fp!(0.000000000000000001));
-            (fp!(-0.000000000000000001), fp!(0.000000000000000001), fp!(-0.000000000000000001), FixedPoint::ZERO);
+            (fp!(0.000000000000000001), "0.000000000000000001");
+            (fp!(-0.000000000000000001), "-0.000000000000000001");
+            (fp!(170141183460469231731.687303715884105727), "170141183460469231731.687303715884105727");
+            (fp!(-170141183460469231731.687303715884105728), "-170141183460469231731.687303715884105728");
         },
     };
     Ok(())
 }
 
 #[test]
-fn rmul_overflow() -> Result<()> {
+#[cfg(feature = "std")]
+fn display_with_flags() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint) => {
-            assert_eq!(a.rmul(b, Ceil), Err(ArithmeticError::Overflow));
+        case (x | FixedPoint, precision | usize, expected | &str) => {
+            assert_eq!(format!("{:.*}", precision, x), expected);
         },
         all {
-            (FixedPoint::MAX, fp!(1.000000001));
+            (fp!(0), 0, "0");
+            (fp!(0), 2, "0.00");
+            (fp!(1.5), 0, "1");
+            (fp!(1.5), 3, "1.500");
+            (fp!(1.999), 2, "1.99");
+            (fp!(-1.999), 2, "-1.99");
+            (fp!(-0.001), 2, "-0.00");
+            (fp!(10.042), 1, "10.0");
+            (fp!(0.000000001), 9, "0.000000001");
+            (fp!(-12.5), 20, "-12.50000000000000000000");
         },
-        fp64 {
-            (fp!(96038.388349945), fp!(96038.388349945));
-            (fp!(-97000), fp!(96100))
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, f | f64) => {
+            assert_eq!(format!("{:>12.4}|", x), format!("{:>12.4}|", f));
+            assert_eq!(format!("{:<12.4}|", x), format!("{:<12.4}|", f));
+            assert_eq!(format!("{:^12.4}|", x), format!("{:^12.4}|", f));
+            assert_eq!(format!("{:*^12.1}|", x), format!("{:*^12.1}|", f));
+            assert_eq!(format!("{:+.3}", x), format!("{:+.3}", f));
+            assert_eq!(format!("{:012.2}", x), format!("{:012.2}", f));
+            assert_eq!(format!("{:+012.2}", x), format!("{:+012.2}", f));
+            assert_eq!(format!("{:.150}", x), format!("{:.150}", f));
         },
-        fp128 {
-            (FixedPoint::MAX, fp!(1.000000000000000001));
-            (fp!(13043817825.332783), fp!(13043817825.332783));
-            (fp!(-13043817826), fp!(13043817826))
+        all {
+            (fp!(0), 0.);
+            (fp!(2.5), 2.5);
+            (fp!(-12.25), -12.25);
+            (fp!(1234.125), 1234.125);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(format!("{:>8}|{:<8}|{:+}|{:08}", x, x, x, x), expected);
+        },
+        all {
+            (fp!(1.5), "     1.5|1.5     |+1.5|000001.5");
+            (fp!(-1.5), "    -1.5|-1.5    |-1.5|-00001.5");
+            (fp!(2), "     2.0|2.0     |+2.0|000002.0");
         },
     };
     Ok(())
 }
 
 #[test]
-fn rdiv_exact() -> Result<()> {
+#[cfg(feature = "std")]
+fn fmt_grouped() -> Result<()> {
     test_fixed_point! {
-        case (numerator | FixedPoint, denominator | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(numerator.rdiv(denominator, Ceil)?, expected);
-            assert_eq!(numerator.rdiv(denominator, Floor)?, expected);
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(x.fmt_grouped(',', '.').to_string(), expected);
+            assert_eq!(
+                x.fmt_grouped('\u{a0}', ',').to_string(),
+                expected.replace(',', "\u{a0}").replace('.', ",")
+            );
         },
         all {
-            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::ONE);
-            (fp!(5), fp!(2), fp!(2.5));
-            (fp!(-5), fp!(2), fp!(-2.5));
-            (fp!(5), fp!(-2), fp!(-2.5));
-            (fp!(-5), fp!(-2), fp!(2.5));
-            (fp!(5), fp!(0.2), fp!(25));
-            (fp!(0.00000001), fp!(10), fp!(0.000000001));
-            (fp!(0.000000001), fp!(0.1), fp!(0.00000001));
+            (fp!(0), "0.0");
+            (fp!(1), "1.0");
+            (fp!(-12.5), "-12.5");
+            (fp!(123.25), "123.25");
+            (fp!(1234.25), "1,234.25");
+            (fp!(-123456), "-123,456.0");
+            (fp!(1234567.890123), "1,234,567.890123");
+        },
+        fp64 {
+            (FixedPoint::MIN, "-9,223,372,036.854775808");
         },
         fp128 {
-            (fp!(0.00000000000000001), fp!(10), fp!(0.000000000000000001));
-            (fp!(0.000000000000000001), fp!(0.1), fp!(0.00000000000000001));
+            (FixedPoint::MIN, "-170,141,183,460,469,231,731.687303715884105728");
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(format!("{:>16.2}|{:<+14.0}|{:#}", x.fmt_grouped(',', '.'), x.fmt_grouped('_', '.'), x.fmt_grouped(' ', '.')), expected);
+        },
+        fp64 {
+            (fp!(1234567.899), "    1,234,567.89|+1_234_567    |1 234 567.899000000");
         },
     };
     Ok(())
 }
 
 #[test]
-fn rdiv_by_layout() -> Result<()> {
+fn to_array_string() -> Result<()> {
     test_fixed_point! {
-        case (
-            a | FixedPoint,
-            b | Layout,
-            expected_floor | FixedPoint,
-            expected_ceil | FixedPoint,
-        ) => {
-            assert_eq!(a.rdiv(b, Floor)?, expected_floor);
-            assert_eq!(a.rdiv(b, Ceil)?, expected_ceil);
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(x.to_array_string(), expected);
+
+            let mut buffer = crate::ArrayString::<64>::new();
+            x.write_to(&mut buffer).unwrap();
+            assert_eq!(buffer, expected);
         },
         all {
-            (fp!(2.4), 2, fp!(1.2), fp!(1.2));
-            (fp!(0), 5, FixedPoint::ZERO, FixedPoint::ZERO);
+            (fp!(0), "0.0");
+            (fp!(-12.5), "-12.5");
+            (fp!(1000.000001), "1000.000001");
         },
         fp64 {
-            (fp!(7), 3, fp!(2.333333333), fp!(2.333333334));
-            (fp!(-7), 3, fp!(-2.333333334), fp!(-2.333333333));
-            (fp!(-7), -3, fp!(2.333333333), fp!(2.333333334));
-            (fp!(7), -3, fp!(-2.333333334), fp!(-2.333333333));
-            (fp!(0.000000003), 2, fp!(0.000000001), fp!(0.000000002));
-            (fp!(0.000000003), 7, fp!(0), fp!(0.000000001));
-            (fp!(0.000000001), 7, fp!(0), fp!(0.000000001));
+            (FixedPoint::MIN, "-9223372036.854775808");
+            (FixedPoint::MAX, "9223372036.854775807");
         },
         fp128 {
-            (fp!(7), 3, fp!(2.333333333333333333), fp!(2.333333333333333334));
-            (fp!(-7), 3, fp!(-2.333333333333333334), fp!(-2.333333333333333333));
-            (fp!(-7), -3, fp!(2.333333333333333333), fp!(2.333333333333333334));
-            (fp!(7), -3, fp!(-2.333333333333333334), fp!(-2.333333333333333333));
-            (fp!(0.000000000000000003), 2, fp!(0.000000000000000001), fp!(0.000000000000000002));
-            (fp!(0.000000000000000003), 7, fp!(0), fp!(0.000000000000000001));
-            (fp!(0.000000000000000001), 7, fp!(0), fp!(0.000000000000000001));
+            (FixedPoint::MIN, "-170141183460469231731.687303715884105728");
+            (FixedPoint::MAX, "170141183460469231731.687303715884105727");
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.to_array_string().len(), FixedPoint::MAX_DISPLAY_LEN);
+        },
+        all {
+            ();
         },
     };
+
+    #[cfg(feature = "i64")]
+    {
+        type Zero = crate::FixedPoint<i64, typenum::U0>;
+        type Eighteen = crate::FixedPoint<i64, typenum::U18>;
+        assert_eq!(Zero::MIN.to_array_string(), "-9223372036854775808.0");
+        assert_eq!(Zero::MAX_DISPLAY_LEN, 22);
+        assert_eq!(Zero::MIN.to_array_string().len(), Zero::MAX_DISPLAY_LEN);
+        assert_eq!(Eighteen::MIN.to_array_string(), "-9.223372036854775808");
+        assert_eq!(Eighteen::MAX_DISPLAY_LEN, 21);
+    }
+    #[cfg(feature = "i128")]
+    {
+        type Zero = crate::FixedPoint<i128, typenum::U0>;
+        assert_eq!(Zero::MIN.to_array_string().len(), Zero::MAX_DISPLAY_LEN);
+        assert_eq!(Zero::MAX_DISPLAY_LEN, 42);
+    }
+    #[cfg(feature = "i16")]
+    {
+        type Two = crate::FixedPoint<i16, typenum::U2>;
+        assert_eq!(Two::MIN.to_array_string(), "-327.68");
+        assert_eq!(Two::MAX_DISPLAY_LEN, 7);
+    }
     Ok(())
 }
 
 #[test]
-fn rdiv_round() -> Result<()> {
+fn array_string() {
+    use core::fmt::Write;
+
+    let mut s = crate::ArrayString::<8>::new();
+    assert!(s.is_empty());
+    write!(s, "{}-{}", 1, 2).unwrap();
+    assert_eq!(s, "1-2");
+    assert_eq!(s.len(), 3);
+    assert!(write!(s, "{}", 123_456).is_err());
+    assert_eq!(s.as_str(), "1-2");
+    write!(s, "ё45").unwrap();
+    assert_eq!(s, "1-2ё45");
+    assert!(s.write_str("ё").is_err());
+    s.write_str("6").unwrap();
+    assert_eq!(s.len(), crate::ArrayString::<8>::CAPACITY);
+    s.clear();
+    assert_eq!(s, crate::ArrayString::<4>::default());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn exp_format() -> Result<()> {
     test_fixed_point! {
-        case (
-            numerator | FixedPoint,
-            denominator | FixedPoint,
-            expected_ceil | FixedPoint,
-            expected_floor | FixedPoint,
-        ) => {
-            assert_eq!(numerator.rdiv(denominator, Ceil)?, expected_ceil);
-            assert_eq!(numerator.rdiv(denominator, Floor)?, expected_floor);
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(format!("{:e}", x), expected);
+            assert_eq!(format!("{:E}", x), expected.replace('e', "E"));
+        },
+        all {
+            (fp!(0), "0e0");
+            (fp!(1), "1e0");
+            (fp!(1200), "1.2e3");
+            (fp!(-1200), "-1.2e3");
+            (fp!(12.34), "1.234e1");
+            (fp!(-0.00123), "-1.23e-3");
+            (fp!(0.000000001), "1e-9");
+            (fp!(0.00000015), "1.5e-7");
         },
         fp64 {
-            (fp!(100), fp!(3), fp!(33.333333334), fp!(33.333333333));
-            (fp!(-100), fp!(-3), fp!(33.333333334), fp!(33.333333333));
-            (fp!(-100), fp!(3), fp!(-33.333333333), fp!(-33.333333334));
-            (fp!(100), fp!(-3), fp!(-33.333333333), fp!(-33.333333334));
+            (FixedPoint::MAX, "9.223372036854775807e9");
+            (FixedPoint::MIN, "-9.223372036854775808e9");
         },
         fp128 {
-            (fp!(100), fp!(3), fp!(33.333333333333333334), fp!(33.333333333333333333));
-            (fp!(-100), fp!(-3), fp!(33.333333333333333334), fp!(33.333333333333333333));
-            (fp!(-100), fp!(3), fp!(-33.333333333333333333), fp!(-33.333333333333333334));
-            (fp!(100), fp!(-3), fp!(-33.333333333333333333), fp!(-33.333333333333333334));
+            (fp!(0.000000000000000001), "1e-18");
+            (FixedPoint::MAX, "1.70141183460469231731687303715884105727e20");
         },
     };
-    Ok(())
-}
-
-#[test]
-fn rdiv_layout() -> Result<()> {
     test_fixed_point! {
-        case (
-            a | Layout,
-            b | Layout,
-            expected_floor | Layout,
-            expected_ceil | Layout,
-        ) => {
-            assert_eq!(a.rdiv(b, Floor)?, expected_floor);
-            assert_eq!(a.rdiv(b, Ceil)?, expected_ceil);
-            assert_eq!(a.rdiv(-b, Floor)?, -expected_ceil);
-            assert_eq!((-a).rdiv(b, Floor)?, -expected_ceil);
-            assert_eq!(a.rdiv(-b, Ceil)?, -expected_floor);
-            assert_eq!((-a).rdiv(b, Ceil)?, -expected_floor);
-            assert_eq!((-a).rdiv(-b, Floor)?, expected_floor);
-            assert_eq!((-a).rdiv(-b, Ceil)?, expected_ceil);
+        case (x | FixedPoint, expected | &str) => {
+            assert_eq!(format!("{:.2e}", x), expected);
         },
         all {
-            (5, 2, 2, 3);
-            (0, 5, 0, 0);
+            (fp!(0), "0.00e0");
+            (fp!(1), "1.00e0");
+            (fp!(1.234), "1.23e0");
+            (fp!(-1.235), "-1.24e0");
+            (fp!(1.245), "1.24e0");
+            (fp!(1.2451), "1.25e0");
+            (fp!(9.995), "1.00e1");
+            (fp!(-9.999), "-1.00e1");
+            (fp!(0.0001), "1.00e-4");
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, f | f64) => {
+            assert_eq!(format!("{:.0e}", x), format!("{:.0e}", f));
+            assert_eq!(format!("{:+e}", x), format!("{:+e}", f));
+            assert_eq!(format!("{:>10e}|", x), format!("{:>10e}|", f));
+            assert_eq!(format!("{:<10e}|", x), format!("{:<10e}|", f));
+            assert_eq!(format!("{:010e}", x), format!("{:010e}", f));
+            assert_eq!(format!("{:12.3E}", x), format!("{:12.3E}", f));
+            assert_eq!(format!("{:.150e}", x), format!("{:.150e}", f));
+            assert_eq!(format!("{:>200.150e}", x), format!("{:.150e}", f));
+        },
+        all {
+            (fp!(0), 0.);
+            (fp!(2.5), 2.5);
+            (fp!(3.5), 3.5);
+            (fp!(-12), -12.);
+            (fp!(0.125), 0.125);
+            (fp!(-1500), -1500.);
         },
     };
     Ok(())
 }
 
 #[test]
-fn rdiv_division_by_zero() -> Result<()> {
+#[cfg(feature = "std")]
+fn display_and_integral_sample() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint) => {
-            let expected = Err(ArithmeticError::DivisionByZero);
-            assert_eq!(x.rdiv(FixedPoint::ZERO, Floor), expected);
-            assert_eq!(x.rdiv(FixedPoint::ZERO, Ceil), expected);
-        },
-        all {
-            (fp!(0));
-            (fp!(1));
-            (fp!(-1));
-            (FixedPoint::MAX);
-            (FixedPoint::MIN);
+        case () => {
+            let coef = *FixedPoint::ONE.as_bits();
+            let precision = FixedPoint::PRECISION as usize;
+
+            let mut samples = vec![0, 1, -1, coef, -coef, coef - 1, 1 - coef, Layout::MAX, Layout::MIN];
+            // Cheap LCG with varying shifts to cover all magnitudes.
+            let layout_bits = 8 * core::mem::size_of::<Layout>();
+            let mut state = 0x2545_f491_4f6c_dd1d_u128;
+            for i in 0..10_000 {
+                state = state
+                    .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+                    .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+                samples.push((state as Layout) >> (i % layout_bits));
+            }
+
+            for bits in samples {
+                let x = FixedPoint::from_bits(bits);
+
+                let digits = format!("{:0>width$}", bits.unsigned_abs(), width = precision + 1);
+                let (int, frac) = digits.split_at(digits.len() - precision);
+                let frac = frac.trim_end_matches('0');
+                let sign = if bits < 0 { "-" } else { "" };
+                let expected = format!("{}{}.{}", sign, int, if frac.is_empty() { "0" } else { frac });
+                assert_eq!(x.to_string(), expected);
+
+                let floor = bits.div_euclid(coef);
+                let ceil = floor + (bits.rem_euclid(coef) != 0) as Layout;
+                assert_eq!(x.integral(Floor), floor);
+                assert_eq!(x.integral(Ceil), ceil);
+            }
         },
     };
     Ok(())
 }
 
 #[test]
-fn rdiv_overflow() -> Result<()> {
+#[cfg(all(feature = "i32", feature = "i64", feature = "i128"))]
+fn try_convert() -> Result<()> {
+    use typenum::{U18, U2, U30, U9};
+
+    type Amount = FixedPoint<i64, U9>;
+    type Cents = FixedPoint<i32, U2>;
+    type Wide = FixedPoint<i128, U18>;
+
+    let fits: Amount = "1.25".parse()?;
+    let rounded: Amount = "-1.255".parse()?;
+    let huge: Amount = "30000000".parse()?;
+
+    assert_eq!(
+        fits.try_convert::<i32, U2>(Floor)?,
+        "1.25".parse::<Cents>()?
+    );
+    assert_eq!(fits.try_convert::<i32, U2>(Ceil)?, "1.25".parse::<Cents>()?);
+    assert_eq!(
+        rounded.try_convert::<i32, U2>(Floor)?,
+        "-1.26".parse::<Cents>()?
+    );
+    assert_eq!(
+        rounded.try_convert::<i32, U2>(Ceil)?,
+        "-1.25".parse::<Cents>()?
+    );
+    assert_eq!(
+        huge.try_convert::<i32, U2>(Floor),
+        Err(ConvertError::new("too big number"))
+    );
+    assert_eq!(
+        huge.try_convert::<i64, U2>(Floor)?,
+        "30000000".parse::<FixedPoint<i64, U2>>()?
+    );
+
+    // 3e25 doesn't fit `i64`, but rescaling happens in the wider layout.
+    assert_eq!(
+        fits.try_convert::<i128, U18>(Floor)?,
+        "1.25".parse::<Wide>()?
+    );
+    assert_eq!(
+        huge.try_convert::<i128, U18>(Floor)?,
+        "30000000".parse::<Wide>()?
+    );
+
+    assert_eq!(
+        fits.try_convert_exact::<i32, U2>()?,
+        "1.25".parse::<Cents>()?
+    );
+    assert_eq!(
+        rounded.try_convert_exact::<i32, U2>(),
+        Err(ConvertError::new("requested precision is too low"))
+    );
+    assert_eq!(
+        huge.try_convert_exact::<i32, U2>(),
+        Err(ConvertError::new("too big number"))
+    );
+    assert_eq!(
+        huge.try_convert_exact::<i128, U18>()?,
+        "30000000".parse::<Wide>()?
+    );
+
+    let tiny: Wide = "0.000000000000000001".parse()?;
+    assert_eq!(
+        tiny.try_convert::<i64, U2>(Floor)?,
+        FixedPoint::from_bits(0)
+    );
+    assert_eq!(
+        tiny.try_convert::<i64, U2>(Ceil)?,
+        "0.01".parse::<FixedPoint<i64, U2>>()?
+    );
+    assert_eq!(
+        Wide::MAX.try_convert::<i128, U30>(Floor),
+        Err(ConvertError::new("too big number"))
+    );
+    Ok(())
+}
+
+#[test]
+fn from_scaled_int() -> Result<()> {
     test_fixed_point! {
-        case (denominator | FixedPoint) => {
-            assert_eq!(
-                FixedPoint::MAX.rdiv(denominator, Ceil),
-                Err(ArithmeticError::Overflow)
-            );
+        case (value | i128, value_scale | u32, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(FixedPoint::from_scaled_int(value, value_scale, Floor)?, expected_floor);
+            assert_eq!(FixedPoint::from_scaled_int(value, value_scale, Ceil)?, expected_ceil);
         },
         all {
-            (fp!(0.999999999));
+            (1500, 3, fp!(1.5), fp!(1.5));
+            (-1500, 3, fp!(-1.5), fp!(-1.5));
+            (15, 0, fp!(15), fp!(15));
+            (0, 100, fp!(0), fp!(0));
+            (1, 100, fp!(0), FixedPoint::EPSILON);
+            (-1, 100, FixedPoint::EPSILON.cneg()?, fp!(0));
+            (i128::MAX, 100, fp!(0), FixedPoint::EPSILON);
+        },
+        fp64 {
+            (15, 10, fp!(0.000000001), fp!(0.000000002));
+            (-15, 10, fp!(-0.000000002), fp!(-0.000000001));
+            (123456789123, 11, fp!(1.234567891), fp!(1.234567892));
+            (9223372036854775807, 9, FixedPoint::MAX, FixedPoint::MAX);
         },
         fp128 {
-            (fp!(0.999999999999999999));
+            (15, 19, fp!(0.000000000000000001), fp!(0.000000000000000002));
+            (-15, 19, fp!(-0.000000000000000002), fp!(-0.000000000000000001));
+            (123456789123, 11, fp!(1.23456789123), fp!(1.23456789123));
         },
     };
-    Ok(())
-}
-
-#[test]
-fn float_mul() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.rmul(b, Ceil)?, expected);
+        case (value | i128, value_scale | u32) => {
+            assert!(FixedPoint::from_scaled_int(value, value_scale, Floor).is_err());
         },
         all {
-            (fp!(525), fp!(10), fp!(5250));
-            (fp!(525), fp!(0.0001), fp!(0.0525));
-            (FixedPoint::MAX, FixedPoint::ONE, FixedPoint::MAX);
-            (
-                FixedPoint::from_bits(Layout::MAX / 10 * 10),
-                fp!(0.1),
-                FixedPoint::from_bits(Layout::MAX / 10),
-            );
+            (i128::MAX, 0);
+            (i128::MIN, 5);
+            (170141183460469231732, 0);
+        },
+        fp64 {
+            (9223372036854775808, 9);
+            (10000000000, 0);
         },
     };
     Ok(())
 }
 
 #[test]
-fn float_mul_overflow() -> Result<()> {
+#[cfg(all(feature = "std", feature = "i64"))]
+fn display_roundtrip_and_ord() {
+    use core::cmp::Ordering;
+
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    // Compares decimal strings like `-12.5` numerically without parsing them into numbers.
+    fn cmp_decimal(a: &str, b: &str) -> Ordering {
+        fn split(s: &str) -> (bool, &str, &str) {
+            let (negative, s) = s.strip_prefix('-').map_or((false, s), |s| (true, s));
+            let (int, frac) = s.split_once('.').unwrap();
+            let frac = frac.trim_end_matches('0');
+            (negative && (int != "0" || !frac.is_empty()), int, frac)
+        }
+
+        let (a_negative, a_int, a_frac) = split(a);
+        let (b_negative, b_int, b_frac) = split(b);
+        let magnitude = a_int
+            .len()
+            .cmp(&b_int.len())
+            .then_with(|| a_int.cmp(b_int))
+            .then_with(|| a_frac.cmp(b_frac));
+
+        match (a_negative, b_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => magnitude,
+            (true, true) => magnitude.reverse(),
+        }
+    }
+
+    let mut samples = vec![Fp64::MIN, Fp64::MAX, Fp64::ZERO, Fp64::EPSILON, Fp64::ONE];
+    samples.push(Fp64::EPSILON.cneg().unwrap());
+    samples.push(Fp64::ONE.cneg().unwrap());
+    // Cheap LCG with varying shifts to cover all magnitudes.
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    for i in 0..10_000 {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let x = Fp64::from_bits((state as i64) >> (i % 64));
+        samples.push(x);
+        samples.push(x.saturating_add(Fp64::EPSILON));
+    }
+
+    for x in &samples {
+        assert_eq!(x.to_string().parse::<Fp64>().unwrap(), *x);
+    }
+
+    for pair in samples.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(
+            a.cmp(&b),
+            cmp_decimal(&a.to_string(), &b.to_string()),
+            "{} vs {}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn from_f64_rounded() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint) => {
-            assert!(a.rmul(b, Ceil).is_err());
+        case (value | f64, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(FixedPoint::from_f64_rounded(value, Floor)?, expected_floor);
+            assert_eq!(FixedPoint::from_f64_rounded(value, Ceil)?, expected_ceil);
+            assert_eq!(FixedPoint::from_f64(value, Floor)?, expected_floor);
+            assert_eq!(FixedPoint::from_f64(value, Ceil)?, expected_ceil);
+        },
+        all {
+            (0., fp!(0), fp!(0));
+            (1., fp!(1), fp!(1));
+            (-2.5, fp!(-2.5), fp!(-2.5));
+            (1e9, fp!(1000000000), fp!(1000000000));
+            (-0., fp!(0), fp!(0));
         },
         fp64 {
-            (fp!(140000), fp!(140000));
-            (fp!(-140000), fp!(140000));
+            (0.0000000015, fp!(0.000000001), fp!(0.000000002));
+            (-0.0000000015, fp!(-0.000000002), fp!(-0.000000001));
+            (1.1, fp!(1.1), fp!(1.100000001));
+            (1e-10, fp!(0), fp!(0.000000001));
         },
         fp128 {
-            (fp!(13043817826), fp!(13043817825));
-            (fp!(-13043817826), fp!(13043817825));
+            (0.0000000000000000015, fp!(0.000000000000000001), fp!(0.000000000000000002));
+            (-0.0000000000000000015, fp!(-0.000000000000000002), fp!(-0.000000000000000001));
+            (1e-19, fp!(0), fp!(0.000000000000000001));
+            (1.1, fp!(1.100000000000000088), fp!(1.100000000000000089));
+            (1e20, fp!(100000000000000000000), fp!(100000000000000000000));
         },
     };
-    Ok(())
-}
-
-#[test]
-fn half_sum_exact() -> Result<()> {
     test_fixed_point! {
-        case (expected | FixedPoint) => {
-            assert_eq!(FixedPoint::half_sum(expected, expected, Floor), expected);
-            assert_eq!(FixedPoint::half_sum(expected, expected, Ceil), expected);
+        case (value | f64, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_f64_rounded(value, Nearest)?, expected);
+            assert_eq!(FixedPoint::from_f64_rounded(-value, Nearest)?, expected.cneg()?);
         },
         all {
-            (fp!(0));
-            (fp!(1));
-            (fp!(-1));
-            (FixedPoint::MAX);
-            (FixedPoint::MIN);
+            (2.5, fp!(2.5));
+            (1e-19, fp!(0));
+        },
+        fp64 {
+            (0.0000000016, fp!(0.000000002));
+            (0.0000000014, fp!(0.000000001));
+            (1.1, fp!(1.1));
+            (1e-10, fp!(0));
+        },
+        fp128 {
+            (0.0000000000000000016, fp!(0.000000000000000002));
+            (1.1, fp!(1.100000000000000089));
         },
     };
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(FixedPoint::half_sum(a, b, Floor), expected);
-            assert_eq!(FixedPoint::half_sum(b, a, Floor), expected);
-            assert_eq!(FixedPoint::half_sum(a, b, Ceil), expected);
-            assert_eq!(FixedPoint::half_sum(b, a, Ceil), expected);
+        case (value | f64, toward_zero | FixedPoint, away_from_zero | FixedPoint) => {
+            assert_eq!(FixedPoint::from_f64_rounded(value, TowardZero)?, toward_zero);
+            assert_eq!(FixedPoint::from_f64_rounded(value, AwayFromZero)?, away_from_zero);
+            assert_eq!(FixedPoint::from_f64_rounded(-value, TowardZero)?, toward_zero.cneg()?);
+            assert_eq!(FixedPoint::from_f64_rounded(-value, AwayFromZero)?, away_from_zero.cneg()?);
         },
         all {
-            (fp!(1), fp!(3), fp!(2));
-            (fp!(1), fp!(2), fp!(1.5));
-            (fp!(7.123456789), fp!(7.123456783), fp!(7.123456786));
-            (fp!(9000), fp!(9050), fp!(9025));
-            (fp!(9000), fp!(-9000), fp!(0));
-            (fp!(9000000000), fp!(9000000002), fp!(9000000001));
-            (
-                fp!(9000000000.000000001),
-                fp!(-9000000000.000000005),
-                fp!(-0.000000002),
-            );
-            (FixedPoint::MAX, FixedPoint::MIN.cadd(FixedPoint::EPSILON)?, fp!(0));
+            (2.5, fp!(2.5), fp!(2.5));
+        },
+        fp64 {
+            (1.1, fp!(1.1), fp!(1.100000001));
         },
         fp128 {
-            (fp!(7.123456789123456789), fp!(7.123456789123456783), fp!(7.123456789123456786));
+            (1.1, fp!(1.100000000000000088), fp!(1.100000000000000089));
+        },
+    };
+    test_fixed_point! {
+        case (value | f64) => {
+            assert!(FixedPoint::from_f64_rounded(value, Floor).is_err());
+            assert!(FixedPoint::from_f64_rounded(value, Ceil).is_err());
+        },
+        all {
+            (f64::NAN);
+            (f64::INFINITY);
+            (f64::NEG_INFINITY);
+            (1e40);
+            (-1e40);
+            (f64::MAX);
+            (f64::MIN);
         },
     };
     Ok(())
 }
 
 #[test]
-fn half_sum_rounded() -> Result<()> {
+fn try_from_f64_exact() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
-            assert_eq!(FixedPoint::half_sum(a, b, Floor), expected_floor);
-            assert_eq!(FixedPoint::half_sum(b, a, Floor), expected_floor);
-            assert_eq!(FixedPoint::half_sum(a, b, Ceil), expected_ceil);
-            assert_eq!(FixedPoint::half_sum(b, a, Ceil), expected_ceil);
+        case (value | f64, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::try_from_f64_exact(value)?, expected);
+            assert_eq!(FixedPoint::try_from_f64_exact(-value)?, expected.cneg()?);
         },
         all {
-            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::EPSILON.cneg()?, fp!(0));
+            (0., fp!(0));
+            (1., fp!(1));
+            (2.5, fp!(2.5));
+            (0.125, fp!(0.125));
+            (1e9, fp!(1000000000));
+            (0.001953125, fp!(0.001953125));
+            (8589934591.75, fp!(8589934591.75));
         },
-        fp64 {
-            (fp!(9000000000.000000394), fp!(9000000001.000000397), fp!(9000000000.500000395), fp!(9000000000.500000396));
-            (
-                fp!(9000000000.000000001),
-                fp!(-9000000000.000000006),
-                fp!(-0.000000003),
-                fp!(-0.000000002),
+        fp128 {
+            (0.000003814697265625, fp!(0.000003814697265625));
+            (1e20, fp!(100000000000000000000));
+        },
+    };
+    test_fixed_point! {
+        case (value | f64) => {
+            assert!(FixedPoint::try_from_f64_exact(value).is_err());
+        },
+        all {
+            (0.1);
+            (1.1);
+            (5e-324);
+            (f64::NAN);
+            (f64::INFINITY);
+            (1e40);
+            (f64::MAX);
+        },
+        fp64 {
+            (0.0009765625);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn to_parts() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, negative | bool, integral | u128, fractional | u64, fractional_len | u8) => {
+            let expected = DecimalParts { negative, integral, fractional, fractional_len };
+            assert_eq!(x.to_parts(), expected);
+        },
+        all {
+            (fp!(0), false, 0, 0, 0);
+            (fp!(-12.340), true, 12, 34, 2);
+            (fp!(12.034), false, 12, 34, 3);
+            (fp!(-0.5), true, 0, 5, 1);
+            (fp!(7), false, 7, 0, 0);
+            (fp!(-7), true, 7, 0, 0);
+            (fp!(0.000000001), false, 0, 1, 9);
+        },
+        fp64 {
+            (FixedPoint::MAX, false, 9223372036, 854775807, 9);
+            (FixedPoint::MIN, true, 9223372036, 854775808, 9);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), false, 0, 1, 18);
+            (FixedPoint::MAX, false, 170141183460469231731, 687303715884105727, 18);
+            (FixedPoint::MIN, true, 170141183460469231731, 687303715884105728, 18);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(overflowing_literals)]
+fn from_good_str() -> Result<()> {
+    test_fixed_point! {
+        case (input | &str, expected | Layout) => {
+            let expected = FixedPoint::from_bits(expected);
+            let input: FixedPoint = input.parse()?;
+            assert_eq!(input, expected);
+
+            #[cfg(all(feature = "std", feature = "serde"))]
+            assert_eq!(
+                serde_json::from_str::<FixedPoint>(&format!("\"{}\"", input)).unwrap(),
+                expected
             );
-            (fp!(7.123456789), fp!(7.123456788), fp!(7.123456788), fp!(7.123456789));
+        },
+        fp64 {
+            ("1", 1000000000);
+            ("1.1", 1100000000);
+            ("1.02", 1020000000);
+            ("-1.02", -1020000000);
+            ("+1.02", 1020000000);
+            ("0.1234", 123400000);
+            ("-0.1234", -123400000);
+            ("123456789.123456789", 123456789123456789);
+            ("9223372036.854775807", 9223372036854775807);
+            ("-9223372036.854775808", -9223372036854775808);
         },
         fp128 {
-            (fp!(7.123456789123456789), fp!(7.123456789123456788), fp!(7.123456789123456788), fp!(7.123456789123456789));
+            ("1", 1000000000000000000);
+            ("1.1", 1100000000000000000);
+            ("1.02", 1020000000000000000);
+            ("-1.02", -1020000000000000000);
+            ("+1.02", 1020000000000000000);
+            ("0.1234", 123400000000000000);
+            ("-0.1234", -123400000000000000);
+            ("123456789.123456789", 123456789123456789000000000);
+            ("9223372036.854775807", 9223372036854775807000000000);
+            ("-9223372036.854775808", -9223372036854775808000000000);
+            ("170141183460469231731.687303715884105727",
+             170141183460469231731687303715884105727);
+            ("-170141183460469231731.687303715884105728",
+             -170141183460469231731687303715884105728);
         },
     };
     Ok(())
 }
 
 #[test]
-fn integral() -> Result<()> {
+fn from_bad_str() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, expected_floor | Layout, expected_ceil | Layout) => {
-            assert_eq!(a.integral(Floor), expected_floor);
-            assert_eq!(a.integral(Ceil), expected_ceil);
+        case (bad_str | &str) => {
+            let result: Result<FixedPoint, ConvertError> = bad_str.parse();
+            assert!(result.is_err(), "must not parse '{}'", bad_str);
+
+            #[cfg(all(feature = "std", feature = "serde"))]
+            assert!(serde_json::from_str::<FixedPoint>(&format!("\"{}\"", bad_str)).is_err());
         },
         all {
-            (FixedPoint::ZERO, 0, 0);
-            (fp!(0.0001), 0, 1);
-            (fp!(-0.0001), -1, 0);
-            (fp!(2.0001), 2, 3);
-            (fp!(-2.0001), -3, -2);
+            ("");
+            ("a.12");
+            ("12.a");
+            ("13.9999999999999999999999999999999999999999999999999999999999999");
+            ("100000000000000000000000");
+            ("170141183460469231731687303715.884105728");
+            ("13.0000000000000000001");
+            ("13.1000000000000000001");
+            ("9223372036.8547758204856183567");
+        },
+        fp64 {
+            ("13.0000000001");
+            ("13.1000000001");
+            ("9223372036.854775808");
         },
     };
     Ok(())
 }
 
 #[test]
-fn round_towards_zero_by() -> Result<()> {
+fn from_flexible_str() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, rounder | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(x.round_towards_zero_by(rounder), expected);
-            assert_eq!(x.cneg()?.round_towards_zero_by(rounder), expected.cneg()?);
+        case (input | &str, expected | FixedPoint) => {
+            assert_eq!(input.parse::<FixedPoint>()?, expected);
         },
         all {
-            (fp!(1234.56789), fp!(100), fp!(1200));
-            (fp!(1234.56789), fp!(10), fp!(1230));
-            (fp!(1234.56789), fp!(1), fp!(1234));
-            (fp!(1234.56789), fp!(0.1), fp!(1234.5));
-            (fp!(1234.56789), fp!(0.01), fp!(1234.56));
-            (fp!(1234.56789), fp!(0.001), fp!(1234.567));
-            (fp!(1234.56789), fp!(0.0001), fp!(1234.5678));
-            (fp!(1234.56789), fp!(0.00001), fp!(1234.56789));
+            ("1.", fp!(1));
+            ("-1.", fp!(-1));
+            (".5", fp!(0.5));
+            ("-.5", fp!(-0.5));
+            ("+.5", fp!(0.5));
+            ("+1.5", fp!(1.5));
+            ("-0.5", fp!(-0.5));
+            ("-0.000000001", fp!(-0.000000001));
+            ("-0", fp!(0));
+            ("007.50", fp!(7.5));
+            ("1_000", fp!(1000));
+            ("-1_000.000_1", fp!(-1000.0001));
+            ("1_2_3.4_5", fp!(123.45));
+            ("1_000.5e-3", fp!(1.0005));
+            (".5e1", fp!(5));
+            ("1.e2", fp!(100));
+            ("-1_5.0_0e-1", fp!(-1.5));
         },
-        fp128 {
-            (fp!(1234.56789123456789), fp!(0.0000000000001), fp!(1234.5678912345678));
-            (fp!(1234.56789123456789), fp!(0.00000000000001), fp!(1234.56789123456789));
+    };
+    test_fixed_point! {
+        case (input | &str) => {
+            assert!(input.parse::<FixedPoint>().is_err(), "must not parse '{}'", input);
+        },
+        all {
+            (".");
+            ("-");
+            ("+");
+            ("-.");
+            ("+-1");
+            ("--1");
+            ("- 1");
+            ("1 000");
+            ("1.2.3");
+            ("_1");
+            ("1_");
+            ("1__0");
+            ("1_.5");
+            ("1._5");
+            ("1.5_");
+            ("1.-5");
+            ("1.+5");
+            ("_.5e1");
+            ("1.5_e1");
+            ("0x10");
+            ("1,5");
         },
     };
     Ok(())
 }
 
 #[test]
-#[allow(clippy::cognitive_complexity)]
-fn next_power_of_ten() -> Result<()> {
+fn from_str_with_mode() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(x.next_power_of_ten()?, expected);
-            assert_eq!(x.cneg()?.next_power_of_ten()?, expected.cneg()?);
+        case (input | &str, floor | FixedPoint, ceil | FixedPoint, nearest | FixedPoint) => {
+            assert_eq!(FixedPoint::from_str_with_mode(input, Floor)?, floor);
+            assert_eq!(FixedPoint::from_str_with_mode(input, Ceil)?, ceil);
+            assert_eq!(FixedPoint::from_str_with_mode(input, Nearest)?, nearest);
+            assert_eq!(FixedPoint::from_str_with_mode(input, TowardZero)?, if floor.inner >= 0 { floor } else { ceil });
+            assert_eq!(FixedPoint::from_str_with_mode(input, AwayFromZero)?, if floor.inner >= 0 { ceil } else { floor });
         },
         all {
-            (fp!(0.000000001), fp!(0.000000001));
-            (fp!(0.000000002), fp!(0.00000001));
-            (fp!(0.000000009), fp!(0.00000001));
-            (fp!(0.0000001), fp!(0.0000001));
-            (fp!(0.0000002), fp!(0.000001));
-            (fp!(0.1), fp!(0.1));
-            (fp!(0.100000001), fp!(1));
-            (fp!(1), fp!(1));
-            (fp!(2), fp!(10));
-            (fp!(1234567), fp!(10000000));
-            (fp!(923372036.654775807), fp!(1000000000));
-            (fp!(-0.000000001), fp!(-0.000000001));
-            (fp!(-0.000000002), fp!(-0.00000001));
-            (fp!(-0.000000009), fp!(-0.00000001));
-            (fp!(-0.00000001), fp!(-0.00000001));
-            (fp!(-0.00000002), fp!(-0.0000001));
-            (fp!(-0.100000001), fp!(-1));
-            (fp!(-923372021.854775808), fp!(-1000000000));
+            ("1", fp!(1), fp!(1), fp!(1));
+            ("-1.5", fp!(-1.5), fp!(-1.5), fp!(-1.5));
+            (" 2.25 ", fp!(2.25), fp!(2.25), fp!(2.25));
+            ("1.5e3", fp!(1500), fp!(1500), fp!(1500));
+            ("1.1234567890000", fp!(1.123456789), fp!(1.123456789), fp!(1.123456789));
+        },
+        fp64 {
+            ("0.0000000015", fp!(0.000000001), fp!(0.000000002), fp!(0.000000002));
+            ("0.0000000014999", fp!(0.000000001), fp!(0.000000002), fp!(0.000000001));
+            ("-0.0000000015", fp!(-0.000000002), fp!(-0.000000001), fp!(-0.000000002));
+            ("-0.0000000001", fp!(-0.000000001), fp!(0), fp!(0));
+            ("1.99999999999999999999999", fp!(1.999999999), fp!(2), fp!(2));
+            ("15e-10", fp!(0.000000001), fp!(0.000000002), fp!(0.000000002));
+            ("-1.5e-10", fp!(-0.000000001), fp!(0), fp!(0));
         },
         fp128 {
-            (fp!(0.000000000000000001), fp!(0.000000000000000001));
-            (fp!(0.000000000000000002), fp!(0.00000000000000001));
-            (fp!(0.000000000000000009), fp!(0.00000000000000001));
-            (fp!(0.00000000000000001), fp!(0.00000000000000001));
-            (fp!(0.00000000000000002), fp!(0.0000000000000001));
-            (fp!(0.100000000000000001), fp!(1));
-            (fp!(1234567891234567), fp!(10000000000000000));
-            (fp!(923372036987654321.854775807), fp!(1000000000000000000));
-            (fp!(-0.000000000000000001), fp!(-0.000000000000000001));
-            (fp!(-0.000000000000000002), fp!(-0.00000000000000001));
-            (fp!(-0.000000000000000009), fp!(-0.00000000000000001));
-            (fp!(-0.00000000000000001), fp!(-0.00000000000000001));
-            (fp!(-0.00000000000000002), fp!(-0.0000000000000001));
-            (fp!(-0.100000000000000001), fp!(-1));
-            (fp!(-923372036987654321.854775808), fp!(-1000000000000000000));
+            ("0.0000000000000000015", fp!(0.000000000000000001), fp!(0.000000000000000002), fp!(0.000000000000000002));
+            ("1.99999999999999999999999", fp!(1.999999999999999999), fp!(2), fp!(2));
         },
     };
     test_fixed_point! {
-        case (x | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(x.next_power_of_ten()?, expected);
+        case (input | &str) => {
+            assert!(FixedPoint::from_str_with_mode(input, Nearest).is_err());
+        },
+        all {
+            ("");
+            ("1.5x");
+            ("1.23456789012345678901234a");
+            ("1.2345678901234567890123ё");
+            ("a.5");
+            ("1e100");
         },
         fp64 {
-            (fp!(0), fp!(0.000000001));
+            ("9223372036.8547758075");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_scientific_str() -> Result<()> {
+    test_fixed_point! {
+        case (input | &str, expected | FixedPoint) => {
+            assert_eq!(input.parse::<FixedPoint>()?, expected);
+        },
+        all {
+            ("7.02e5", fp!(702000));
+            ("7.02E5", fp!(702000));
+            ("7.02e+5", fp!(702000));
+            ("-7.02e5", fp!(-702000));
+            ("+7.02e5", fp!(702000));
+            ("-0.5e1", fp!(-5));
+            ("1.5e-7", fp!(0.00000015));
+            ("15e-8", fp!(0.00000015));
+            ("1e0", fp!(1));
+            ("0e100", fp!(0));
+            ("-0e-100", fp!(0));
+            ("1.500000000000000000000000000000e2", fp!(150));
+            ("1e-9", fp!(0.000000001));
+            ("  2.5e3\n", fp!(2500));
         },
         fp128 {
-            (fp!(0), fp!(0.000000000000000001));
+            ("1e-18", fp!(0.000000000000000001));
+            ("1.7e20", fp!(170000000000000000000));
         },
     };
     test_fixed_point! {
-        case (x | FixedPoint) => {
-            assert_eq!(x.next_power_of_ten(), Err(ArithmeticError::Overflow));
+        case (input | &str) => {
+            assert!(input.parse::<FixedPoint>().is_err(), "must not parse '{}'", input);
         },
         all {
-            (FixedPoint::MAX);
-            (FixedPoint::MIN);
+            ("e5");
+            ("1e");
+            ("1.5e");
+            ("1.5e1.5");
+            ("1.ae5");
+            ("1e5e5");
+            ("1e-100");
+            ("1e100");
+            ("1e2147483647");
+            ("-1e2147483647");
+            ("1.5e-2147483648");
+            ("100000000000000000000000000000000000000000e-30");
         },
         fp64 {
-            (fp!(9223372036.654775807));
-            (fp!(-9223372036.654775807));
-        },
-        fp128 {
-            (fp!(150000000000000000000.0));
-            (fp!(-150000000000000000000.854775807));
+            ("1.5e-9");
+            ("1e10");
         },
     };
     Ok(())
 }
 
 #[test]
-fn rounding_to_i64() -> Result<()> {
+#[cfg(feature = "alloc")]
+fn parse_list() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, expected | i64) => {
-            assert_eq!(x.rounding_to_i64(), expected);
+        case (input | &str, expected | &[FixedPoint]) => {
+            assert_eq!(FixedPoint::parse_list(input)?, expected);
         },
         all {
-            (fp!(0), 0);
-            (fp!(42), 42);
-            (fp!(1.4), 1);
-            (fp!(1.6), 2);
-            (fp!(-1.4), -1);
-            (fp!(-1.6), -2);
-            (fp!(0.4999), 0);
-            (fp!(0.5), 1);
-            (fp!(0.5001), 1);
+            ("1 -2.5 0.000000001", &[fp!(1), fp!(-2.5), fp!(0.000000001)]);
+            ("  7\t\n8.25  ", &[fp!(7), fp!(8.25)]);
+            ("", &[]);
+            (" \t\n", &[]);
         },
     };
     Ok(())
 }
 
 #[test]
-#[cfg(feature = "std")]
-#[allow(clippy::float_cmp)]
-fn to_f64() -> Result<()> {
+fn from_str_stripping() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, expected | f64) => {
-            assert_eq!(f64::from(x), expected);
+        case (input | &str, prefixes | &[&str], suffixes | &[&str], expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_str_stripping(input, prefixes, suffixes)?, expected);
         },
         all {
-            (fp!(0), 0.0);
-            (fp!(0.1), 0.1);
-            (fp!(1), 1.0);
-            (fp!(1.5), 1.5);
-            (fp!(-5), -5.);
-            (fp!(-14.14), -14.14);
-            (fp!(42.123456789), 42.123456789);
-            (fp!(-42.123456789), -42.123456789);
-            (fp!(8003332421.536753168), 8003332421.536753);
-            (fp!(-8003332421.536753168), -8003332421.536753);
-            (fp!(9223372036.854775807), 9223372036.854776);
-            (fp!(-9223372036.854775807), -9223372036.854776);
-            (fp!(922337203.685477581), 922337203.6854776);
-            (fp!(-922337203.685477581), -922337203.6854776);
-            (fp!(92233720.36854775), 92233720.36854775);
-            (fp!(-92233720.36854775), -92233720.36854775);
+            ("$1.50", &["$"], &[], fp!(1.5));
+            ("  $1.50\n", &["$"], &[], fp!(1.5));
+            ("$ 1.50", &["$"], &[], fp!(1.5));
+            ("$-1.50", &["$"], &[], fp!(-1.5));
+            ("1.50 USD", &[], &[" USD"], fp!(1.5));
+            ("1.50 USD", &["$"], &[" EUR", " USD"], fp!(1.5));
+            ("$1.50 USD", &["$"], &[" USD"], fp!(1.5));
+            ("1.50", &["$"], &[" USD"], fp!(1.5));
         },
-        fp128 {
-            (fp!(0.0000000000025), 25e-13);
-            (fp!(1000000.0000000000025), 1e6);
-            (fp!(-1000000.0000000000025), -1e6);
-            (fp!(0.000000000000000025), 25e-18);
-            (fp!(-0.000000000000000025), -25e-18);
-            (fp!(2.1234567890123457), 2.1234567890123457);
-            (fp!(-2.1234567890123457), -2.1234567890123457);
+    };
+    test_fixed_point! {
+        case (input | &str, prefixes | &[&str], suffixes | &[&str]) => {
+            assert!(FixedPoint::from_str_stripping(input, prefixes, suffixes).is_err());
+        },
+        all {
+            ("1.50 EUR", &["$"], &[" USD"]);
+            ("$$1.50", &["$"], &[]);
+            ("USD", &[], &["USD"]);
         },
     };
     Ok(())
 }
 
 #[test]
-#[cfg(feature = "std")]
-#[allow(clippy::float_cmp)]
-fn from_f64() -> Result<()> {
+#[cfg(feature = "alloc")]
+fn parse_bad_list() -> Result<()> {
+    use alloc::string::ToString;
+
     test_fixed_point! {
-        case (x | FixedPoint, expected | f64) => {
-            assert_eq!(Ok(x), expected.try_into());
-            assert_eq!(Ok(x.cneg().unwrap()), (-expected).try_into());
+        case (input | &str, index | usize) => {
+            let err = FixedPoint::parse_list(input).unwrap_err();
+            assert_eq!(err.index(), Some(index));
+            assert_eq!(anyhow::Error::from(err.clone()).to_string(), err.to_string());
         },
         all {
-            (fp!(0), 0.0);
-            (fp!(0.5), 0.5);
-            (fp!(1), 1.0);
-            (fp!(1.5), 1.5);
-            (fp!(42.123456789), 42.123456789);
+            ("oops", 0);
+            ("1 2.5 x.1 4", 2);
+            ("1 2 3 4.a 5.b", 3);
+        },
+    };
+    Ok(())
+}
 
-            (fp!(803332.421536753), 803332.421536753);
-            (fp!(8033324.21536753), 8033324.21536753);
-            (fp!(80333242.1536753), 80333242.1536753);
-            (fp!(803332421.536753), 803332421.536753);
-            (fp!(8033324215.36753), 8033324215.36753);
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_default_format() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let json = serde_json::to_string(&x).unwrap();
+            assert_eq!(json, format!("\"{}\"", x));
+            assert_eq!(serde_json::from_str::<FixedPoint>(&json).unwrap(), x);
 
-            (fp!(9223372036.85477), 9223372036.85477);
+            let bytes = bincode1::serialize(&x).unwrap();
+            assert_eq!(bytes, x.into_bits().to_le_bytes());
+            assert_eq!(bincode1::deserialize::<FixedPoint>(&bytes).unwrap(), x);
         },
-        fp128 {
-            (fp!(0.803332421536753), 0.803332421536753);
-            (fp!(8.03332421536753), 8.03332421536753);
-            (fp!(8.03332421536753), 8.03332421536753);
-            (fp!(80.3332421536753), 80.3332421536753);
-            (fp!(803.332421536753), 803.332421536753);
-            (fp!(8033.32421536753), 8033.32421536753);
-            (fp!(80333.2421536753), 80333.2421536753);
-            // <see part of cases in `all` sections>
-            (fp!(80333242153.6753), 80333242153.6753);
-            (fp!(803332421536.753), 803332421536.753);
-            (fp!(8033324215367.53), 8033324215367.53);
-            (fp!(80333242153675.3), 80333242153675.3);
-            (fp!(803332421536753), 803332421536753.);
-            (fp!(8033324215367530), 8033324215367530.);
-            (fp!(8033324215367533), 8033324215367533.);
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-42.000001));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
         },
     };
     Ok(())
 }
 
 #[test]
-fn saturating_add() -> Result<()> {
+#[cfg(feature = "parity")]
+fn parity_max_encoded_len() -> Result<()> {
+    use parity_scale_codec::{Encode, MaxEncodedLen};
+
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.saturating_add(b), expected);
-            assert_eq!(b.saturating_add(a), expected);
-            assert_eq!(a.cneg()?.saturating_add(b.cneg()?), expected.cneg()?);
+        case (x | FixedPoint) => {
+            assert_eq!(FixedPoint::max_encoded_len(), core::mem::size_of::<Layout>());
+            assert_eq!(x.encoded_size(), FixedPoint::max_encoded_len());
         },
         all {
-            (fp!(0), fp!(0), fp!(0));
-            (fp!(0), fp!(3000.0000006), fp!(3000.0000006));
-            (fp!(-1000.0000002), fp!(0), fp!(-1000.0000002));
-            (fp!(-1000.0000002), fp!(3000.0000006), fp!(2000.0000004));
-            (fp!(-1000.0000002), fp!(-3000.0000006), fp!(-4000.0000008));
-            (fp!(4611686018.427387903), fp!(4611686018.427387903), fp!(9223372036.854775806));
-        },
-        fp128 {
-            (fp!(0), fp!(3000000000000.0000000000000006), fp!(3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(-1000000000000.0000000000000002));
-            (fp!(-1000000000000.0000000000000002), fp!(3000000000000.0000000000000006), fp!(2000000000000.0000000000000004));
-            (fp!(-1000000000000.0000000000000002), fp!(-3000000000000.0000000000000006), fp!(-4000000000000.0000000000000008));
-            (fp!(4611686018000000000.000000000427387903), fp!(4611686018000000000.000000000427387903), fp!(9223372036000000000.000000000854775806));
+            (fp!(0));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
         },
     };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parity")]
+fn parity_compact() -> Result<()> {
+    use parity_scale_codec::{Decode, Encode};
+
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.saturating_add(b), expected);
+        case (x | FixedPoint, expected_len | usize) => {
+            #[derive(Debug, PartialEq, Encode, Decode)]
+            struct Struct {
+                #[codec(compact)]
+                number: FixedPoint,
+            }
+
+            let bytes = Struct { number: x }.encode();
+            assert_eq!(bytes.len(), expected_len);
+            assert_eq!(Struct::decode(&mut &bytes[..]).unwrap(), Struct { number: x });
+        },
+        all {
+            (fp!(0), 1);
+            (FixedPoint::from_bits(63), 1);
+            (FixedPoint::from_bits(64), 2);
         },
         fp64 {
-            (fp!(9222222222), fp!(9222222222), FixedPoint::MAX);
-            (fp!(4611686019), fp!(4611686018.427387903), FixedPoint::MAX);
-            (fp!(-9222222222), fp!(-9222222222), FixedPoint::MIN);
-            (fp!(-4611686019), fp!(-4611686018.427387903), FixedPoint::MIN);
+            (fp!(1.5), 5);
+            // Negative values are encoded as big unsigned integers.
+            (fp!(-1), 9);
         },
         fp128 {
-            (fp!(85550005550005550005), fp!(85550005550005550005), FixedPoint::MAX);
-            (fp!(85550005550005550005), fp!(85550005550005550005.000000000427387), FixedPoint::MAX);
-            (fp!(-85550005550005550005), fp!(-85550005550005550005), FixedPoint::MIN);
-            (fp!(-85550005550005550005), fp!(-85550005550005550005.000000000427387), FixedPoint::MIN);
+            (fp!(1.5), 9);
+            (fp!(-1), 17);
         },
     };
     Ok(())
 }
 
 #[test]
-fn saturating_mul() -> Result<()> {
+#[cfg(feature = "bytemuck")]
+fn bytemuck() -> Result<()> {
     test_fixed_point! {
-        case (a | FixedPoint, b | Layout, expected | FixedPoint) => {
-            assert_eq!(a.saturating_mul(b), expected);
-            assert_eq!(CheckedMul::saturating_mul(b, a), expected);
-            assert_eq!(a.cneg()?.saturating_mul(b), expected.cneg()?);
-            assert_eq!(a.saturating_mul(-b), expected.cneg()?);
-            assert_eq!(a.cneg()?.saturating_mul(-b), expected);
+        case (x | FixedPoint) => {
+            let values = [x, FixedPoint::ZERO, x];
+            let bytes: &[u8] = ::bytemuck::cast_slice(&values);
+            assert_eq!(bytes.len(), 3 * core::mem::size_of::<Layout>());
+            assert_eq!(&bytes[..bytes.len() / 3], &x.into_bits().to_ne_bytes()[..]);
+            assert_eq!(::bytemuck::cast_slice::<u8, FixedPoint>(bytes), &values[..]);
+
+            let bits: Layout = ::bytemuck::cast(x);
+            assert_eq!(bits, x.into_bits());
         },
         all {
-            (fp!(0), 0, fp!(0));
-            (fp!(3000.0000006), 0, fp!(0));
-            (fp!(3000.0000006), 1, fp!(3000.0000006));
-            (fp!(-1000.0000002), 0, fp!(0));
-            (fp!(-1000.0000002), 3, fp!(-3000.0000006));
-            (fp!(-1000.0000002), -4, fp!(4000.0000008));
-            (fp!(68601.48179), -468, fp!(-32105493.47772));
-        },
-        fp128 {
-            (fp!(3000000000000.0000000000000006), 0, FixedPoint::ZERO);
-            (fp!(3000000000000.0000000000000006), 1, fp!(3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), 0, FixedPoint::ZERO);
-            (fp!(-1000000000000.0000000000000002), 3, fp!(-3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), -4, fp!(4000000000000.0000000000000008));
-            (fp!(68603957391461.48475635294179), -85204, fp!(-5845331585582084347.18029605227516));
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-0.000000001));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
         },
     };
     test_fixed_point! {
-        case (a | FixedPoint, b | i128, expected | FixedPoint) => {
-            let b = b as Layout;
-            assert_eq!(a.saturating_mul(b), expected);
-        },
-        fp64 {
-            (fp!(9222222222), 9222222222, FixedPoint::MAX);
-            (fp!(4611686019.427387903), 4611686019, FixedPoint::MAX);
-            (fp!(-9222222222), 9222222222, FixedPoint::MIN);
-            (fp!(4611686019.427387903), -4611686019, FixedPoint::MIN);
-        },
-        fp128 {
-            (fp!(85550005550005550005), 85550005550005550005, FixedPoint::MAX);
-            (fp!(14000444000.427387), 14000444000, FixedPoint::MAX);
-            (fp!(-85550005550005550005), 85550005550005550005, FixedPoint::MIN);
-            (fp!(14000444000.427387), -14000444000, FixedPoint::MIN);
+        case () => {
+            assert_eq!(<FixedPoint as ::bytemuck::Zeroable>::zeroed(), FixedPoint::ZERO);
         },
     };
     Ok(())
 }
 
 #[test]
-fn saturating_rmul() -> Result<()> {
+#[cfg(feature = "zerocopy")]
+fn zerocopy() -> Result<()> {
+    use ::zerocopy::{
+        byteorder::{LittleEndian, I64},
+        AsBytes, FromBytes, FromZeroes, Unaligned,
+    };
+
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.saturating_rmul(b, Floor), expected);
-            assert_eq!(b.saturating_rmul(a, Floor), expected);
-            assert_eq!(a.cneg()?.saturating_rmul(b, Floor), expected.cneg()?);
-            assert_eq!(a.saturating_rmul(b.cneg()?, Floor), expected.cneg()?);
-            assert_eq!(a.cneg()?.saturating_rmul(b.cneg()?, Floor), expected);
+        case (x | FixedPoint) => {
+            #[derive(Debug, PartialEq, FromZeroes, FromBytes, AsBytes)]
+            #[repr(C)]
+            struct Packet {
+                price: FixedPoint,
+                quantity: FixedPoint,
+            }
+
+            assert_eq!(x.as_bytes(), &x.into_bits().to_ne_bytes()[..]);
+            assert_eq!(FixedPoint::read_from(x.as_bytes()), Some(x));
+
+            let packet = Packet { price: x, quantity: FixedPoint::ONE };
+            assert_eq!(Packet::read_from(packet.as_bytes()), Some(packet));
         },
         all {
-            (fp!(0), fp!(0), fp!(0));
-            (fp!(0), fp!(3000.0000006), fp!(0));
-            (fp!(1), fp!(3000.0000006), fp!(3000.0000006));
-            (fp!(-1000.0000002), fp!(0), fp!(0));
-            (fp!(-1000.0000002), fp!(3), fp!(-3000.0000006));
-            (fp!(-1000.0000002), fp!(-4), fp!(4000.0000008));
-            (fp!(68601.48179), fp!(-468.28), fp!(-32124701.8926212));
-        },
-        fp128 {
-            (fp!(0), fp!(3000000000000.0000000000000006), fp!(0));
-            (fp!(1), fp!(3000000000000.0000000000000006), fp!(3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(0));
-            (fp!(-1000000000000.0000000000000002), fp!(3), fp!(-3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), fp!(-4), fp!(4000000000000.0000000000000008));
+            (fp!(0));
+            (fp!(1.5));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
         },
     };
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, mode | RoundMode, expected | FixedPoint) => {
-            assert_eq!(a.saturating_rmul(b, mode), expected);
-        },
-        fp64 {
-            (fp!(0.000000001), fp!(-0.1), Floor, fp!(-0.000000001));
-            (fp!(0.000000001), fp!(0.1), Ceil, fp!(0.000000001));
-            (fp!(0.000000001), fp!(0.1), Floor, fp!(0));
-            (fp!(-0.000000001), fp!(0.1), Ceil, fp!(0));
-            (fp!(9222222222), fp!(9222222222), Floor, FixedPoint::MAX);
-            (fp!(4611686019), fp!(4611686018.427387903), Floor, FixedPoint::MAX);
-            (fp!(-9222222222), fp!(9222222222), Floor, FixedPoint::MIN);
-            (fp!(4611686019), fp!(-4611686018.427387903), Floor, FixedPoint::MIN);
-        },
-        fp128 {
-            (fp!(0.000000000000000001), fp!(0.1), Floor, fp!(0));
-            (fp!(0.000000000000000001), fp!(-0.1), Floor, fp!(-0.000000000000000001));
-            (fp!(0.000000000000000001), fp!(0.1), Ceil, fp!(0.000000000000000001));
-            (fp!(-0.000000000000000001), fp!(0.1), Ceil, fp!(0));
-            (fp!(85550005550005550005), fp!(85550005550005550005), Floor, FixedPoint::MAX);
-            (fp!(4611686019), fp!(4611686018000000000.000000000427387903), Floor, FixedPoint::MAX);
-            (fp!(-85550005550005550005), fp!(85550005550005550005), Floor, FixedPoint::MIN);
-            (fp!(4611686019), fp!(-4611686018000000000.000000000427387903), Floor, FixedPoint::MIN);
+        case () => {
+            assert_eq!(FixedPoint::new_zeroed(), FixedPoint::ZERO);
         },
     };
+
+    // Unaligned if the inner type is, e.g. for fields of packed structs.
+    fn assert_unaligned<T: Unaligned>() {}
+    assert_unaligned::<crate::FixedPoint<I64<LittleEndian>, typenum::U9>>();
     Ok(())
 }
 
 #[test]
-fn saturating_sub() -> Result<()> {
+#[cfg(feature = "diesel")]
+fn diesel() -> Result<()> {
+    use ::diesel::{
+        expression::AsExpression,
+        pg::data_types::PgNumeric,
+        sql_types::{Nullable, Numeric},
+    };
+
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.saturating_sub(b), expected);
-            assert_eq!(b.saturating_sub(a), expected.cneg()?);
-            assert_eq!(a.cneg()?.saturating_sub(b.cneg()?), expected.cneg()?);
+        case (x | FixedPoint, numeric | PgNumeric) => {
+            assert_eq!(PgNumeric::from(x), numeric);
+            assert_eq!(FixedPoint::try_from(numeric)?, x);
         },
-        all {
-            (fp!(0), fp!(0), fp!(0));
-            (fp!(0), fp!(3000.0000006), fp!(-3000.0000006));
-            (fp!(-1000.0000002), fp!(0), fp!(-1000.0000002));
-            (fp!(-1000.0000002), fp!(3000.0000006), fp!(-4000.0000008));
-            (fp!(-1000.0000002), fp!(-3000.0000006), fp!(2000.0000004));
-            (fp!(4611686018.427387903), fp!(-4611686018.427387903), fp!(9223372036.854775806));
+        fp64 {
+            (fp!(0), PgNumeric::Positive { weight: 0, scale: 9, digits: vec![] });
+            (fp!(1.5), PgNumeric::Positive { weight: 0, scale: 9, digits: vec![1, 5000] });
+            (fp!(10000), PgNumeric::Positive { weight: 1, scale: 9, digits: vec![1] });
+            (fp!(0.000000001), PgNumeric::Positive { weight: -3, scale: 9, digits: vec![1000] });
+            (
+                fp!(-12345.000000001),
+                PgNumeric::Negative { weight: 1, scale: 9, digits: vec![1, 2345, 0, 0, 1000] }
+            );
         },
         fp128 {
-            (fp!(0), fp!(3000000000000.0000000000000006), fp!(-3000000000000.0000000000000006));
-            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(-1000000000000.0000000000000002));
-            (fp!(-1000000000000.0000000000000002), fp!(3000000000000.0000000000000006), fp!(-4000000000000.0000000000000008));
-            (fp!(-1000000000000.0000000000000002), fp!(-3000000000000.0000000000000006), fp!(2000000000000.0000000000000004));
-            (fp!(4611686018000000000.000000000427387903), fp!(-4611686018000000000.000000000427387903), fp!(9223372036000000000.000000000854775806));
+            (fp!(0), PgNumeric::Positive { weight: 0, scale: 18, digits: vec![] });
+            (fp!(1.5), PgNumeric::Positive { weight: 0, scale: 18, digits: vec![1, 5000] });
+            (fp!(10000), PgNumeric::Positive { weight: 1, scale: 18, digits: vec![1] });
+            (
+                fp!(0.000000000000000001),
+                PgNumeric::Positive { weight: -5, scale: 18, digits: vec![100] }
+            );
+            (
+                fp!(-12345.000000001),
+                PgNumeric::Negative { weight: 1, scale: 18, digits: vec![1, 2345, 0, 0, 1000] }
+            );
         },
     };
+
+    // Scale validation: digits beyond `PRECISION` must be zeros.
     test_fixed_point! {
-        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
-            assert_eq!(a.saturating_sub(b), expected);
+        case (numeric | PgNumeric, expected | Option<FixedPoint>) => {
+            assert_eq!(FixedPoint::try_from(numeric).ok(), expected);
+        },
+        all {
+            (PgNumeric::Positive { weight: 0, scale: 30, digits: vec![2, 5000, 0, 0] }, Some(fp!(2.5)));
+            (PgNumeric::Positive { weight: -1, scale: 2, digits: vec![2500] }, Some(fp!(0.25)));
+            (PgNumeric::Negative { weight: -3, scale: 9, digits: vec![1000] }, Some(fp!(-0.000000001)));
+            (PgNumeric::Positive { weight: 10, scale: 0, digits: vec![1] }, None);
+            (PgNumeric::NaN, None);
         },
         fp64 {
-            (fp!(9222222222), fp!(-9222222222), FixedPoint::MAX);
-            (fp!(4611686019), fp!(-4611686018.27387903), FixedPoint::MAX);
-            (fp!(-9222222222), fp!(9222222222), FixedPoint::MIN);
-            (fp!(-4611686019), fp!(4611686018.47387903), FixedPoint::MIN);
+            (PgNumeric::Positive { weight: -3, scale: 12, digits: vec![1] }, None);
         },
         fp128 {
-            (fp!(85550005550005550005), fp!(-85550005550005550005), FixedPoint::MAX);
-            (fp!(85550005550005550005), fp!(-85550005550005550005.000000000427387903), FixedPoint::MAX);
-            (fp!(-85550005550005550005), fp!(85550005550005550005), FixedPoint::MIN);
-            (fp!(-85550005550005550005), fp!(85550005550005550005.000000000427387903), FixedPoint::MIN);
+            (PgNumeric::Positive { weight: -3, scale: 12, digits: vec![1] }, Some(fp!(0.000000000001)));
+        },
+    };
+
+    test_fixed_point! {
+        case () => {
+            // Usable in queries, including nullable columns.
+            let _ = AsExpression::<Numeric>::as_expression(FixedPoint::ONE);
+            let _ = AsExpression::<Nullable<Numeric>>::as_expression(FixedPoint::ONE);
         },
     };
     Ok(())
 }
 
 #[test]
-fn sqrt_exact() -> Result<()> {
+#[cfg(feature = "num-traits")]
+fn num_traits_ops() -> Result<()> {
+    use ::num_traits::{Num, Signed};
+
+    fn poly<T: Num + Copy>(x: T) -> T {
+        // x^2 - 2x + 1
+        x * x - (T::one() + T::one()) * x + T::one()
+    }
+
     test_fixed_point! {
-        case (expected | FixedPoint) => {
-            let square = expected.rmul(expected, Floor)?;
-            assert_eq!(expected.rmul(expected, Ceil)?, square);
-            assert_eq!(square.rsqrt(Floor)?, expected);
-            assert_eq!(square.rsqrt(Ceil)?, expected);
+        case (x | FixedPoint, y | FixedPoint, quotient | FixedPoint, remainder | FixedPoint) => {
+            assert_eq!(x / y, quotient);
+            assert_eq!(x % y, remainder);
+            assert_eq!(::num_traits::CheckedDiv::checked_div(&x, &y), Some(quotient));
         },
         all {
-            (fp!(0));
-            (fp!(1));
-            (fp!(2));
-            (fp!(3));
-            (fp!(1000));
-            (fp!(96038));
-            (FixedPoint::MAX.rsqrt(Floor)?.integral(Floor).try_into()?);
+            (fp!(7), fp!(2), fp!(3.5), fp!(1));
+            (fp!(7.5), fp!(-2), fp!(-3.75), fp!(1.5));
+        },
+        fp64 {
+            (fp!(7), fp!(3), fp!(2.333333333), fp!(1));
+            (fp!(-7), fp!(3), fp!(-2.333333333), fp!(-1));
+            (fp!(0.5), fp!(0.3), fp!(1.666666666), fp!(0.2));
         },
         fp128 {
-            (fp!(10431725));
-            (fp!(13043817825));
+            (fp!(7), fp!(3), fp!(2.333333333333333333), fp!(1));
+            (fp!(-7), fp!(3), fp!(-2.333333333333333333), fp!(-1));
+            (fp!(0.5), fp!(0.3), fp!(1.666666666666666666), fp!(0.2));
         },
     };
-    Ok(())
-}
-
-#[test]
-fn sqrt_approx() -> Result<()> {
     test_fixed_point! {
-        case (x | FixedPoint, expected_floor | FixedPoint) => {
-            assert_eq!(x.rsqrt(Floor)?, expected_floor);
-            assert_eq!(x.rsqrt(Ceil)?.inner, expected_floor.inner + 1);
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(poly(x), expected);
         },
-        fp64 {
-            (fp!(2), fp!(1.414213562));
-            (FixedPoint::MAX, fp!(96038.388349944));
+        all {
+            (fp!(1), fp!(0));
+            (fp!(3), fp!(4));
+            (fp!(-0.5), fp!(2.25));
         },
-        fp128 {
-            (fp!(2), fp!(1.414213562373095048));
-            (fp!(3.14159265358979323), fp!(1.772453850905516024));
-            (fp!(5), fp!(2.236067977499789696));
-            (FixedPoint::MAX, fp!(13043817825.332782212349571806));
+    };
+    test_fixed_point! {
+        case () => {
+            let x: FixedPoint = "-1.5".parse()?;
+            assert_eq!(FixedPoint::from_str_radix("-1.5", 10), Ok(x));
+            assert!(FixedPoint::from_str_radix("10", 16).is_err());
+            assert_eq!(-fp!(1.5), x);
+            assert_eq!(Signed::abs(&x), fp!(1.5));
+            assert_eq!(Signed::abs_sub(&fp!(1), &fp!(3)), fp!(0));
+            assert_eq!(Signed::abs_sub(&fp!(3), &fp!(1)), fp!(2));
+            assert_eq!(Signed::signum(&x), -fp!(1));
+            assert!(Signed::is_negative(&x));
+            assert!(::num_traits::Zero::is_zero(&fp!(0)));
+            assert_eq!(<FixedPoint as ::num_traits::Bounded>::max_value(), FixedPoint::MAX);
+            assert_eq!(::num_traits::CheckedAdd::checked_add(&FixedPoint::MAX, &fp!(1)), None);
+            assert_eq!(::num_traits::CheckedDiv::checked_div(&fp!(1), &fp!(0)), None);
+            assert_eq!(
+                ::num_traits::Saturating::saturating_sub(FixedPoint::MIN, fp!(1)),
+                FixedPoint::MIN
+            );
         },
     };
     Ok(())
 }
 
 #[test]
-fn sqrt_negative() -> Result<()> {
+#[cfg(feature = "num-traits")]
+#[should_panic(expected = "attempt to add with overflow")]
+fn num_traits_add_overflow() {
+    type FixedPoint = crate::FixedPoint<i64, typenum::U9>;
+    let _ = FixedPoint::MAX + FixedPoint::MAX;
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary() -> Result<()> {
+    use ::arbitrary::{Arbitrary, Unstructured};
+
     test_fixed_point! {
-        case (x | FixedPoint) => {
-            let expected = Err(ArithmeticError::DomainViolation);
-            assert_eq!(x.rsqrt(Floor), expected);
-            assert_eq!(x.rsqrt(Ceil), expected);
-        },
+        case () => {
+            // Cheap LCG to fill the input without pulling `rand`.
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            let data = (0..1 << 16)
+                .map(|_| {
+                    state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                    (state >> 56) as u8
+                })
+                .collect::<Vec<_>>();
+
+            let mut u = Unstructured::new(&data);
+            let mut values = Vec::new();
+            while !u.is_empty() {
+                values.push(FixedPoint::arbitrary(&mut u).unwrap());
+            }
+
+            let edge_cases = [
+                FixedPoint::MIN,
+                FixedPoint::MIN.cadd(FixedPoint::EPSILON)?,
+                FixedPoint::ZERO.csub(FixedPoint::EPSILON)?,
+                FixedPoint::ZERO,
+                FixedPoint::EPSILON,
+                FixedPoint::ONE,
+                FixedPoint::MAX.csub(FixedPoint::EPSILON)?,
+                FixedPoint::MAX,
+            ];
+            for edge_case in &edge_cases {
+                assert!(values.contains(edge_case), "{} isn't generated", edge_case);
+            }
+            let random = values.iter().filter(|value| !edge_cases.contains(value)).count();
+            assert!(random > values.len() / 2);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "proptest")]
+fn proptest_strategies() -> Result<()> {
+    use ::proptest::{
+        prelude::*,
+        test_runner::{TestError, TestRunner},
+    };
+
+    use crate::proptest::fixed_point_in;
+
+    test_fixed_point! {
+        case (low | FixedPoint, high | FixedPoint) => {
+            let strategy = fixed_point_in(low..=high);
+            TestRunner::default()
+                .run(&strategy, |x| {
+                    prop_assert!(low <= x && x <= high);
+                    Ok(())
+                })
+                .unwrap();
+        },
+        all {
+            (fp!(-1), fp!(1));
+            (fp!(0.5), fp!(0.6));
+            (fp!(-0.6), fp!(-0.5));
+            (fp!(1), fp!(1));
+            (FixedPoint::MIN, FixedPoint::MAX);
+            (FixedPoint::MAX, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        // Shrinks toward zero and whole numbers.
+        case (threshold | FixedPoint, whole | FixedPoint) => {
+            for _ in 0..10 {
+                let result = TestRunner::default().run(&any::<FixedPoint>(), |x| {
+                    prop_assert!(x < threshold);
+                    Ok(())
+                });
+                match result {
+                    Err(TestError::Fail(_, x)) => assert!(x == threshold || x == whole, "{}", x),
+                    _ => panic!("unexpected result: {:?}", result),
+                }
+            }
+        },
+        all {
+            (fp!(1), fp!(1));
+            (fp!(10.5), fp!(11));
+            (fp!(0.25), fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            TestRunner::default()
+                .run(&any::<(FixedPoint, FixedPoint)>(), |(a, b)| {
+                    prop_assert_eq!(a.cadd(b).ok(), b.cadd(a).ok());
+                    Ok(())
+                })
+                .unwrap();
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn rand_uniform() -> Result<()> {
+    use ::rand::{distributions::Uniform, rngs::SmallRng, Rng, SeedableRng};
+
+    test_fixed_point! {
+        case (low | FixedPoint, high | FixedPoint) => {
+            let mut rng = SmallRng::seed_from_u64(42);
+            for _ in 0..1000 {
+                let x = rng.gen_range(low..=high);
+                assert!(low <= x && x <= high);
+                let x = rng.gen_range(low..high);
+                assert!(low <= x && x < high);
+            }
+        },
+        all {
+            (fp!(-1), fp!(1));
+            (fp!(0.5), fp!(0.6));
+            (FixedPoint::MIN, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Every value of a tiny range is hit.
+            let mut rng = SmallRng::seed_from_u64(42);
+            let mut hits = [0; 4];
+            let uniform = Uniform::new_inclusive(FixedPoint::from_bits(0), FixedPoint::from_bits(3));
+            for x in (&mut rng).sample_iter(uniform).take(1000) {
+                hits[x.into_bits() as usize] += 1;
+            }
+            assert!(hits.iter().all(|&hit| hit > 200), "{:?}", hits);
+
+            assert_eq!(rng.gen_range(fp!(1)..=fp!(1)), fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "schemars")]
+fn schemars() -> Result<()> {
+    use ::schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    test_fixed_point! {
+        case (pattern | &str) => {
+            #[derive(JsonSchema)]
+            #[schemars(crate = "::schemars")]
+            #[allow(dead_code)]
+            struct Struct {
+                number: FixedPoint,
+            }
+
+            let schema = schema_for!(FixedPoint);
+            assert_eq!(schema.get("type"), Some(&json!("string")));
+            assert_eq!(schema.get("pattern"), Some(&json!(pattern)));
+
+            // Inlined to fields.
+            let schema = schema_for!(Struct);
+            let field = &schema.as_value()["properties"]["number"];
+            assert_eq!(field, &json!({ "type": "string", "pattern": pattern }));
+        },
+        fp64 {
+            (r"^-?[0-9]+(\.[0-9]{1,9})?$");
+        },
+        fp128 {
+            (r"^-?[0-9]+(\.[0-9]{1,18})?$");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sp-arithmetic")]
+fn sp_arithmetic() -> Result<()> {
+    use ::sp_arithmetic::{traits::Saturating, FixedPointNumber, Perbill, Percent};
+
+    test_fixed_point! {
+        case (x | FixedPoint, y | FixedPoint, product | FixedPoint) => {
+            assert_eq!(Saturating::saturating_mul(x, y), product);
+            assert_eq!(::num_traits::CheckedMul::checked_mul(&x, &y), Some(product));
+        },
+        all {
+            (fp!(2), fp!(3), fp!(6));
+            (fp!(-1.5), fp!(0.5), fp!(-0.75));
+            // Rounds toward zero.
+            (fp!(-0.1), fp!(0.1), fp!(-0.01));
+            (FixedPoint::from_bits(-1), fp!(0.5), fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exp | usize, expected | FixedPoint) => {
+            assert_eq!(Saturating::saturating_pow(x, exp), expected);
+        },
+        all {
+            (fp!(2), 3, fp!(8));
+            (fp!(-2), 3, fp!(-8));
+            (fp!(-2), 1000, FixedPoint::MAX);
+            (fp!(-2), 1001, FixedPoint::MIN);
+            (fp!(-2), usize::MAX, FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(<FixedPoint as FixedPointNumber>::DIV, FixedPoint::ONE.into_bits());
+            assert_eq!(FixedPoint::saturating_from_integer(3), fp!(3));
+            assert_eq!(FixedPoint::checked_from_rational(1, 4), Some(fp!(0.25)));
+            assert_eq!(FixedPoint::from(Percent::from_percent(50)), fp!(0.5));
+            assert_eq!(FixedPoint::from(Perbill::from_parts(1)), fp!(0.000000001));
+            assert_eq!(Saturating::saturating_add(FixedPoint::MAX, fp!(1)), FixedPoint::MAX);
+            assert_eq!(Saturating::saturating_sub(FixedPoint::MIN, fp!(1)), FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "borsh"))]
+fn borsh() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let bytes = ::borsh::to_vec(&x).unwrap();
+            assert_eq!(bytes, x.into_bits().to_le_bytes());
+            assert_eq!(::borsh::from_slice::<FixedPoint>(&bytes).unwrap(), x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-42.000001));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "bincode"))]
+fn bincode() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let config = ::bincode::config::standard();
+            let bytes = ::bincode::encode_to_vec(x, config).unwrap();
+            assert_eq!(bytes, ::bincode::encode_to_vec(x.into_bits(), config).unwrap());
+            assert_eq!(::bincode::decode_from_slice::<FixedPoint, _>(&bytes, config).unwrap(), (x, bytes.len()));
+
+            let config = config.with_fixed_int_encoding();
+            let bytes = ::bincode::encode_to_vec(x, config).unwrap();
+            assert_eq!(bytes, x.into_bits().to_le_bytes());
+            assert_eq!(::bincode::borrow_decode_from_slice::<FixedPoint, _>(&bytes, config).unwrap().0, x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-42.000001));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "rkyv"))]
+fn rkyv() -> Result<()> {
+    use ::rkyv::rancor::Error;
+
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let bytes = ::rkyv::to_bytes::<Error>(&x).unwrap();
+            assert_eq!(&bytes[..], &x.into_bits().to_le_bytes()[..]);
+
+            let archived = ::rkyv::access::<crate::ArchivedFixedPoint<Layout, _>, Error>(&bytes).unwrap();
+            assert_eq!(::rkyv::deserialize::<FixedPoint, Error>(archived).unwrap(), x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-42.000001));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_with() -> Result<()> {
+    test_fixed_point! {
+        case (input | f64, expected | FixedPoint) => {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::as_f64")]
+                number: FixedPoint,
+            }
+
+            let actual = serde_json::from_str::<Struct>(&format!(r#"{{"number":{}}}"#, input)).unwrap().number;
+            assert_eq!(expected, actual);
+        },
+        all {
+            (1., fp!(1.0));
+            (1.1, fp!(1.1));
+            (1.02, fp!(1.02));
+            (-1.02, fp!(-1.02));
+            (0.1234, fp!(0.1234));
+            (-0.1234, fp!(-0.1234));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_float() -> Result<()> {
+    test_fixed_point! {
+        case (input | &str, mode | RoundMode, expected | FixedPoint) => {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::float")]
+                number: FixedPoint,
+            }
+
+            let actual = serde_json::from_str::<Struct>(&format!(r#"{{"number":{}}}"#, input))
+                .map(|s| s.number);
+            if mode == RoundMode::Nearest {
+                assert_eq!(actual.unwrap(), expected);
+            }
+
+            let mut deserializer = serde_json::Deserializer::from_str(input);
+            let actual = crate::serde::float::deserialize_rounded(&mut deserializer, mode).unwrap();
+            assert_eq!(actual, expected);
+
+            assert!(serde_json::from_str::<Struct>(&format!(r#"{{"number":"{}"}}"#, input)).is_err());
+            assert!(serde_json::from_str::<Struct>(r#"{"number":1e30}"#).is_err());
+        },
+        all {
+            ("0.3", Floor, fp!(0.3));
+            ("0.3", Ceil, fp!(0.3));
+            ("-0.3", Nearest, fp!(-0.3));
+            ("1", Nearest, fp!(1));
+            ("1e-300", Floor, fp!(0));
+            ("-1e-300", TowardZero, fp!(0));
+        },
+        fp64 {
+            ("1e-300", Ceil, fp!(0.000000001));
+            ("-1e-300", AwayFromZero, fp!(-0.000000001));
+            ("0.1234567895", Nearest, fp!(0.12345679));
+            ("0.1234567895", Floor, fp!(0.123456789));
+            ("-0.1234567895", Nearest, fp!(-0.12345679));
+            ("-0.1234567895", Ceil, fp!(-0.123456789));
+        },
+        fp128 {
+            ("1e-300", Ceil, fp!(0.000000000000000001));
+            ("-1e-300", AwayFromZero, fp!(-0.000000000000000001));
+            ("1.2345678912345675e-3", Nearest, fp!(0.001234567891234568));
+            ("1.2345678912345675e-3", TowardZero, fp!(0.001234567891234567));
+        },
+    };
+
+    test_fixed_point! {
+        case (x | FixedPoint, expected | &str) => {
+            #[derive(::serde::Serialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::float")]
+                number: FixedPoint,
+            }
+
+            let json = serde_json::to_string(&Struct { number: x }).unwrap();
+            assert_eq!(json, format!(r#"{{"number":{}}}"#, expected));
+        },
+        all {
+            (fp!(0.3), "0.3");
+            (fp!(-1.5), "-1.5");
+            (fp!(0), "0.0");
+            (fp!(0.123456789), "0.123456789");
+        },
+        fp64 {
+            (FixedPoint::MAX, "9223372036.854776");
+        },
+        fp128 {
+            (fp!(0.123456789123456789), "0.12345678912345678");
+            (FixedPoint::MAX, "1.7014118346046924e+20");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_fixed_digits() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | &str) => {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::fixed_digits")]
+                number: FixedPoint,
+            }
+
+            assert_eq!(format!("{:#}", x), expected);
+
+            let json = serde_json::to_string(&Struct { number: x }).unwrap();
+            assert_eq!(json, format!(r#"{{"number":"{}"}}"#, expected));
+            assert_eq!(serde_json::from_str::<Struct>(&json).unwrap().number, x);
+        },
+        fp64 {
+            (fp!(1.5), "1.500000000");
+            (fp!(-1.5), "-1.500000000");
+            (fp!(0), "0.000000000");
+            (fp!(0.000000001), "0.000000001");
+            (FixedPoint::MIN, "-9223372036.854775808");
+        },
+        fp128 {
+            (fp!(1.5), "1.500000000000000000");
+            (fp!(-0.000000000000000001), "-0.000000000000000001");
+            (FixedPoint::MIN, "-170141183460469231731.687303715884105728");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_repr() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | Layout) => {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::repr")]
+                number: FixedPoint,
+            }
+
+            let json = serde_json::to_string(&Struct { number: x }).unwrap();
+            assert_eq!(json, format!(r#"{{"number":{}}}"#, expected));
+            assert_eq!(serde_json::from_str::<Struct>(&json).unwrap().number, x);
+
+            let bytes = bincode1::serialize(&x).unwrap();
+            assert_eq!(bytes, bincode1::serialize(&expected).unwrap());
+            assert_eq!(bincode1::deserialize::<FixedPoint>(&bytes).unwrap(), x);
+        },
+        all {
+            (fp!(0), 0);
+            (FixedPoint::MAX, Layout::MAX);
+            (FixedPoint::MIN, Layout::MIN);
+        },
+        fp64 {
+            (fp!(1.5), 1_500_000_000);
+            (fp!(-0.000000001), -1);
+        },
+        fp128 {
+            (fp!(1.5), 1_500_000_000_000_000_000);
+            (fp!(-0.000000000000000001), -1);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde", feature = "i64"))]
+fn serde_repr_invalid_precision() {
+    use typenum::{U18, U19};
+
+    let deserialize = crate::serde::repr::deserialize::<i64, U18, _>;
+    assert!(deserialize(&mut serde_json::Deserializer::from_str("1")).is_ok());
+    let deserialize = crate::serde::repr::deserialize::<i64, U19, _>;
+    assert!(deserialize(&mut serde_json::Deserializer::from_str("1")).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_str() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | &str) => {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            struct Struct {
+                #[serde(with = "crate::serde::str")]
+                number: FixedPoint,
+            }
+
+            let json = serde_json::to_string(&Struct { number: x }).unwrap();
+            assert_eq!(json, format!(r#"{{"number":"{}"}}"#, expected));
+            assert_eq!(serde_json::from_str::<Struct>(&json).unwrap().number, x);
+
+            let bytes = bincode1::serialize(&Struct { number: x }).unwrap();
+            assert_eq!(bincode1::deserialize::<Struct>(&bytes).unwrap().number, x);
+
+            for json in &[r#"{"number":1.5}"#, r#"{"number":1}"#, r#"{"number":"1.5x"}"#] {
+                assert!(serde_json::from_str::<Struct>(json).is_err(), "must not parse {}", json);
+            }
+        },
+        all {
+            (fp!(1.5), "1.5");
+            (fp!(-1.5), "-1.5");
+            (fp!(0), "0.0");
+            (fp!(42), "42.0");
+        },
+        fp64 {
+            (FixedPoint::MIN, "-9223372036.854775808");
+        },
+        fp128 {
+            (FixedPoint::MIN, "-170141183460469231731.687303715884105728");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_from_number() -> Result<()> {
+    test_fixed_point! {
+        case (input | &str, expected | FixedPoint) => {
+            #[derive(::serde::Deserialize)]
+            struct Struct {
+                price: FixedPoint,
+            }
+
+            let actual = serde_json::from_str::<Struct>(&format!(r#"{{"price":{}}}"#, input)).unwrap().price;
+            assert_eq!(actual, expected);
+            assert_eq!(serde_json::from_str::<FixedPoint>(input).unwrap(), expected);
+        },
+        all {
+            ("0.123456789", fp!(0.123456789));
+            ("-0.123456789", fp!(-0.123456789));
+            ("42", fp!(42));
+            ("-42", fp!(-42));
+            ("1.5", fp!(1.5));
+            ("9223372036", fp!(9223372036));
+        },
+        fp128 {
+            ("9223372036854775807", fp!(9223372036854775807));
+            ("18446744073709551615", fp!(18446744073709551615));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_from_arbitrary_precision_number() -> Result<()> {
+    use ::serde::de::value::{Error, MapDeserializer};
+
+    test_fixed_point! {
+        case (key | &str, input | &str, expected | Option<FixedPoint>) => {
+            // That's how `serde_json` with `arbitrary_precision` passes number literals.
+            let entries = [(key, input)];
+            let deserializer = MapDeserializer::<_, Error>::new(entries.iter().copied());
+            let actual = <FixedPoint as ::serde::Deserialize>::deserialize(deserializer);
+            assert_eq!(actual.ok(), expected);
+        },
+        all {
+            ("$serde_json::private::Number", "1.5", Some(fp!(1.5)));
+            ("$serde_json::private::Number", "-42", Some(fp!(-42)));
+            ("$serde_json::private::Number", "0.1234567890123456789", None);
+            ("number", "1.5", None);
+        },
+        fp128 {
+            ("$serde_json::private::Number", "0.123456789012345678", Some(fp!(0.123456789012345678)));
+            ("$serde_json::private::Number", "-0.123456789012345678", Some(fp!(-0.123456789012345678)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "serde"))]
+fn serde_from_bad_number() -> Result<()> {
+    test_fixed_point! {
+        case (input | &str) => {
+            assert!(serde_json::from_str::<FixedPoint>(input).is_err(), "must not parse {}", input);
+        },
+        all {
+            ("1e30");
+            ("true");
+        },
+        fp64 {
+            ("0.1234567890123456789");
+            ("9223372036854775807");
+            ("-18446744073709551615");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn exp_and_coef_should_agree() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert!(FixedPoint::PRECISION > 0);
+            const TEN: Layout = 10;
+            assert_eq!(FixedPoint::COEF, TEN.pow(FixedPoint::PRECISION as u32));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cmul_overflow() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let result = FixedPoint::MAX.cmul(Layout::MAX);
+            assert_eq!(result, Err(ArithmeticError::Overflow));
+
+            let result = FixedPoint::MAX.cmul(Layout::MIN);
+            assert_eq!(result, Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rmul_exact() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            // Check the result
+            assert_eq!(a.rmul(b, Floor)?, expected);
+            // Check the commutative property
+            assert_eq!(b.rmul(a, Floor)?, expected);
+            // Check that round mode doesn't matter
+            assert_eq!(a.rmul(b, Ceil)?, expected);
+            assert_eq!(b.rmul(a, Ceil)?, expected);
+        },
+        all {
+            (fp!(525), fp!(10), fp!(5250));
+            (fp!(-525), fp!(10), fp!(-5250));
+            (fp!(-525), fp!(-10), fp!(5250));
+            (fp!(525), fp!(-10), fp!(-5250));
+            (fp!(525), fp!(0.0001), fp!(0.0525));
+            (fp!(-525), fp!(0.0001), fp!(-0.0525));
+            (fp!(-525), fp!(-0.0001), fp!(0.0525));
+            (FixedPoint::MAX, FixedPoint::ONE, FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::ONE, FixedPoint::MIN);
+            (FixedPoint::ONE, fp!(0.000000001), fp!(0.000000001));
+            (fp!(-1), fp!(-0.000000001), fp!(0.000000001));
+            (
+                FixedPoint::from_bits(Layout::MAX / 10 * 10),
+                fp!(0.1),
+                FixedPoint::from_bits(Layout::MAX / 10),
+            );
+            (
+                FixedPoint::from_bits(Layout::MIN / 10 * 10),
+                fp!(0.1),
+                FixedPoint::from_bits(Layout::MIN / 10),
+            );
+        },
+        fp128 {
+            (fp!(13043817825.332782), fp!(13043817825.332782), fp!(170141183460469226191.989043859524));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rmul_round() -> Result<()> {
+    test_fixed_point! {
+        case (
+            a | FixedPoint,
+            b | FixedPoint,
+            expected_floor | FixedPoint,
+            expected_ceil | FixedPoint,
+        ) => {
+            // Check the result
+            assert_eq!(a.rmul(b, Floor)?, expected_floor);
+            assert_eq!(a.rmul(b, Ceil)?, expected_ceil);
+            // Check the commutative property
+            assert_eq!(b.rmul(a, Floor)?, expected_floor);
+            assert_eq!(b.rmul(a, Ceil)?, expected_ceil);
+            // Arguments' negation doesn't change the result
+            assert_eq!(b.cneg()?.rmul(a.cneg()?, Floor)?, expected_floor);
+            assert_eq!(b.cneg()?.rmul(a.cneg()?, Ceil)?, expected_ceil);
+        },
+        fp64 {
+            (fp!(0.1), fp!(0.000000001), fp!(0), fp!(0.000000001));
+            (fp!(-0.1), fp!(0.000000001), fp!(-0.000000001), fp!(0));
+            (fp!(0.000000001), fp!(0.000000001), fp!(0), fp!(0.000000001));
+            (fp!(-0.000000001), fp!(0.000000001), fp!(-0.000000001), fp!(0));
+        },
+        fp128 {
+            (fp!(0.1), fp!(0.000000000000000001), FixedPoint::ZERO, fp!(0.000000000000000001));
+            (fp!(-0.1), fp!(0.000000000000000001), fp!(-0.000000000000000001), FixedPoint::ZERO);
+            (fp!(0.000000000000000001), fp!(0.000000000000000001), FixedPoint::ZERO, fp!(0.000000000000000001));
+            (fp!(-0.000000000000000001), fp!(0.000000000000000001), fp!(-0.000000000000000001), FixedPoint::ZERO);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rmul_nearest() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.rmul(b, Nearest)?, expected);
+            assert_eq!(b.rmul(a, Nearest)?, expected);
+            assert_eq!(b.cneg()?.rmul(a.cneg()?, Nearest)?, expected);
+            // Halfway cases are rounded away from zero for both signs.
+            assert_eq!(a.cneg()?.rmul(b, Nearest)?, expected.cneg()?);
+        },
+        all {
+            (fp!(1.5), fp!(2), fp!(3));
+        },
+        fp64 {
+            (fp!(0.4), fp!(0.000000001), fp!(0));
+            (fp!(0.5), fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.6), fp!(0.000000001), fp!(0.000000001));
+            (fp!(1.5), fp!(0.000000003), fp!(0.000000005));
+            (fp!(0.000000001), fp!(0.000000001), fp!(0));
+        },
+        fp128 {
+            (fp!(0.4), fp!(0.000000000000000001), fp!(0));
+            (fp!(0.5), fp!(0.000000000000000001), fp!(0.000000000000000001));
+            (fp!(0.6), fp!(0.000000000000000001), fp!(0.000000000000000001));
+            (fp!(1.5), fp!(0.000000000000000003), fp!(0.000000000000000005));
+            (fp!(0.000000000000000001), fp!(0.000000000000000001), fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rmul_overflow() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint) => {
+            assert_eq!(a.rmul(b, Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX, fp!(1.000000001));
+        },
+        fp64 {
+            (fp!(96038.388349945), fp!(96038.388349945));
+            (fp!(-97000), fp!(96100))
+        },
+        fp128 {
+            (FixedPoint::MAX, fp!(1.000000000000000001));
+            (fp!(13043817825.332783), fp!(13043817825.332783));
+            (fp!(-13043817826), fp!(13043817826))
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_exact() -> Result<()> {
+    test_fixed_point! {
+        case (numerator | FixedPoint, denominator | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(numerator.rdiv(denominator, Ceil)?, expected);
+            assert_eq!(numerator.rdiv(denominator, Floor)?, expected);
+        },
+        all {
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::ONE);
+            (fp!(5), fp!(2), fp!(2.5));
+            (fp!(-5), fp!(2), fp!(-2.5));
+            (fp!(5), fp!(-2), fp!(-2.5));
+            (fp!(-5), fp!(-2), fp!(2.5));
+            (fp!(5), fp!(0.2), fp!(25));
+            (fp!(0.00000001), fp!(10), fp!(0.000000001));
+            (fp!(0.000000001), fp!(0.1), fp!(0.00000001));
+        },
+        fp128 {
+            (fp!(0.00000000000000001), fp!(10), fp!(0.000000000000000001));
+            (fp!(0.000000000000000001), fp!(0.1), fp!(0.00000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_by_layout() -> Result<()> {
+    test_fixed_point! {
+        case (
+            a | FixedPoint,
+            b | Layout,
+            expected_floor | FixedPoint,
+            expected_ceil | FixedPoint,
+        ) => {
+            assert_eq!(a.rdiv(b, Floor)?, expected_floor);
+            assert_eq!(a.rdiv(b, Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(2.4), 2, fp!(1.2), fp!(1.2));
+            (fp!(0), 5, FixedPoint::ZERO, FixedPoint::ZERO);
+        },
+        fp64 {
+            (fp!(7), 3, fp!(2.333333333), fp!(2.333333334));
+            (fp!(-7), 3, fp!(-2.333333334), fp!(-2.333333333));
+            (fp!(-7), -3, fp!(2.333333333), fp!(2.333333334));
+            (fp!(7), -3, fp!(-2.333333334), fp!(-2.333333333));
+            (fp!(0.000000003), 2, fp!(0.000000001), fp!(0.000000002));
+            (fp!(0.000000003), 7, fp!(0), fp!(0.000000001));
+            (fp!(0.000000001), 7, fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(7), 3, fp!(2.333333333333333333), fp!(2.333333333333333334));
+            (fp!(-7), 3, fp!(-2.333333333333333334), fp!(-2.333333333333333333));
+            (fp!(-7), -3, fp!(2.333333333333333333), fp!(2.333333333333333334));
+            (fp!(7), -3, fp!(-2.333333333333333334), fp!(-2.333333333333333333));
+            (fp!(0.000000000000000003), 2, fp!(0.000000000000000001), fp!(0.000000000000000002));
+            (fp!(0.000000000000000003), 7, fp!(0), fp!(0.000000000000000001));
+            (fp!(0.000000000000000001), 7, fp!(0), fp!(0.000000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_round() -> Result<()> {
+    test_fixed_point! {
+        case (
+            numerator | FixedPoint,
+            denominator | FixedPoint,
+            expected_ceil | FixedPoint,
+            expected_floor | FixedPoint,
+        ) => {
+            assert_eq!(numerator.rdiv(denominator, Ceil)?, expected_ceil);
+            assert_eq!(numerator.rdiv(denominator, Floor)?, expected_floor);
+        },
+        fp64 {
+            (fp!(100), fp!(3), fp!(33.333333334), fp!(33.333333333));
+            (fp!(-100), fp!(-3), fp!(33.333333334), fp!(33.333333333));
+            (fp!(-100), fp!(3), fp!(-33.333333333), fp!(-33.333333334));
+            (fp!(100), fp!(-3), fp!(-33.333333333), fp!(-33.333333334));
+        },
+        fp128 {
+            (fp!(100), fp!(3), fp!(33.333333333333333334), fp!(33.333333333333333333));
+            (fp!(-100), fp!(-3), fp!(33.333333333333333334), fp!(33.333333333333333333));
+            (fp!(-100), fp!(3), fp!(-33.333333333333333333), fp!(-33.333333333333333334));
+            (fp!(100), fp!(-3), fp!(-33.333333333333333333), fp!(-33.333333333333333334));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_nearest() -> Result<()> {
+    test_fixed_point! {
+        case (numerator | FixedPoint, denominator | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(numerator.rdiv(denominator, Nearest)?, expected);
+            assert_eq!(numerator.cneg()?.rdiv(denominator, Nearest)?, expected.cneg()?);
+            assert_eq!(numerator.rdiv(denominator.cneg()?, Nearest)?, expected.cneg()?);
+        },
+        fp64 {
+            (fp!(1), fp!(3), fp!(0.333333333));
+            (fp!(2), fp!(3), fp!(0.666666667));
+            (fp!(0.000000001), fp!(2), fp!(0.000000001));
+            (fp!(0.000000001), fp!(4), fp!(0));
+            (fp!(0.000000003), fp!(4), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(1), fp!(3), fp!(0.333333333333333333));
+            (fp!(2), fp!(3), fp!(0.666666666666666667));
+            (fp!(0.000000000000000001), fp!(2), fp!(0.000000000000000001));
+            (fp!(0.000000000000000001), fp!(4), fp!(0));
+            (fp!(0.000000000000000003), fp!(4), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case (numerator | Layout, denominator | Layout, expected | Layout) => {
+            assert_eq!(numerator.rdiv(denominator, Nearest)?, expected);
+            let a = FixedPoint::from_bits(numerator);
+            assert_eq!(a.rdiv(denominator, Nearest)?, FixedPoint::from_bits(expected));
+        },
+        all {
+            (7, 2, 4);
+            (-7, 2, -4);
+            (5, 3, 2);
+            (4, 3, 1);
+            (-4, -3, 1);
+            (Layout::MAX, Layout::MIN, -1);
+            (Layout::MIN, Layout::MAX, -1);
+            (Layout::MIN / 2 - 1, Layout::MIN, 1);
+            (Layout::MIN / 2 + 1, Layout::MIN, 0);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rrem() -> Result<()> {
+    test_fixed_point! {
+        case (
+            a | FixedPoint,
+            b | FixedPoint,
+            expected_floor | FixedPoint,
+            expected_ceil | FixedPoint,
+            expected_nearest | FixedPoint,
+        ) => {
+            assert_eq!(a.rrem(b, Floor)?, expected_floor);
+            assert_eq!(a.rrem(b, Ceil)?, expected_ceil);
+            assert_eq!(a.rrem(b, Nearest)?, expected_nearest);
+            // The remainder agrees with the quotient rounded by `rdiv`.
+            for mode in &[Floor, Ceil, Nearest, TowardZero, AwayFromZero] {
+                let quotient = a.rdiv(b, *mode)?.integral(*mode);
+                assert_eq!(a.rrem(b, *mode)?, a.csub(b.cmul(quotient)?)?);
+            }
+        },
+        all {
+            (fp!(7.5), fp!(2), fp!(1.5), fp!(-0.5), fp!(-0.5));
+            (fp!(-7.5), fp!(2), fp!(0.5), fp!(-1.5), fp!(0.5));
+            (fp!(7.5), fp!(-2), fp!(-0.5), fp!(1.5), fp!(-0.5));
+            (fp!(-7.5), fp!(-2), fp!(-1.5), fp!(0.5), fp!(0.5));
+            (fp!(6), fp!(2), fp!(0), fp!(0), fp!(0));
+            (fp!(5), fp!(0.3), fp!(0.2), fp!(-0.1), fp!(-0.1));
+            (fp!(0.1), fp!(3), fp!(0.1), fp!(-2.9), fp!(0.1));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | Layout, expected_toward_zero | FixedPoint, expected_away | FixedPoint) => {
+            assert_eq!(a.rrem(b, TowardZero)?, expected_toward_zero);
+            assert_eq!(a.rrem(b, AwayFromZero)?, expected_away);
+        },
+        all {
+            (fp!(7.5), 2, fp!(1.5), fp!(-0.5));
+            (fp!(-7.5), 2, fp!(-1.5), fp!(0.5));
+            (fp!(7.5), -2, fp!(1.5), fp!(-0.5));
+        },
+        fp64 {
+            (FixedPoint::MAX, 1, fp!(0.854775807), fp!(-0.145224193));
+        },
+        fp128 {
+            (FixedPoint::MAX, 1, fp!(0.687303715884105727), fp!(-0.312696284115894273));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint) => {
+            assert_eq!(a.rrem(FixedPoint::ZERO, Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(a.rrem(0, Floor), Err(ArithmeticError::DivisionByZero));
+            // `MIN / -EPSILON` overflows, but the remainder doesn't.
+            assert_eq!(FixedPoint::MIN.rrem(FixedPoint::EPSILON.cneg()?, Floor)?, FixedPoint::ZERO);
+            // The divisor doesn't fit the layout, so the remainder can overflow.
+            assert_eq!(a.rrem(Layout::MAX, TowardZero)?, a);
+            assert_eq!(a.rrem(Layout::MAX, AwayFromZero), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_rem() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint) => {
+            for mode in &[Floor, Ceil, Nearest, TowardZero, AwayFromZero] {
+                let (quotient, remainder) = a.rdiv_rem(b, *mode)?;
+                assert_eq!(quotient, a.rdiv(b, *mode)?);
+                // The product is exact for integer divisors.
+                let divisor = b.integral(Floor);
+                assert_eq!(quotient.cmul(divisor)?.cadd(remainder)?, a);
+            }
+        },
+        all {
+            (fp!(10), fp!(3));
+            (fp!(-10), fp!(3));
+            (fp!(10), fp!(-7));
+            (fp!(-0.000000001), fp!(-2));
+            (fp!(6), fp!(2));
+            (fp!(0), fp!(5));
+            (FixedPoint::MAX, fp!(1));
+            (FixedPoint::MIN, fp!(2));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected_floor | (FixedPoint, FixedPoint), expected_ceil | (FixedPoint, FixedPoint)) => {
+            assert_eq!(a.rdiv_rem(b, Floor)?, expected_floor);
+            assert_eq!(a.rdiv_rem(b, Ceil)?, expected_ceil);
+        },
+        fp64 {
+            (fp!(10), fp!(3), (fp!(3.333333333), fp!(0.000000001)), (fp!(3.333333334), fp!(-0.000000002)));
+            (fp!(-10), fp!(3), (fp!(-3.333333334), fp!(0.000000002)), (fp!(-3.333333333), fp!(-0.000000001)));
+            // The remainder of a fractional divisor is truncated.
+            (fp!(1), fp!(0.3), (fp!(3.333333333), fp!(0)), (fp!(3.333333334), fp!(0)));
+        },
+        fp128 {
+            (fp!(10), fp!(3), (fp!(3.333333333333333333), fp!(0.000000000000000001)), (fp!(3.333333333333333334), fp!(-0.000000000000000002)));
+            (fp!(-10), fp!(3), (fp!(-3.333333333333333334), fp!(0.000000000000000002)), (fp!(-3.333333333333333333), fp!(-0.000000000000000001)));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint) => {
+            assert_eq!(a.rdiv_rem(FixedPoint::ZERO, Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(FixedPoint::MAX.rdiv_rem(FixedPoint::EPSILON, Floor), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mul_div() -> Result<()> {
+    test_fixed_point! {
+        case (
+            a | FixedPoint,
+            b | FixedPoint,
+            c | FixedPoint,
+            expected_floor | FixedPoint,
+            expected_ceil | FixedPoint,
+        ) => {
+            assert_eq!(a.mul_div(b, c, Floor)?, expected_floor);
+            assert_eq!(a.mul_div(b, c, Ceil)?, expected_ceil);
+            assert_eq!(b.mul_div(a, c, Floor)?, expected_floor);
+            assert_eq!(a.cneg()?.mul_div(b.cneg()?, c, Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(6), fp!(4), fp!(3), fp!(8), fp!(8));
+            (fp!(-6), fp!(4), fp!(3), fp!(-8), fp!(-8));
+            (fp!(0), fp!(4), fp!(3), fp!(0), fp!(0));
+            // `rmul` would round `EPSILON * 0.5` to zero first.
+            (FixedPoint::EPSILON, fp!(0.5), fp!(0.25), FixedPoint::EPSILON.cmul(2)?, FixedPoint::EPSILON.cmul(2)?);
+            // The intermediate product overflows the layout.
+            (FixedPoint::MAX, fp!(2), fp!(2), FixedPoint::MAX, FixedPoint::MAX);
+            (FixedPoint::MAX.cneg()?, fp!(3), fp!(3), FixedPoint::MAX.cneg()?, FixedPoint::MAX.cneg()?);
+        },
+        fp64 {
+            (fp!(1), fp!(1), fp!(3), fp!(0.333333333), fp!(0.333333334));
+            (fp!(-1), fp!(1), fp!(3), fp!(-0.333333334), fp!(-0.333333333));
+            (fp!(1), fp!(1), fp!(-3), fp!(-0.333333334), fp!(-0.333333333));
+        },
+        fp128 {
+            (fp!(1), fp!(1), fp!(3), fp!(0.333333333333333333), fp!(0.333333333333333334));
+            (fp!(-1), fp!(1), fp!(3), fp!(-0.333333333333333334), fp!(-0.333333333333333333));
+            (fp!(1), fp!(1), fp!(-3), fp!(-0.333333333333333334), fp!(-0.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, c | FixedPoint, expected | ArithmeticError) => {
+            assert_eq!(a.mul_div(b, c, Floor), Err(expected.clone()));
+            assert_eq!(a.mul_div(b, c, Ceil), Err(expected));
+        },
+        all {
+            (fp!(1), fp!(1), fp!(0), ArithmeticError::DivisionByZero);
+            (FixedPoint::MAX, fp!(2), fp!(1), ArithmeticError::Overflow);
+            (FixedPoint::MIN, fp!(1), fp!(1).cneg()?, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cpowi() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, exp | u32, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.cpowi(exp, Floor)?, expected_floor);
+            assert_eq!(x.cpowi(exp, Ceil)?, expected_ceil);
+            if exp % 2 == 1 {
+                assert_eq!(x.cneg()?.cpowi(exp, Floor)?, expected_ceil.cneg()?);
+                assert_eq!(x.cneg()?.cpowi(exp, Ceil)?, expected_floor.cneg()?);
+            } else {
+                assert_eq!(x.cneg()?.cpowi(exp, Floor)?, expected_floor);
+                assert_eq!(x.cneg()?.cpowi(exp, Ceil)?, expected_ceil);
+            }
+        },
+        all {
+            (fp!(0), 0, fp!(1), fp!(1));
+            (fp!(0), 5, fp!(0), fp!(0));
+            (fp!(7), 0, fp!(1), fp!(1));
+            (fp!(7), 1, fp!(7), fp!(7));
+            (fp!(2), 10, fp!(1024), fp!(1024));
+            (fp!(1.5), 3, fp!(3.375), fp!(3.375));
+            (fp!(0.1), 3, fp!(0.001), fp!(0.001));
+            (fp!(1), 1000000, fp!(1), fp!(1));
+        },
+        fp64 {
+            (fp!(1.000000001), 2, fp!(1.000000002), fp!(1.000000003));
+            (fp!(0.5), 30, fp!(0.000000000), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(1.000000000000000001), 2, fp!(1.000000000000000002), fp!(1.000000000000000003));
+            (fp!(0.5), 30, fp!(0.000000000931322574), fp!(0.000000000931322575));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exp | u32) => {
+            assert_eq!(x.cpowi(exp, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(x.cpowi(exp, Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX, 2);
+            (FixedPoint::MIN, 2);
+            (FixedPoint::MIN, 3);
+            (fp!(2), 200);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.cpowi(1, Floor)?, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_layout() -> Result<()> {
+    test_fixed_point! {
+        case (
+            a | Layout,
+            b | Layout,
+            expected_floor | Layout,
+            expected_ceil | Layout,
+        ) => {
+            assert_eq!(a.rdiv(b, Floor)?, expected_floor);
+            assert_eq!(a.rdiv(b, Ceil)?, expected_ceil);
+            assert_eq!(a.rdiv(-b, Floor)?, -expected_ceil);
+            assert_eq!((-a).rdiv(b, Floor)?, -expected_ceil);
+            assert_eq!(a.rdiv(-b, Ceil)?, -expected_floor);
+            assert_eq!((-a).rdiv(b, Ceil)?, -expected_floor);
+            assert_eq!((-a).rdiv(-b, Floor)?, expected_floor);
+            assert_eq!((-a).rdiv(-b, Ceil)?, expected_ceil);
+        },
+        all {
+            (5, 2, 2, 3);
+            (0, 5, 0, 0);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_division_by_zero() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let expected = Err(ArithmeticError::DivisionByZero);
+            assert_eq!(x.rdiv(FixedPoint::ZERO, Floor), expected);
+            assert_eq!(x.rdiv(FixedPoint::ZERO, Ceil), expected);
+        },
+        all {
+            (fp!(0));
+            (fp!(1));
+            (fp!(-1));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_overflow() -> Result<()> {
+    test_fixed_point! {
+        case (denominator | FixedPoint) => {
+            assert_eq!(
+                FixedPoint::MAX.rdiv(denominator, Ceil),
+                Err(ArithmeticError::Overflow)
+            );
+        },
+        all {
+            (fp!(0.999999999));
+        },
+        fp128 {
+            (fp!(0.999999999999999999));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn float_mul() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.rmul(b, Ceil)?, expected);
+        },
+        all {
+            (fp!(525), fp!(10), fp!(5250));
+            (fp!(525), fp!(0.0001), fp!(0.0525));
+            (FixedPoint::MAX, FixedPoint::ONE, FixedPoint::MAX);
+            (
+                FixedPoint::from_bits(Layout::MAX / 10 * 10),
+                fp!(0.1),
+                FixedPoint::from_bits(Layout::MAX / 10),
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn float_mul_overflow() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint) => {
+            assert!(a.rmul(b, Ceil).is_err());
+        },
+        fp64 {
+            (fp!(140000), fp!(140000));
+            (fp!(-140000), fp!(140000));
+        },
+        fp128 {
+            (fp!(13043817826), fp!(13043817825));
+            (fp!(-13043817826), fp!(13043817825));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i32", feature = "i64"))]
+fn rdiv_by_narrower_layout() -> Result<()> {
+    type Amount = crate::FixedPoint<i64, typenum::U6>;
+    type Ratio = crate::FixedPoint<i32, typenum::U6>;
+
+    let amount: Amount = "1000000.5".parse()?;
+    let ratio: Ratio = "0.25".parse()?;
+    assert_eq!(amount.rdiv(ratio, Floor)?, "4000002".parse()?);
+
+    let amount: Amount = "1".parse()?;
+    let ratio: Ratio = "-3".parse()?;
+    assert_eq!(amount.rdiv(ratio, Floor)?, "-0.333334".parse()?);
+    assert_eq!(amount.rdiv(ratio, Ceil)?, "-0.333333".parse()?);
+
+    let ratio: Ratio = "0.000001".parse()?;
+    assert_eq!(
+        Amount::MAX.rdiv(ratio, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        amount.rdiv(Ratio::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+fn widening_conversions() -> Result<()> {
+    type Short = crate::FixedPoint<i16, typenum::U2>;
+    type Mid = crate::FixedPoint<i32, typenum::U2>;
+    type Long = crate::FixedPoint<i64, typenum::U2>;
+    type Wide = crate::FixedPoint<i128, typenum::U2>;
+
+    let short: Short = "-123.45".parse()?;
+    assert_eq!(Mid::from(short), "-123.45".parse()?);
+    assert_eq!(Long::from(short), "-123.45".parse()?);
+    assert_eq!(Wide::from(short), "-123.45".parse()?);
+    assert_eq!(Long::from(Mid::MAX).into_bits(), i64::from(i32::MAX));
+    assert_eq!(Wide::from(Long::MIN).into_bits(), i128::from(i64::MIN));
+    let wide: Wide = Mid::MIN.into();
+    assert_eq!(wide.into_bits(), i128::from(i32::MIN));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i16", feature = "i32", feature = "i64", feature = "i128"))]
+fn narrowing_conversions() -> Result<()> {
+    type Short = crate::FixedPoint<i16, typenum::U2>;
+    type Mid = crate::FixedPoint<i32, typenum::U2>;
+    type Long = crate::FixedPoint<i64, typenum::U2>;
+    type Wide = crate::FixedPoint<i128, typenum::U2>;
+
+    let value: Wide = "-123.45".parse()?;
+    assert_eq!(Short::try_from(value)?, "-123.45".parse()?);
+    assert_eq!(Mid::try_from(value)?, "-123.45".parse()?);
+    assert_eq!(Long::try_from(value)?, "-123.45".parse()?);
+    assert_eq!(Short::try_from(Mid::from(Short::MIN))?, Short::MIN);
+    assert!(Short::try_from(Mid::from_bits(i32::from(i16::MAX) + 1)).is_err());
+    assert!(Mid::try_from(Long::MIN).is_err());
+    assert!(Long::try_from(Wide::MAX).is_err());
+
+    assert_eq!(value.saturating_narrow::<i16>(), "-123.45".parse()?);
+    assert_eq!(Long::MAX.saturating_narrow::<i16>(), Short::MAX);
+    assert_eq!(Long::MIN.saturating_narrow::<i32>(), Mid::MIN);
+    assert_eq!(Wide::MIN.saturating_narrow::<i64>(), Long::MIN);
+    assert_eq!(Long::MAX.saturating_narrow::<i64>(), Long::MAX);
+    Ok(())
+}
+
+#[test]
+fn try_sum_detailed() -> Result<()> {
+    test_fixed_point! {
+        case (values | [FixedPoint; 4], expected | core::result::Result<FixedPoint, (FixedPoint, usize)>) => {
+            assert_eq!(FixedPoint::try_sum_detailed(values), expected);
+        },
+        all {
+            ([fp!(1), fp!(-2.5), fp!(0.000000001), fp!(3)], Ok(fp!(1.500000001)));
+            ([FixedPoint::MAX, FixedPoint::MIN, fp!(1), fp!(0)], Ok(fp!(1).csub(FixedPoint::EPSILON)?));
+            ([fp!(1), fp!(2), FixedPoint::MAX, fp!(3)], Err((fp!(3), 2)));
+            ([fp!(-1), FixedPoint::MIN, fp!(-1), fp!(3)], Err((fp!(-1), 1)));
+            ([FixedPoint::MIN, fp!(-0.000000001), fp!(1), fp!(3)], Err((FixedPoint::MIN, 1)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rescale_is_lossless() -> Result<()> {
+    use typenum::{U0, U2, U40};
+
+    test_fixed_point! {
+        case (x | FixedPoint, to_u2 | bool, to_u0 | bool, to_u40 | bool) => {
+            assert_eq!(x.rescale_is_lossless::<U2>(), to_u2);
+            assert_eq!(x.rescale_is_lossless::<U0>(), to_u0);
+            assert_eq!(x.rescale_is_lossless::<U40>(), to_u40);
+            assert!(x.rescale_is_lossless::<typenum::U9>());
+        },
+        all {
+            (fp!(0), true, true, true);
+            (fp!(1.25), true, false, false);
+            (fp!(-1.25), true, false, false);
+            (fp!(1.255), false, false, false);
+            (fp!(1234), true, true, false);
+        },
+        fp64 {
+            (fp!(0.000000001), false, false, false);
+        },
+        fp128 {
+            (fp!(0.000000001), false, false, true);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rescale() -> Result<()> {
+    use typenum::{U12, U2, U40};
+
+    test_fixed_point! {
+        case (x | FixedPoint, floor | Layout, ceil | Layout, nearest | Layout) => {
+            assert_eq!(x.rescale::<U2>(Floor)?.into_bits(), floor);
+            assert_eq!(x.rescale::<U2>(Ceil)?.into_bits(), ceil);
+            assert_eq!(x.rescale::<U2>(Nearest)?.into_bits(), nearest);
+            assert_eq!(x.rescale::<U12>(Floor)?.rescale::<U2>(Floor)?.into_bits(), floor);
+        },
+        all {
+            (fp!(0), 0, 0, 0);
+            (fp!(1.25), 125, 125, 125);
+            (fp!(1.255), 125, 126, 126);
+            (fp!(-1.255), -126, -125, -126);
+            (fp!(-1.254), -126, -125, -125);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert!(x.rescale::<U40>(Floor).is_err());
+        },
+        all {
+            (fp!(1));
+            (FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert!(x.rescale::<U12>(Floor).is_err());
+        },
+        fp64 {
+            (FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn approx_eq() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, tolerance | FixedPoint, expected | bool) => {
+            assert_eq!(a.approx_eq(b, tolerance), expected);
+            assert_eq!(b.approx_eq(a, tolerance), expected);
+        },
+        all {
+            (fp!(1), fp!(1), fp!(0), true);
+            (fp!(1), fp!(1.5), fp!(0.5), true);
+            (fp!(1), fp!(1.5), fp!(0.499999999), false);
+            (fp!(-1), fp!(1), fp!(2), true);
+            (fp!(-1), fp!(1), fp!(1.999999999), false);
+            (fp!(1), fp!(1), fp!(-0.1), false);
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX, false);
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::ZERO, false);
+            (FixedPoint::MIN, FixedPoint::MIN, FixedPoint::ZERO, true);
+            (FixedPoint::MAX, FixedPoint::MAX.csub(fp!(1))?, fp!(1), true);
+            (FixedPoint::MIN, FixedPoint::MIN.cadd(FixedPoint::MAX)?, FixedPoint::MAX, true);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn approx_eq_epsilon() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | bool) => {
+            assert_eq!(a.approx_eq_epsilon(b), expected);
+            assert_eq!(b.approx_eq_epsilon(a), expected);
+        },
+        all {
+            (fp!(1), fp!(1), true);
+            (fp!(1), fp!(1).cadd(FixedPoint::EPSILON)?, true);
+            (fp!(1), fp!(1).cadd(FixedPoint::EPSILON.cmul(2)?)?, false);
+            (FixedPoint::MIN, FixedPoint::MAX, false);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn half_sum_exact() -> Result<()> {
+    test_fixed_point! {
+        case (expected | FixedPoint) => {
+            assert_eq!(FixedPoint::half_sum(expected, expected, Floor), expected);
+            assert_eq!(FixedPoint::half_sum(expected, expected, Ceil), expected);
+        },
+        all {
+            (fp!(0));
+            (fp!(1));
+            (fp!(-1));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::half_sum(a, b, Floor), expected);
+            assert_eq!(FixedPoint::half_sum(b, a, Floor), expected);
+            assert_eq!(FixedPoint::half_sum(a, b, Ceil), expected);
+            assert_eq!(FixedPoint::half_sum(b, a, Ceil), expected);
+        },
+        all {
+            (fp!(1), fp!(3), fp!(2));
+            (fp!(1), fp!(2), fp!(1.5));
+            (fp!(7.123456789), fp!(7.123456783), fp!(7.123456786));
+            (fp!(9000), fp!(9050), fp!(9025));
+            (fp!(9000), fp!(-9000), fp!(0));
+            (fp!(9000000000), fp!(9000000002), fp!(9000000001));
+            (
+                fp!(9000000000.000000001),
+                fp!(-9000000000.000000005),
+                fp!(-0.000000002),
+            );
+            (FixedPoint::MAX, FixedPoint::MIN.cadd(FixedPoint::EPSILON)?, fp!(0));
+        },
+        fp128 {
+            (fp!(7.123456789123456789), fp!(7.123456789123456783), fp!(7.123456789123456786));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn half_sum_rounded() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(FixedPoint::half_sum(a, b, Floor), expected_floor);
+            assert_eq!(FixedPoint::half_sum(b, a, Floor), expected_floor);
+            assert_eq!(FixedPoint::half_sum(a, b, Ceil), expected_ceil);
+            assert_eq!(FixedPoint::half_sum(b, a, Ceil), expected_ceil);
+        },
+        all {
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::EPSILON.cneg()?, fp!(0));
+        },
+        fp64 {
+            (fp!(9000000000.000000394), fp!(9000000001.000000397), fp!(9000000000.500000395), fp!(9000000000.500000396));
+            (
+                fp!(9000000000.000000001),
+                fp!(-9000000000.000000006),
+                fp!(-0.000000003),
+                fp!(-0.000000002),
+            );
+            (fp!(7.123456789), fp!(7.123456788), fp!(7.123456788), fp!(7.123456789));
+        },
+        fp128 {
+            (fp!(7.123456789123456789), fp!(7.123456789123456788), fp!(7.123456789123456788), fp!(7.123456789123456789));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn integral() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, expected_floor | Layout, expected_ceil | Layout) => {
+            assert_eq!(a.integral(Floor), expected_floor);
+            assert_eq!(a.integral(Ceil), expected_ceil);
+        },
+        all {
+            (FixedPoint::ZERO, 0, 0);
+            (fp!(0.0001), 0, 1);
+            (fp!(-0.0001), -1, 0);
+            (fp!(2.0001), 2, 3);
+            (fp!(-2.0001), -3, -2);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn integral_nearest() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, expected | Layout) => {
+            assert_eq!(a.integral(Nearest), expected);
+            assert_eq!(a.round_to_inner(Nearest), expected);
+        },
+        all {
+            (FixedPoint::ZERO, 0);
+            (fp!(0.4999), 0);
+            (fp!(0.5), 1);
+            (fp!(-0.5), -1);
+            (fp!(2.5), 3);
+            (fp!(-2.5), -3);
+            (fp!(-2.4999), -2);
+            (fp!(-2.5001), -3);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn round_to_inner() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | Layout, expected_ceil | Layout) => {
+            assert_eq!(x.round_to_inner(Floor), expected_floor);
+            assert_eq!(x.round_to_inner(Ceil), expected_ceil);
+        },
+        all {
+            (fp!(0), 0, 0);
+            (fp!(42), 42, 42);
+            (fp!(1.4), 1, 2);
+            (fp!(-1.4), -2, -1);
+            (FixedPoint::MAX, Layout::MAX / 10i64.pow(FixedPoint::PRECISION as u32) as Layout, Layout::MAX / 10i64.pow(FixedPoint::PRECISION as u32) as Layout + 1);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | i128) => {
+            assert_eq!(x.round_to_inner(Floor) as i128, expected);
+            // The deprecated method truncates the result.
+            assert_ne!(x.rounding_to_i64() as i128, expected);
+        },
+        fp128 {
+            (fp!(100000000000000000000.4), 100000000000000000000);
+            (fp!(-100000000000000000000.4), -100000000000000000001);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_towards_zero_by() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, rounder | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.round_towards_zero_by(rounder), expected);
+            assert_eq!(x.cneg()?.round_towards_zero_by(rounder), expected.cneg()?);
+        },
+        all {
+            (fp!(1234.56789), fp!(100), fp!(1200));
+            (fp!(1234.56789), fp!(10), fp!(1230));
+            (fp!(1234.56789), fp!(1), fp!(1234));
+            (fp!(1234.56789), fp!(0.1), fp!(1234.5));
+            (fp!(1234.56789), fp!(0.01), fp!(1234.56));
+            (fp!(1234.56789), fp!(0.001), fp!(1234.567));
+            (fp!(1234.56789), fp!(0.0001), fp!(1234.5678));
+            (fp!(1234.56789), fp!(0.00001), fp!(1234.56789));
+        },
+        fp128 {
+            (fp!(1234.56789123456789), fp!(0.0000000000001), fp!(1234.5678912345678));
+            (fp!(1234.56789123456789), fp!(0.00000000000001), fp!(1234.56789123456789));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to_pow10() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, exponent | i32, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.round_to_pow10(exponent, Floor)?, expected_floor);
+            assert_eq!(x.round_to_pow10(exponent, Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(1234), 2, fp!(1200), fp!(1300));
+            (fp!(-1234), 2, fp!(-1300), fp!(-1200));
+            (fp!(1200), 2, fp!(1200), fp!(1200));
+            (fp!(1234), 0, fp!(1234), fp!(1234));
+            (fp!(1234.5678), 0, fp!(1234), fp!(1235));
+            (fp!(1234.5678), -2, fp!(1234.56), fp!(1234.57));
+            (fp!(-1234.5678), -2, fp!(-1234.57), fp!(-1234.56));
+            (fp!(0.000000001), -9, fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.000000001), -30, fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.000000001), 3, fp!(0), fp!(1000));
+            (fp!(0), 50, fp!(0), fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to_places() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, places | u32, expected_floor | FixedPoint, expected_ceil | FixedPoint, expected_nearest | FixedPoint) => {
+            assert_eq!(x.round_to_places(places, Floor)?, expected_floor);
+            assert_eq!(x.round_to_places(places, Ceil)?, expected_ceil);
+            assert_eq!(x.round_to_places(places, Nearest)?, expected_nearest);
+            assert_eq!(x.cneg()?.round_to_places(places, Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.round_to_places(places, Ceil)?, expected_floor.cneg()?);
+            assert_eq!(x.cneg()?.round_to_places(places, Nearest)?, expected_nearest.cneg()?);
+        },
+        all {
+            (fp!(1.2345), 0, fp!(1), fp!(2), fp!(1));
+            (fp!(1.2345), 2, fp!(1.23), fp!(1.24), fp!(1.23));
+            (fp!(1.2345), 3, fp!(1.234), fp!(1.235), fp!(1.235));
+            (fp!(1.2345), 4, fp!(1.2345), fp!(1.2345), fp!(1.2345));
+            (fp!(0.000000001), 8, fp!(0), fp!(0.00000001), fp!(0));
+            (fp!(0.000000001), 9, fp!(0.000000001), fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.000000001), u32::MAX, fp!(0.000000001), fp!(0.000000001), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), 17, fp!(0), fp!(0.00000000000000001), fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, places | u32, mode | RoundMode) => {
+            assert_eq!(x.round_to_places(places, mode), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX, 0, Ceil);
+            (FixedPoint::MAX, 2, AwayFromZero);
+            (FixedPoint::MIN, 1, Floor);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to_pow10_beyond_layout() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, exponent | i32, mode | RoundMode, expected | Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(x.round_to_pow10(exponent, mode), expected);
+        },
+        all {
+            (fp!(5), 50, Floor, Ok(fp!(0)));
+            (fp!(5), 50, Ceil, Err(ArithmeticError::Overflow));
+            (fp!(-5), 50, Floor, Err(ArithmeticError::Overflow));
+            (fp!(-5), 50, Ceil, Ok(fp!(0)));
+            (FixedPoint::MAX, 0, Ceil, Err(ArithmeticError::Overflow));
+            (FixedPoint::MIN, 0, Floor, Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::cognitive_complexity)]
+fn next_power_of_ten() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.next_power_of_ten()?, expected);
+            assert_eq!(x.cneg()?.next_power_of_ten()?, expected.cneg()?);
+        },
+        all {
+            (fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.000000002), fp!(0.00000001));
+            (fp!(0.000000009), fp!(0.00000001));
+            (fp!(0.0000001), fp!(0.0000001));
+            (fp!(0.0000002), fp!(0.000001));
+            (fp!(0.1), fp!(0.1));
+            (fp!(0.100000001), fp!(1));
+            (fp!(1), fp!(1));
+            (fp!(2), fp!(10));
+            (fp!(1234567), fp!(10000000));
+            (fp!(923372036.654775807), fp!(1000000000));
+            (fp!(-0.000000001), fp!(-0.000000001));
+            (fp!(-0.000000002), fp!(-0.00000001));
+            (fp!(-0.000000009), fp!(-0.00000001));
+            (fp!(-0.00000001), fp!(-0.00000001));
+            (fp!(-0.00000002), fp!(-0.0000001));
+            (fp!(-0.100000001), fp!(-1));
+            (fp!(-923372021.854775808), fp!(-1000000000));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), fp!(0.000000000000000001));
+            (fp!(0.000000000000000002), fp!(0.00000000000000001));
+            (fp!(0.000000000000000009), fp!(0.00000000000000001));
+            (fp!(0.00000000000000001), fp!(0.00000000000000001));
+            (fp!(0.00000000000000002), fp!(0.0000000000000001));
+            (fp!(0.100000000000000001), fp!(1));
+            (fp!(1234567891234567), fp!(10000000000000000));
+            (fp!(923372036987654321.854775807), fp!(1000000000000000000));
+            (fp!(-0.000000000000000001), fp!(-0.000000000000000001));
+            (fp!(-0.000000000000000002), fp!(-0.00000000000000001));
+            (fp!(-0.000000000000000009), fp!(-0.00000000000000001));
+            (fp!(-0.00000000000000001), fp!(-0.00000000000000001));
+            (fp!(-0.00000000000000002), fp!(-0.0000000000000001));
+            (fp!(-0.100000000000000001), fp!(-1));
+            (fp!(-923372036987654321.854775808), fp!(-1000000000000000000));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.next_power_of_ten()?, expected);
+        },
+        fp64 {
+            (fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(0), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.next_power_of_ten(), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+        fp64 {
+            (fp!(9223372036.654775807));
+            (fp!(-9223372036.654775807));
+        },
+        fp128 {
+            (fp!(150000000000000000000.0));
+            (fp!(-150000000000000000000.854775807));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn rounding_to_i64() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | i64) => {
+            assert_eq!(x.rounding_to_i64(), expected);
+        },
+        all {
+            (fp!(0), 0);
+            (fp!(42), 42);
+            (fp!(1.4), 1);
+            (fp!(1.6), 2);
+            (fp!(-1.4), -1);
+            (fp!(-1.6), -2);
+            (fp!(0.4999), 0);
+            (fp!(0.5), 1);
+            (fp!(0.5001), 1);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[allow(clippy::float_cmp)]
+fn to_f64() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | f64) => {
+            assert_eq!(f64::from(x), expected);
+        },
+        all {
+            (fp!(0), 0.0);
+            (fp!(0.1), 0.1);
+            (fp!(1), 1.0);
+            (fp!(1.5), 1.5);
+            (fp!(-5), -5.);
+            (fp!(-14.14), -14.14);
+            (fp!(42.123456789), 42.123456789);
+            (fp!(-42.123456789), -42.123456789);
+            (fp!(8003332421.536753168), 8003332421.536753);
+            (fp!(-8003332421.536753168), -8003332421.536753);
+            (fp!(9223372036.854775807), 9223372036.854776);
+            (fp!(-9223372036.854775807), -9223372036.854776);
+            (fp!(922337203.685477581), 922337203.6854776);
+            (fp!(-922337203.685477581), -922337203.6854776);
+            (fp!(92233720.36854775), 92233720.36854775);
+            (fp!(-92233720.36854775), -92233720.36854775);
+        },
+        fp128 {
+            (fp!(0.0000000000025), 25e-13);
+            (fp!(1000000.0000000000025), 1e6);
+            (fp!(-1000000.0000000000025), -1e6);
+            (fp!(0.000000000000000025), 25e-18);
+            (fp!(-0.000000000000000025), -25e-18);
+            (fp!(2.1234567890123457), 2.1234567890123457);
+            (fp!(-2.1234567890123457), -2.1234567890123457);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn from_f64() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | f64) => {
+            assert_eq!(Ok(x), expected.try_into());
+            assert_eq!(Ok(x.cneg().unwrap()), (-expected).try_into());
+        },
+        all {
+            (fp!(0), 0.0);
+            (fp!(0.5), 0.5);
+            (fp!(1), 1.0);
+            (fp!(1.5), 1.5);
+            (fp!(42.123456789), 42.123456789);
+
+            (fp!(803332.421536753), 803332.421536753);
+            (fp!(8033324.21536753), 8033324.21536753);
+            (fp!(80333242.1536753), 80333242.1536753);
+            (fp!(803332421.536753), 803332421.536753);
+            (fp!(8033324215.36753), 8033324215.36753);
+
+            (fp!(9223372036.85477), 9223372036.85477);
+        },
+        fp128 {
+            (fp!(0.803332421536753), 0.803332421536753);
+            (fp!(8.03332421536753), 8.03332421536753);
+            (fp!(8.03332421536753), 8.03332421536753);
+            (fp!(80.3332421536753), 80.3332421536753);
+            (fp!(803.332421536753), 803.332421536753);
+            (fp!(8033.32421536753), 8033.32421536753);
+            (fp!(80333.2421536753), 80333.2421536753);
+            // <see part of cases in `all` sections>
+            (fp!(80333242153.6753), 80333242153.6753);
+            (fp!(803332421536.753), 803332421536.753);
+            (fp!(8033324215367.53), 8033324215367.53);
+            (fp!(80333242153675.3), 80333242153675.3);
+            (fp!(803332421536753), 803332421536753.);
+            (fp!(8033324215367530), 8033324215367530.);
+            (fp!(8033324215367533), 8033324215367533.);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | f64) => {
+            assert_eq!(Ok(x), expected.try_into());
+        },
+        all {
+            (fp!(0), -0.0);
+            (fp!(0), 1e-300);
+            (fp!(0), 5e-324);
+            (fp!(10), 10.0);
+            (fp!(100), 100.0);
+            (fp!(0.1), 0.1);
+            (fp!(0.3), 0.1 + 0.2);
+        },
+        fp64 {
+            // Rounded according to the exact binary value: `0.00000000149999...`.
+            (fp!(0.000000001), 0.0000000015);
+            (fp!(0.000000003), 0.0000000025);
+        },
+    };
+    test_fixed_point! {
+        case (x | f64) => {
+            assert!(FixedPoint::try_from(x).is_err());
+        },
+        all {
+            (f64::NAN);
+            (f64::INFINITY);
+            (f64::NEG_INFINITY);
+            (1e40);
+            (f64::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_f32() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | f32) => {
+            assert_eq!(Ok(x), expected.try_into());
+            assert_eq!(Ok(x.cneg().unwrap()), (-expected).try_into());
+        },
+        all {
+            (fp!(0), 0.0);
+            (fp!(0.1), 0.1);
+            (fp!(0.5), 0.5);
+            (fp!(1.5), 1.5);
+            (fp!(4.20134), 4.20134);
+            (fp!(0.000123), 0.000_123_456_78);
+            (fp!(8033324), 8033324.0);
+            // Only 6 significant digits are kept.
+            (fp!(123457), 123456.7);
+        },
+    };
+    test_fixed_point! {
+        case (x | f32) => {
+            assert!(FixedPoint::try_from(x).is_err());
+        },
+        all {
+            (f32::NAN);
+            (f32::INFINITY);
+            (1e30);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_add() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.saturating_add(b), expected);
+            assert_eq!(b.saturating_add(a), expected);
+            assert_eq!(a.cneg()?.saturating_add(b.cneg()?), expected.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(0), fp!(3000.0000006), fp!(3000.0000006));
+            (fp!(-1000.0000002), fp!(0), fp!(-1000.0000002));
+            (fp!(-1000.0000002), fp!(3000.0000006), fp!(2000.0000004));
+            (fp!(-1000.0000002), fp!(-3000.0000006), fp!(-4000.0000008));
+            (fp!(4611686018.427387903), fp!(4611686018.427387903), fp!(9223372036.854775806));
+        },
+        fp128 {
+            (fp!(0), fp!(3000000000000.0000000000000006), fp!(3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(-1000000000000.0000000000000002));
+            (fp!(-1000000000000.0000000000000002), fp!(3000000000000.0000000000000006), fp!(2000000000000.0000000000000004));
+            (fp!(-1000000000000.0000000000000002), fp!(-3000000000000.0000000000000006), fp!(-4000000000000.0000000000000008));
+            (fp!(4611686018000000000.000000000427387903), fp!(4611686018000000000.000000000427387903), fp!(9223372036000000000.000000000854775806));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.saturating_add(b), expected);
+        },
+        fp64 {
+            (fp!(9222222222), fp!(9222222222), FixedPoint::MAX);
+            (fp!(4611686019), fp!(4611686018.427387903), FixedPoint::MAX);
+            (fp!(-9222222222), fp!(-9222222222), FixedPoint::MIN);
+            (fp!(-4611686019), fp!(-4611686018.427387903), FixedPoint::MIN);
+        },
+        fp128 {
+            (fp!(85550005550005550005), fp!(85550005550005550005), FixedPoint::MAX);
+            (fp!(85550005550005550005), fp!(85550005550005550005.000000000427387), FixedPoint::MAX);
+            (fp!(-85550005550005550005), fp!(-85550005550005550005), FixedPoint::MIN);
+            (fp!(-85550005550005550005), fp!(-85550005550005550005.000000000427387), FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_add_sub_int() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | Layout, sum | FixedPoint, diff | FixedPoint) => {
+            assert_eq!(a.saturating_add_int(b), sum);
+            assert_eq!(a.saturating_sub_int(b), diff);
+        },
+        all {
+            (fp!(1.5), 2, fp!(3.5), fp!(-0.5));
+            (fp!(-1.5), -2, fp!(-3.5), fp!(0.5));
+            (fp!(0), 0, fp!(0), fp!(0));
+            (fp!(0), Layout::MAX, FixedPoint::MAX, FixedPoint::MIN);
+            (fp!(0), Layout::MIN, FixedPoint::MIN, FixedPoint::MAX);
+            (FixedPoint::MAX, 1, FixedPoint::MAX, FixedPoint::MAX.csub(fp!(1))?);
+            (FixedPoint::MIN, 1, FixedPoint::MIN.cadd(fp!(1))?, FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | i128, sum | FixedPoint, diff | FixedPoint) => {
+            let b = b as Layout;
+            assert_eq!(a.saturating_add_int(b), sum);
+            assert_eq!(a.saturating_sub_int(b), diff);
+        },
+        fp64 {
+            // `rhs * COEF` overflows, but the result is in range.
+            (FixedPoint::MIN, 10000000000, fp!(776627963.145224192), FixedPoint::MIN);
+            (FixedPoint::MAX, 10000000000, FixedPoint::MAX, fp!(-776627963.145224193));
+        },
+        fp128 {
+            (
+                FixedPoint::MIN,
+                200000000000000000000,
+                fp!(29858816539530768268.312696284115894272),
+                FixedPoint::MIN
+            );
+            (
+                FixedPoint::MAX,
+                200000000000000000000,
+                FixedPoint::MAX,
+                fp!(-29858816539530768268.312696284115894273)
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn add_mod() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, modulus | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.add_mod(b, modulus, Floor)?, expected);
+            assert_eq!(b.add_mod(a, modulus, Ceil)?, expected);
+        },
+        all {
+            (fp!(0.25), fp!(0.5), fp!(1), fp!(0.75));
+            (fp!(0.75), fp!(0.5), fp!(1), fp!(0.25));
+            (fp!(0.75), fp!(0.25), fp!(1), fp!(0));
+            (fp!(23), fp!(1), fp!(24), fp!(0));
+            (fp!(-0.25), fp!(0), fp!(1), fp!(0.75));
+            (fp!(-3.5), fp!(-10), fp!(1), fp!(0.5));
+            (fp!(370), fp!(-5), fp!(360), fp!(5));
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX, fp!(0));
+            (FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX.csub(FixedPoint::EPSILON.cmul(2)?)?);
+            (FixedPoint::MAX.csub(fp!(1))?, FixedPoint::MAX.csub(fp!(2))?, FixedPoint::MAX, FixedPoint::MAX.csub(fp!(3))?);
+        },
+    };
+    test_fixed_point! {
+        case (modulus | FixedPoint) => {
+            assert_eq!(fp!(1).add_mod(fp!(1), modulus, Floor), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0));
+            (fp!(-1));
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn copysign() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, sign_source | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.copysign(sign_source)?, expected);
+            assert_eq!(x.cneg()?.copysign(sign_source)?, expected);
+        },
+        all {
+            (fp!(1.5), fp!(-0.1), fp!(-1.5));
+            (fp!(1.5), fp!(0.1), fp!(1.5));
+            (fp!(1.5), fp!(0), fp!(1.5));
+            (fp!(0), fp!(-1), fp!(0));
+            (fp!(0), fp!(1), fp!(0));
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MAX.cneg()?);
+            (FixedPoint::MAX, FixedPoint::EPSILON, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1).cneg()?)?, FixedPoint::MIN);
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1)), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.copysign(fp!(0)), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn clamp_min_max() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, lo | FixedPoint, hi | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.clamp(lo, hi), expected);
+            assert_eq!(x.clamp_to_range(lo, hi)?, expected);
+            assert_eq!(x.max(lo).min(hi), expected);
+            assert_eq!(x.min(hi).max(lo), expected);
+        },
+        all {
+            (fp!(0.5), fp!(-1), fp!(1), fp!(0.5));
+            (fp!(1.5), fp!(-1), fp!(1), fp!(1));
+            (fp!(-1.5), fp!(-1), fp!(1), fp!(-1));
+            (fp!(1), fp!(1), fp!(1), fp!(1));
+            (FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MIN);
+            (FixedPoint::MAX, FixedPoint::EPSILON.cneg()?, FixedPoint::EPSILON, FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint) => {
+            assert_eq!(a.min(b), Ord::min(a, b));
+            assert_eq!(a.max(b), Ord::max(a, b));
+            assert_eq!(b.min(a), Ord::min(a, b));
+            assert_eq!(b.max(a), Ord::max(a, b));
+        },
+        all {
+            (fp!(1), fp!(2));
+            (fp!(-1), fp!(0.5));
+            (fp!(3), fp!(3));
+            (FixedPoint::MIN, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, lo | FixedPoint, hi | FixedPoint) => {
+            assert_eq!(x.clamp_to_range(lo, hi), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0), fp!(1), fp!(-1));
+            (fp!(0), FixedPoint::MAX, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "i64")]
+#[should_panic(expected = "`lo` must be less than or equal to `hi`")]
+fn clamp_invalid_range() {
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    Fp64::ZERO.clamp(Fp64::ONE, Fp64::ZERO);
+}
+
+#[test]
+fn signum() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.signum(), expected);
+            assert_eq!(x.is_positive(), expected == FixedPoint::ONE);
+            assert_eq!(x.is_negative(), expected == FixedPoint::ONE.cneg()?);
+            assert_eq!(x.is_zero(), expected == FixedPoint::ZERO);
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(2.5), fp!(1));
+            (fp!(-2.5), fp!(-1));
+            (FixedPoint::EPSILON, fp!(1));
+            (FixedPoint::EPSILON.cneg()?, fp!(-1));
+            (FixedPoint::MAX, fp!(1));
+            (FixedPoint::MIN, fp!(-1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn floor_ceil_round() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint, expected_nearest | FixedPoint) => {
+            assert_eq!(x.floor()?, expected_floor);
+            assert_eq!(x.ceil()?, expected_ceil);
+            assert_eq!(x.round(Floor)?, expected_floor);
+            assert_eq!(x.round(Ceil)?, expected_ceil);
+            assert_eq!(x.round(Nearest)?, expected_nearest);
+            assert_eq!(x.integral(Floor), expected_floor.integral(Floor));
+            assert_eq!(x.integral(Ceil), expected_ceil.integral(Ceil));
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0), fp!(0));
+            (fp!(2), fp!(2), fp!(2), fp!(2));
+            (fp!(2.5), fp!(2), fp!(3), fp!(3));
+            (fp!(-2.5), fp!(-3), fp!(-2), fp!(-3));
+            (fp!(-2.4), fp!(-3), fp!(-2), fp!(-2));
+            (FixedPoint::EPSILON, fp!(0), fp!(1), fp!(0));
+            (FixedPoint::EPSILON.cneg()?, fp!(-1), fp!(0), fp!(0));
+            (fp!(-9223372035.5), fp!(-9223372036), fp!(-9223372035), fp!(-9223372036));
+        },
+        fp128 {
+            (fp!(-170141183460469231730.5), fp!(-170141183460469231731), fp!(-170141183460469231730), fp!(-170141183460469231731));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.floor()?, expected_floor);
+            assert_eq!(x.round(TowardZero)?, expected_ceil);
+            assert_eq!(x.ceil(), Err(ArithmeticError::Overflow));
+            assert_eq!(x.round(AwayFromZero), Err(ArithmeticError::Overflow));
+        },
+        fp64 {
+            (FixedPoint::MAX, fp!(9223372036), fp!(9223372036));
+        },
+        fp128 {
+            (FixedPoint::MAX, fp!(170141183460469231731), fp!(170141183460469231731));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.ceil()?, expected_ceil);
+            assert_eq!(x.floor(), Err(ArithmeticError::Overflow));
+            assert_eq!(x.round(Nearest), Err(ArithmeticError::Overflow));
+        },
+        fp64 {
+            (FixedPoint::MIN, fp!(-9223372036));
+        },
+        fp128 {
+            (FixedPoint::MIN, fp!(-170141183460469231731));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn trunc_fract() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_trunc | FixedPoint, expected_fract | FixedPoint) => {
+            assert_eq!(x.trunc(), expected_trunc);
+            assert_eq!(x.fract(), expected_fract);
+            assert_eq!(x.trunc().cadd(x.fract())?, x);
+            assert_eq!(x.cneg()?.trunc(), expected_trunc.cneg()?);
+            assert_eq!(x.cneg()?.fract(), expected_fract.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(3), fp!(3), fp!(0));
+            (fp!(12.75), fp!(12), fp!(0.75));
+            (fp!(0.5), fp!(0), fp!(0.5));
+            (FixedPoint::EPSILON, fp!(0), FixedPoint::EPSILON);
+        },
+        fp64 {
+            (FixedPoint::MAX, fp!(9223372036), fp!(0.854775807));
+        },
+        fp128 {
+            (FixedPoint::MAX, fp!(170141183460469231731), fp!(0.687303715884105727));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected_trunc | FixedPoint, expected_fract | FixedPoint) => {
+            assert_eq!(x.trunc(), expected_trunc);
+            assert_eq!(x.fract(), expected_fract);
+        },
+        fp64 {
+            (FixedPoint::MIN, fp!(-9223372036), fp!(-0.854775808));
+        },
+        fp128 {
+            (FixedPoint::MIN, fp!(-170141183460469231731), fp!(-0.687303715884105728));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn next_up_down() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_up | FixedPoint, expected_down | FixedPoint) => {
+            assert_eq!(x.next_up()?, expected_up);
+            assert_eq!(x.next_down()?, expected_down);
+            assert_eq!(x.next_up()?.next_down()?, x);
+            assert_eq!(x.cneg()?.next_up()?, expected_down.cneg()?);
+        },
+        all {
+            (fp!(0), FixedPoint::EPSILON, FixedPoint::EPSILON.cneg()?);
+        },
+        fp64 {
+            (fp!(1.5), fp!(1.500000001), fp!(1.499999999));
+            (fp!(-1), fp!(-0.999999999), fp!(-1.000000001));
+        },
+        fp128 {
+            (fp!(1.5), fp!(1.500000000000000001), fp!(1.499999999999999999));
+            (fp!(-1), fp!(-0.999999999999999999), fp!(-1.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.next_up(), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.next_down(), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.next_down()?.next_up()?, FixedPoint::MAX);
+            assert_eq!(FixedPoint::MIN.next_up()?.next_down()?, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cmul_rdiv_pow10() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, exponent | u32, expected | FixedPoint) => {
+            assert_eq!(x.cmul_pow10(exponent)?, expected);
+            assert_eq!(x.cneg()?.cmul_pow10(exponent)?, expected.cneg()?);
+            assert_eq!(expected.rdiv_pow10(exponent, Floor)?, x);
+            assert_eq!(expected.rdiv_pow10(exponent, Ceil)?, x);
+        },
+        all {
+            (fp!(0), 100, fp!(0));
+            (fp!(12.345), 0, fp!(12.345));
+            (fp!(12.345), 2, fp!(1234.5));
+            (fp!(0.000000001), 9, fp!(1));
+            (fp!(0.000000001), 18, fp!(1000000000));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), 38, fp!(100000000000000000000));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exponent | u32) => {
+            assert_eq!(x.cmul_pow10(exponent), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(1), 100);
+            (FixedPoint::MAX, 1);
+            (FixedPoint::EPSILON, 39);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Powers come from the power table, they must agree with the arithmetic ones.
+            for exponent in 0..=50 {
+                let expected = (10 as Layout).checked_pow(exponent).map(FixedPoint::from_bits);
+                assert_eq!(FixedPoint::EPSILON.cmul_pow10(exponent).ok(), expected);
+            }
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exponent | u32, mode | RoundMode, expected | FixedPoint) => {
+            assert_eq!(x.rdiv_pow10(exponent, mode)?, expected);
+        },
+        all {
+            (fp!(12.345), 2, Floor, fp!(0.12345));
+            (FixedPoint::from_bits(15), 1, Floor, FixedPoint::from_bits(1));
+            (FixedPoint::from_bits(15), 1, Ceil, FixedPoint::from_bits(2));
+            (FixedPoint::from_bits(15), 1, Nearest, FixedPoint::from_bits(2));
+            (FixedPoint::from_bits(-15), 1, Floor, FixedPoint::from_bits(-2));
+            (FixedPoint::from_bits(-15), 1, TowardZero, FixedPoint::from_bits(-1));
+            (fp!(1), 100, Floor, fp!(0));
+            (fp!(1), 100, Ceil, FixedPoint::EPSILON);
+            (fp!(-1), 100, Floor, FixedPoint::EPSILON.cneg()?);
+            (fp!(-1), 100, Ceil, fp!(0));
+            (fp!(0), 100, Ceil, fp!(0));
+            (FixedPoint::MAX, 100, Nearest, fp!(0));
+            (fp!(4), 19, Nearest, fp!(0));
+        },
+        fp64 {
+            // `10^19` doesn't fit `i64`, but its half does.
+            (FixedPoint::MAX, 19, Nearest, FixedPoint::EPSILON);
+            (FixedPoint::MIN, 19, Nearest, FixedPoint::EPSILON.cneg()?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rms() -> Result<()> {
+    test_fixed_point! {
+        case (values | &[FixedPoint], expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(FixedPoint::rms(values, Floor)?, expected_floor);
+            assert_eq!(FixedPoint::rms(values, Ceil)?, expected_ceil);
+        },
+        all {
+            (&[fp!(-2.5)], fp!(2.5), fp!(2.5));
+            (&[FixedPoint::MAX], FixedPoint::MAX, FixedPoint::MAX);
+            (&[FixedPoint::MAX, FixedPoint::MIN.cadd(FixedPoint::EPSILON)?], FixedPoint::MAX, FixedPoint::MAX);
+            (&[fp!(0), fp!(0)], fp!(0), fp!(0));
+            (&[fp!(1), fp!(-1), fp!(1), fp!(-1)], fp!(1), fp!(1));
+            (&[fp!(1), fp!(7)], fp!(5), fp!(5));
+        },
+        fp64 {
+            (&[fp!(3), fp!(-4)], fp!(3.535533905), fp!(3.535533906));
+        },
+        fp128 {
+            (&[fp!(3), fp!(-4)], fp!(3.535533905932737622), fp!(3.535533905932737623));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::rms(&[], Floor), Err(ArithmeticError::DomainViolation));
+            assert_eq!(FixedPoint::rms(&[FixedPoint::MIN], Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(
+                FixedPoint::rms(&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX], Floor),
+                Err(ArithmeticError::Overflow),
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn try_reduce() -> Result<()> {
+    test_fixed_point! {
+        case (values | &[FixedPoint], sum | FixedPoint, product | FixedPoint) => {
+            assert_eq!(FixedPoint::try_reduce(values.iter().copied(), FixedPoint::cadd)?, Some(sum));
+            assert_eq!(
+                FixedPoint::try_reduce(values.iter().copied(), |a, b| a.rmul(b, Floor))?,
+                Some(product),
+            );
+        },
+        all {
+            (&[fp!(1.5), fp!(2), fp!(3)], fp!(6.5), fp!(9));
+            (&[fp!(-1.5)], fp!(-1.5), fp!(-1.5));
+            (&[fp!(0.5), fp!(0.5), fp!(-0.5)], fp!(0.5), fp!(-0.125));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::try_reduce(None, FixedPoint::cadd), Ok(None));
+            assert_eq!(
+                FixedPoint::try_reduce([FixedPoint::MAX, fp!(1), fp!(2).cneg()?].iter().copied(), FixedPoint::cadd),
+                Err(ArithmeticError::Overflow),
+            );
+            assert_eq!(
+                FixedPoint::try_reduce([fp!(2), FixedPoint::MAX].iter().copied(), |a, b| a.rmul(b, Ceil)),
+                Err(ArithmeticError::Overflow),
+            );
+            assert_eq!(
+                FixedPoint::try_reduce([fp!(1), fp!(0)].iter().copied(), |a, b| a.rdiv(b, Ceil)),
+                Err(ArithmeticError::DivisionByZero),
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn inverse_lerp() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, v | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(FixedPoint::inverse_lerp(a, b, v, Floor)?, expected_floor);
+            assert_eq!(FixedPoint::inverse_lerp(a, b, v, Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(10), fp!(20), fp!(12.5), fp!(0.25), fp!(0.25));
+            (fp!(20), fp!(10), fp!(12.5), fp!(0.75), fp!(0.75));
+            (fp!(-1), fp!(1), fp!(-1), fp!(0), fp!(0));
+            (fp!(-1), fp!(1), fp!(1), fp!(1), fp!(1));
+            (fp!(0), fp!(1), fp!(2), fp!(2), fp!(2));
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX, fp!(1), fp!(1));
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MAX, fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(0), fp!(3), fp!(1), fp!(0.333333333), fp!(0.333333334));
+            (fp!(0), fp!(-3), fp!(1), fp!(-0.333333334), fp!(-0.333333333));
+        },
+        fp128 {
+            (fp!(0), fp!(3), fp!(1), fp!(0.333333333333333333), fp!(0.333333333333333334));
+            (fp!(0), fp!(-3), fp!(1), fp!(-0.333333333333333334), fp!(-0.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, t | FixedPoint) => {
+            let v = a.cadd(b.csub(a)?.rmul(t, Floor)?)?;
+            let recovered = FixedPoint::inverse_lerp(a, b, v, Floor)?;
+            assert!(recovered.approx_eq(t, FixedPoint::EPSILON), "{} != {}", recovered, t);
+        },
+        all {
+            (fp!(10), fp!(20), fp!(0.123456789));
+            (fp!(-5), fp!(7.5), fp!(0.5));
+            (fp!(100), fp!(101), fp!(0.999999999));
+            (fp!(3), fp!(-1), fp!(0.7));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::inverse_lerp(fp!(1), fp!(1), fp!(1), Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(
+                FixedPoint::inverse_lerp(fp!(0), FixedPoint::EPSILON, FixedPoint::MAX, Floor),
+                Err(ArithmeticError::Overflow),
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rlog() -> Result<()> {
+    use crate::ops::transcendental::RoundingLog;
+
+    test_fixed_point! {
+        case (
+            x | FixedPoint,
+            base | FixedPoint,
+            expected_floor | FixedPoint,
+            expected_ceil | FixedPoint,
+        ) => {
+            assert_eq!(x.rlog(base, Floor)?, expected_floor);
+            assert_eq!(x.rlog(base, Ceil)?, expected_ceil);
+
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rlog(base, *mode)?;
+                assert_eq!(x.log(base, *mode)?, actual);
+                assert_eq!(RoundingLog::rlog(x, base, *mode)?, actual);
+            }
+        },
+        all {
+            // Exact results aren't rounded in any direction.
+            (fp!(8), fp!(2), fp!(3), fp!(3));
+            (fp!(100), fp!(10), fp!(2), fp!(2));
+            (fp!(100), fp!(0.1), fp!(-2), fp!(-2));
+            (fp!(0.5), fp!(2), fp!(-1), fp!(-1));
+            (fp!(0.25), fp!(0.5), fp!(2), fp!(2));
+            (fp!(8), fp!(4), fp!(1.5), fp!(1.5));
+            (fp!(10), fp!(100), fp!(0.5), fp!(0.5));
+            (fp!(1), fp!(7), fp!(0), fp!(0));
+            (fp!(7), fp!(7), fp!(1), fp!(1));
+        },
+        fp64 {
+            (fp!(10), fp!(2), fp!(3.321928094), fp!(3.321928095));
+            (fp!(2), fp!(10), fp!(0.301029995), fp!(0.301029996));
+            (fp!(100), fp!(3), fp!(4.191806548), fp!(4.191806549));
+            (fp!(0.3), fp!(0.7), fp!(3.375546347), fp!(3.375546348));
+            (fp!(2), fp!(1.000000001), fp!(693147180.906518899), fp!(693147180.9065189));
+        },
+        fp128 {
+            (fp!(10), fp!(2), fp!(3.321928094887362347), fp!(3.321928094887362348));
+            (fp!(2), fp!(10), fp!(0.301029995663981195), fp!(0.301029995663981196));
+            (fp!(100), fp!(3), fp!(4.191806548578769208), fp!(4.191806548578769209));
+            (fp!(0.3), fp!(0.7), fp!(3.37554634769283846), fp!(3.375546347692838461));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, base | FixedPoint) => {
+            assert_eq!(x.rlog(base, Floor), Err(ArithmeticError::DomainViolation));
+            assert_eq!(x.rlog(base, Ceil), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0), fp!(2));
+            (fp!(-1), fp!(2));
+            (fp!(2), fp!(0));
+            (fp!(2), fp!(-2));
+            (fp!(2), fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rpow() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, exp | FixedPoint, expected | FixedPoint) => {
+            for mode in &[Floor, Ceil, Nearest, TowardZero, AwayFromZero] {
+                assert_eq!(x.rpow(exp, *mode)?, expected);
+            }
+        },
+        all {
+            // Powers of two have exact binary logarithms.
+            (fp!(4), fp!(0.5), fp!(2));
+            (fp!(4), fp!(-1.5), fp!(0.125));
+            (fp!(2), fp!(10), fp!(1024));
+            (fp!(0.25), fp!(0.5), fp!(0.5));
+            (fp!(7), fp!(0), fp!(1));
+            (fp!(1), fp!(123.45), fp!(1));
+            (fp!(0), fp!(0), fp!(1));
+            (fp!(0), fp!(2.5), fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exp | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            // Tolerances follow the documented bound `(|exp| + 32) * 2^-60 * expected + EPSILON`.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rpow(exp, *mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        fp64 {
+            (fp!(2), fp!(0.5), fp!(1.414213562), FixedPoint::EPSILON);
+            (fp!(0.5), fp!(0.5), fp!(0.707106781), FixedPoint::EPSILON);
+            (fp!(1.05), fp!(2.5), fp!(1.129726322), FixedPoint::EPSILON);
+            (fp!(1.0001), fp!(365), fp!(1.037172411), FixedPoint::EPSILON);
+            (fp!(7), fp!(-0.3), fp!(0.557789825), FixedPoint::EPSILON);
+            (fp!(123.456), fp!(1.7), fp!(3593.939341117), FixedPoint::EPSILON);
+        },
+        fp128 {
+            (fp!(2), fp!(0.5), fp!(1.414213562373095049), fp!(0.00000000000000005));
+            (fp!(0.5), fp!(0.5), fp!(0.707106781186547524), fp!(0.00000000000000003));
+            (fp!(1.05), fp!(2.5), fp!(1.129726321947045722), fp!(0.00000000000000004));
+            (fp!(1.0001), fp!(365), fp!(1.03717241130255193), fp!(0.0000000000000004));
+            (fp!(7), fp!(-0.3), fp!(0.557789825303246007), fp!(0.00000000000000002));
+            (fp!(123.456), fp!(1.7), fp!(3593.939341116631643331), fp!(0.00000000000011));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exp | FixedPoint, expected | ArithmeticError) => {
+            assert_eq!(x.rpow(exp, Floor), Err(expected.clone()));
+            assert_eq!(x.rpow(exp, Ceil), Err(expected));
+        },
+        all {
+            (fp!(1).cneg()?, fp!(0.5), ArithmeticError::DomainViolation);
+            (fp!(0), fp!(1).cneg()?, ArithmeticError::DivisionByZero);
+            (FixedPoint::MAX, fp!(1.5), ArithmeticError::Overflow);
+            (fp!(10), fp!(100), ArithmeticError::Overflow);
+            (fp!(2), FixedPoint::MAX, ArithmeticError::Overflow);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, exp | FixedPoint) => {
+            // Too small results are rounded either to zero or to `EPSILON`.
+            assert_eq!(x.rpow(exp, Floor)?, FixedPoint::ZERO);
+            assert_eq!(x.rpow(exp, Ceil)?, FixedPoint::EPSILON);
+        },
+        all {
+            (fp!(10), fp!(100).cneg()?);
+            (fp!(2), FixedPoint::MIN);
+            (fp!(0.5), FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rexp() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            // Tolerances follow the documented bound `(|x| + 32) * 2^-60 * expected + EPSILON`.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rexp(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        all {
+            (fp!(0), fp!(1), fp!(0));
+            (fp!(20), fp!(485165195.409790278), fp!(0.00000003));
+        },
+        fp64 {
+            (fp!(1), fp!(2.718281828), FixedPoint::EPSILON);
+            (fp!(-1), fp!(0.367879441), FixedPoint::EPSILON);
+            (fp!(-0.05), fp!(0.951229425), FixedPoint::EPSILON);
+            (fp!(0.5), fp!(1.648721271), FixedPoint::EPSILON);
+            (fp!(10), fp!(22026.465794807), FixedPoint::EPSILON);
+            (fp!(-10), fp!(0.0000454), FixedPoint::EPSILON);
+        },
+        fp128 {
+            (fp!(1), fp!(2.718281828459045235), fp!(0.00000000000000008));
+            (fp!(-1), fp!(0.367879441171442322), fp!(0.00000000000000002));
+            (fp!(-0.05), fp!(0.951229424500714009), fp!(0.00000000000000003));
+            (fp!(0.5), fp!(1.648721270700128147), fp!(0.00000000000000005));
+            (fp!(10), fp!(22026.465794806716516958), fp!(0.000000000001));
+            (fp!(-10), fp!(0.000045399929762485), fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rexp(Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(x.rexp(Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(100));
+            (FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rexp(Floor)?, FixedPoint::ZERO);
+            assert_eq!(x.rexp(Ceil)?, FixedPoint::EPSILON);
+        },
+        all {
+            (fp!(100).cneg()?);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rln() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rln(Floor)?, expected_floor);
+            assert_eq!(x.rln(Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(1), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(10), fp!(2.302585092), fp!(2.302585093));
+            (fp!(2), fp!(0.69314718), fp!(0.693147181));
+            (fp!(0.5), fp!(-0.693147181), fp!(-0.69314718));
+            (fp!(1.000000001), fp!(0), fp!(0.000000001));
+            (fp!(123456.789), fp!(11.723646487), fp!(11.723646488));
+            (fp!(0.000000001), fp!(-20.723265837), fp!(-20.723265836));
+            (FixedPoint::MAX, fp!(22.945006538), fp!(22.945006539));
+        },
+        fp128 {
+            (fp!(10), fp!(2.302585092994045684), fp!(2.302585092994045685));
+            (fp!(2), fp!(0.693147180559945309), fp!(0.69314718055994531));
+            (fp!(0.5), fp!(-0.69314718055994531), fp!(-0.693147180559945309));
+            (fp!(123456.789), fp!(11.723646487185880981), fp!(11.723646487185880982));
+            (fp!(0.000000001), fp!(-20.723265836946411157), fp!(-20.723265836946411156));
+            (fp!(2.718281828459045235), fp!(0.999999999999999999), fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rln(Floor), Err(ArithmeticError::DomainViolation));
+            assert_eq!(x.rln(Ceil), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0));
+            (fp!(1).cneg()?);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rlog2() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rlog2(Floor)?, expected_floor);
+            assert_eq!(x.rlog2(Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(1), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(10), fp!(3.321928094), fp!(3.321928095));
+            (fp!(3), fp!(1.5849625), fp!(1.584962501));
+            (fp!(0.1), fp!(-3.321928095), fp!(-3.321928094));
+            (fp!(123456.789), fp!(16.913646648), fp!(16.913646649));
+            (fp!(0.000000001), fp!(-29.897352854), fp!(-29.897352853));
+            (FixedPoint::MAX, fp!(33.102647146), fp!(33.102647147));
+        },
+        fp128 {
+            (fp!(10), fp!(3.321928094887362347), fp!(3.321928094887362348));
+            (fp!(3), fp!(1.584962500721156181), fp!(1.584962500721156182));
+            (fp!(0.000000001), fp!(-29.897352853986261131), fp!(-29.89735285398626113));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            for mode in &[Floor, Ceil, Nearest] {
+                assert_eq!(x.rlog2(*mode)?, expected);
+            }
+        },
+        all {
+            (fp!(8), fp!(3));
+            (fp!(0.5), fp!(-1));
+            (fp!(1024), fp!(10));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rlog10() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rlog10(Floor)?, expected_floor);
+            assert_eq!(x.rlog10(Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(1), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(2), fp!(0.301029995), fp!(0.301029996));
+            (fp!(3), fp!(0.477121254), fp!(0.477121255));
+            (fp!(123456.789), fp!(5.091514977), fp!(5.091514978));
+            (FixedPoint::MAX, fp!(9.964889726), fp!(9.964889727));
+        },
+        fp128 {
+            (fp!(2), fp!(0.301029995663981195), fp!(0.301029995663981196));
+            (fp!(3), fp!(0.477121254719662437), fp!(0.477121254719662438));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            // Exact results aren't rounded in any direction.
+            for mode in &[Floor, Ceil, Nearest] {
+                assert_eq!(x.rlog10(*mode)?, expected);
+            }
+        },
+        all {
+            (fp!(10), fp!(1));
+            (fp!(1000), fp!(3));
+            (fp!(0.001), fp!(-3));
+            (fp!(0.000000001), fp!(-9));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rlog2(Floor), Err(ArithmeticError::DomainViolation));
+            assert_eq!(x.rlog10(Ceil), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0));
+            (fp!(1).cneg()?);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rnth_root() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, n | u32, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rnth_root(n, Floor)?, expected_floor);
+            assert_eq!(x.rnth_root(n, Ceil)?, expected_ceil);
+            if n % 2 == 1 {
+                assert_eq!(x.cneg()?.rnth_root(n, Floor)?, expected_ceil.cneg()?);
+                assert_eq!(x.cneg()?.rnth_root(n, Ceil)?, expected_floor.cneg()?);
+            }
+        },
+        all {
+            (fp!(0), 3, fp!(0), fp!(0));
+            (fp!(1), 7, fp!(1), fp!(1));
+            (fp!(1.5), 1, fp!(1.5), fp!(1.5));
+            (fp!(16), 4, fp!(2), fp!(2));
+            (fp!(0.125), 3, fp!(0.5), fp!(0.5));
+        },
+        fp64 {
+            (fp!(2), 5, fp!(1.148698354), fp!(1.148698355));
+            (fp!(2), 3, fp!(1.259921049), fp!(1.25992105));
+            (fp!(0.000000002), 3, fp!(0.001259921), fp!(0.001259922));
+            (FixedPoint::MAX, 3, fp!(2097.151999999), fp!(2097.152));
+            (FixedPoint::MAX, 4, fp!(309.900610438), fp!(309.900610439));
+        },
+        fp128 {
+            (fp!(2), 3, fp!(1.259921049894873164), fp!(1.259921049894873165));
+            (fp!(0.000000000000000001), 3, fp!(0.000001), fp!(0.000001));
+            (FixedPoint::MAX, 3, fp!(5541191.377756635628281025), fp!(5541191.377756635628281026));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, n | u32, expected | FixedPoint) => {
+            assert_eq!(x.rnth_root(n, Nearest)?, expected);
+            assert_eq!(x.cneg()?.rnth_root(n, Nearest)?, expected.cneg()?);
+            assert_eq!(x.cneg()?.rnth_root(n, TowardZero)?.abs()?, x.rnth_root(n, TowardZero)?);
+            assert_eq!(x.cneg()?.rnth_root(n, AwayFromZero)?.abs()?, x.rnth_root(n, AwayFromZero)?);
+        },
+        fp64 {
+            (fp!(2), 3, fp!(1.25992105));
+            (fp!(10), 3, fp!(2.15443469));
+            (fp!(0.5), 7, fp!(0.905723664));
+        },
+        fp128 {
+            (fp!(2), 3, fp!(1.259921049894873165));
+            (fp!(10), 3, fp!(2.154434690031883722));
+        },
+    };
+    // Too big powers are handled by the approximation.
+    test_fixed_point! {
+        case (x | FixedPoint, n | u32, expected | FixedPoint, tolerance | FixedPoint) => {
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rnth_root(n, *mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        all {
+            (fp!(1024), 10, fp!(2), FixedPoint::EPSILON);
+        },
+        fp64 {
+            (fp!(2), 100, fp!(1.00695555), FixedPoint::EPSILON);
+        },
+        fp128 {
+            (fp!(2), 5, fp!(1.148698354997035007), fp!(0.0000000000000001));
+            (fp!(2), 100, fp!(1.006955550056718809), fp!(0.0000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, n | u32) => {
+            assert_eq!(x.rnth_root(n, Floor), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(2), 0);
+            (fp!(1).cneg()?, 2);
+            (FixedPoint::MIN, 4);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rcbrt() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.rcbrt(Floor)?, expected);
+            assert_eq!(x.rcbrt(Ceil)?, expected);
+        },
+        all {
+            (fp!(27), fp!(3));
+            (fp!(-8), fp!(-2));
+            (fp!(0.001), fp!(0.1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rsin() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rsin(Floor)?, expected_floor);
+            assert_eq!(x.rsin(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rsin(Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.rsin(Ceil)?, expected_floor.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(0.841470984), fp!(0.841470985));
+            (fp!(-0.5), fp!(-0.479425539), fp!(-0.479425538));
+            (fp!(2), fp!(0.909297426), fp!(0.909297427));
+            (fp!(3.141592654), fp!(-0.000000001), fp!(0));
+            (fp!(10), fp!(-0.544021111), fp!(-0.54402111));
+            (fp!(-100), fp!(0.506365641), fp!(0.506365642));
+            (fp!(1000000), fp!(-0.349993503), fp!(-0.349993502));
+            (fp!(9223372036.854775807), fp!(-0.774505497), fp!(-0.774505496));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            // The absolute error is below `2^-58`, i.e. less than 4 units of the last place here.
+            let tolerance = FixedPoint::EPSILON.cmul(4)?;
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rsin(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        fp128 {
+            (fp!(1), fp!(0.841470984807896507));
+            (fp!(-0.5), fp!(-0.479425538604203));
+            (fp!(2), fp!(0.909297426825681695));
+            (fp!(3.141592653589793238), fp!(0));
+            (fp!(1000000000), fp!(0.545843449448699564));
+            (fp!(170141183460469231731.687303715884105727), fp!(-0.98822964263909619));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rcos() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rcos(Floor)?, expected_floor);
+            assert_eq!(x.rcos(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rcos(Floor)?, expected_floor);
+            assert_eq!(x.cneg()?.rcos(Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(0), fp!(1), fp!(1));
+        },
+        fp64 {
+            (fp!(1), fp!(0.540302305), fp!(0.540302306));
+            (fp!(-0.5), fp!(0.877582561), fp!(0.877582562));
+            (fp!(2), fp!(-0.416146837), fp!(-0.416146836));
+            (fp!(3.141592654), fp!(-1), fp!(-0.999999999));
+            (fp!(10), fp!(-0.83907153), fp!(-0.839071529));
+            (fp!(-100), fp!(0.862318872), fp!(0.862318873));
+            (fp!(1000000), fp!(0.936752127), fp!(0.936752128));
+            (fp!(9223372036.854775807), fp!(-0.632567179), fp!(-0.632567178));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            // The absolute error is below `2^-58`, i.e. less than 4 units of the last place here.
+            let tolerance = FixedPoint::EPSILON.cmul(4)?;
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rcos(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        fp128 {
+            (fp!(1), fp!(0.540302305868139717));
+            (fp!(-0.5), fp!(0.877582561890372716));
+            (fp!(2), fp!(-0.416146836547142387));
+            (fp!(3.141592653589793238), fp!(-1));
+            (fp!(1000000000), fp!(0.837887181363902334));
+            (fp!(170141183460469231731.687303715884105727), fp!(-0.152977689253708616));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rtan() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rtan(Floor)?, expected_floor);
+            assert_eq!(x.rtan(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rtan(Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.rtan(Ceil)?, expected_floor.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(1.557407724), fp!(1.557407725));
+            (fp!(-0.5), fp!(-0.54630249), fp!(-0.546302489));
+            (fp!(2), fp!(-2.185039864), fp!(-2.185039863));
+            (fp!(3.141592654), fp!(0), fp!(0.000000001));
+            (fp!(10), fp!(0.648360827), fp!(0.648360828));
+            (fp!(-100), fp!(0.587213915), fp!(0.587213916));
+            (fp!(1000000), fp!(-0.373624454), fp!(-0.373624453));
+            (fp!(9223372036.854775807), fp!(1.224384576), fp!(1.224384577));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            // The absolute error is below `2^-58`, i.e. less than 4 units of the last place here.
+            let tolerance = FixedPoint::EPSILON.cmul(4)?;
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rtan(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        fp128 {
+            (fp!(1), fp!(1.557407724654902231));
+            (fp!(-0.5), fp!(-0.546302489843790513));
+            (fp!(2), fp!(-2.185039863261518992));
+            (fp!(3.141592653589793238), fp!(0));
+            (fp!(1000000000), fp!(0.651452202145141289));
+        },
+    };
+    // The error is amplified by `1 / cos^2(x)`, e.g. it's about `43` for the greatest `fp128`.
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            let actual = x.rtan(Nearest)?;
+            assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+        },
+        all {
+            (fp!(1.570796327), fp!(-4875590037.825021204), fp!(10));
+        },
+        fp128 {
+            (fp!(170141183460469231731.687303715884105727), fp!(6.459959275500291165), fp!(0.0000000000000003));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn ratan() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.ratan(Floor)?, expected_floor);
+            assert_eq!(x.ratan(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.ratan(Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.ratan(Ceil)?, expected_floor.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(0.785398163), fp!(0.785398164));
+            (fp!(0.5), fp!(0.463647609), fp!(0.46364761));
+            (fp!(2), fp!(1.107148717), fp!(1.107148718));
+            (fp!(-3), fp!(-1.249045773), fp!(-1.249045772));
+            (fp!(1000000), fp!(1.570795326), fp!(1.570795327));
+            (fp!(0.000000001), fp!(0), fp!(0.000000001));
+            (fp!(9223372036.854775807), fp!(1.570796326), fp!(1.570796327));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            // The absolute error is below `2^-60`, i.e. close to `EPSILON` here.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.ratan(*mode)?;
+                assert!(actual.approx_eq(expected, FixedPoint::EPSILON), "{} != {}", actual, expected);
+            }
+        },
+        fp128 {
+            (fp!(1), fp!(0.78539816339744831));
+            (fp!(0.5), fp!(0.463647609000806116));
+            (fp!(-3), fp!(-1.249045772398254426));
+            (fp!(1000000000), fp!(1.570796325794896619));
+            (fp!(0.000000000000000001), fp!(0.000000000000000001));
+            (fp!(170141183460469231731.687303715884105727), fp!(1.570796326794896619));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn ratan2() -> Result<()> {
+    test_fixed_point! {
+        case (y | FixedPoint, x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(y.ratan2(x, Floor)?, expected_floor);
+            assert_eq!(y.ratan2(x, Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(0), fp!(1), fp!(0), fp!(0));
+            (fp!(0), fp!(0.000000001), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(-1), fp!(2.35619449), fp!(2.356194491));
+            (fp!(-1), fp!(-1), fp!(-2.356194491), fp!(-2.35619449));
+            (fp!(3), fp!(4), fp!(0.643501108), fp!(0.643501109));
+            (fp!(-3), fp!(4), fp!(-0.643501109), fp!(-0.643501108));
+            (fp!(0), fp!(-1), fp!(3.141592653), fp!(3.141592654));
+            (fp!(1), fp!(0), fp!(1.570796326), fp!(1.570796327));
+            (fp!(-1), fp!(0), fp!(-1.570796327), fp!(-1.570796326));
+            (fp!(0.000000001), fp!(-9223372036.854775807), fp!(3.141592653), fp!(3.141592654));
+        },
+    };
+    test_fixed_point! {
+        case (y | FixedPoint, x | FixedPoint, expected | FixedPoint) => {
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = y.ratan2(x, *mode)?;
+                assert!(actual.approx_eq(expected, FixedPoint::EPSILON), "{} != {}", actual, expected);
+            }
+            // The angle doesn't depend on the distance from the origin.
+            let scaled = y.rmul(fp!(0.5), Floor)?.ratan2(x.rmul(fp!(0.5), Floor)?, Nearest)?;
+            assert!(scaled.approx_eq(expected, FixedPoint::EPSILON), "{} != {}", scaled, expected);
+        },
+        fp128 {
+            (fp!(1), fp!(-1), fp!(2.356194490192344929));
+            (fp!(3), fp!(4), fp!(0.643501108793284387));
+            (fp!(0), fp!(-1), fp!(3.141592653589793238));
+            (fp!(-1), fp!(0), fp!(-1.570796326794896619));
+            (fp!(0.000000000000000001), FixedPoint::MIN, fp!(3.141592653589793238));
+        },
+    };
+    test_fixed_point! {
+        case (y | FixedPoint, x | FixedPoint) => {
+            assert_eq!(y.ratan2(x, Floor), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0), fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rsinh() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rsinh(Floor)?, expected_floor);
+            assert_eq!(x.rsinh(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rsinh(Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.rsinh(Ceil)?, expected_floor.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(1.175201193), fp!(1.175201194));
+            (fp!(0.5), fp!(0.521095305), fp!(0.521095306));
+            (fp!(2), fp!(3.626860407), fp!(3.626860408));
+            (fp!(-3), fp!(-10.017874928), fp!(-10.017874927));
+            (fp!(10), fp!(11013.232874703), fp!(11013.232874704));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            // The error is below `2^-60` for `|x| < 1` and `2^-54` relative to the result otherwise.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rsinh(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        all {
+            (fp!(0.000000001), fp!(0.000000001), FixedPoint::EPSILON);
+            (fp!(23), fp!(4872401723.1244513), fp!(0.0000003));
+        },
+        fp128 {
+            (fp!(1), fp!(1.175201193643801457), fp!(0.00000000000000007));
+            (fp!(0.5), fp!(0.521095305493747362), fp!(0.00000000000000003));
+            (fp!(-3), fp!(-10.017874927409901899), fp!(0.0000000000000006));
+            (fp!(10), fp!(11013.232874703393377237), fp!(0.0000000000007));
+            (fp!(40), fp!(117692633418509992.7039499553745174), fp!(7));
+            (fp!(0.000000000000000001), fp!(0.000000000000000001), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rsinh(Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(x.cneg()?.rsinh(Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(50));
+            (FixedPoint::MAX);
+        },
+        fp64 {
+            (fp!(24));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rcosh() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rcosh(Floor)?, expected_floor);
+            assert_eq!(x.rcosh(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rcosh(Floor)?, expected_floor);
+            assert_eq!(x.cneg()?.rcosh(Ceil)?, expected_ceil);
+        },
+        all {
+            (fp!(0), fp!(1), fp!(1));
+        },
+        fp64 {
+            (fp!(1), fp!(1.543080634), fp!(1.543080635));
+            (fp!(0.5), fp!(1.127625965), fp!(1.127625966));
+            (fp!(2), fp!(3.762195691), fp!(3.762195692));
+            (fp!(-3), fp!(10.067661995), fp!(10.067661996));
+            (fp!(10), fp!(11013.232920103), fp!(11013.232920104));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            // The error is below `2^-60` for `|x| < 1` and `2^-54` relative to the result otherwise.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rcosh(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        all {
+            (fp!(0.000000001), fp!(1), FixedPoint::EPSILON);
+            (fp!(23), fp!(4872401723.1244513), fp!(0.0000003));
+        },
+        fp128 {
+            (fp!(1), fp!(1.543080634815243778), fp!(0.00000000000000009));
+            (fp!(0.5), fp!(1.127625965206380785), fp!(0.00000000000000007));
+            (fp!(-3), fp!(10.067661995777765842), fp!(0.0000000000000006));
+            (fp!(10), fp!(11013.232920103323139721), fp!(0.0000000000007));
+            (fp!(40), fp!(117692633418509992.703949955374517404), fp!(7));
+            (fp!(0.000000000000000001), fp!(1), fp!(0.00000000000000006));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            assert_eq!(x.rcosh(Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(x.cneg()?.rcosh(Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(50));
+            (FixedPoint::MAX);
+        },
+        fp64 {
+            (fp!(24));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rtanh() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint, expected_ceil | FixedPoint) => {
+            assert_eq!(x.rtanh(Floor)?, expected_floor);
+            assert_eq!(x.rtanh(Ceil)?, expected_ceil);
+            assert_eq!(x.cneg()?.rtanh(Floor)?, expected_ceil.cneg()?);
+            assert_eq!(x.cneg()?.rtanh(Ceil)?, expected_floor.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+        },
+        fp64 {
+            (fp!(1), fp!(0.761594155), fp!(0.761594156));
+            (fp!(0.5), fp!(0.462117157), fp!(0.462117158));
+            (fp!(-2), fp!(-0.964027581), fp!(-0.96402758));
+            (fp!(10), fp!(0.999999995), fp!(0.999999996));
+            (fp!(20), fp!(0.999999999), fp!(1));
+            (FixedPoint::MAX, fp!(0.999999999), fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint, tolerance | FixedPoint) => {
+            // The error is below `2^-60` for `|x| < 1` and `2^-54` relative to the result otherwise.
+            for mode in &[Floor, Ceil, Nearest] {
+                let actual = x.rtanh(*mode)?;
+                assert!(actual.approx_eq(expected, tolerance), "{} != {}", actual, expected);
+            }
+        },
+        all {
+            (fp!(0.000000001), fp!(0.000000001), FixedPoint::EPSILON);
+        },
+        fp128 {
+            (fp!(1), fp!(0.761594155955764888), fp!(0.00000000000000005));
+            (fp!(0.5), fp!(0.462117157260009759), fp!(0.00000000000000003));
+            (fp!(-3), fp!(-0.995054753686730451), fp!(0.00000000000000006));
+            (fp!(20), fp!(0.999999999999999992), fp!(0.00000000000000006));
+            (fp!(0.000000000000000001), fp!(0.000000000000000001), fp!(0.000000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_mul() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | Layout, expected | FixedPoint) => {
+            assert_eq!(a.saturating_mul(b), expected);
+            assert_eq!(CheckedMul::saturating_mul(b, a), expected);
+            assert_eq!(a.cneg()?.saturating_mul(b), expected.cneg()?);
+            assert_eq!(a.saturating_mul(-b), expected.cneg()?);
+            assert_eq!(a.cneg()?.saturating_mul(-b), expected);
+        },
+        all {
+            (fp!(0), 0, fp!(0));
+            (fp!(3000.0000006), 0, fp!(0));
+            (fp!(3000.0000006), 1, fp!(3000.0000006));
+            (fp!(-1000.0000002), 0, fp!(0));
+            (fp!(-1000.0000002), 3, fp!(-3000.0000006));
+            (fp!(-1000.0000002), -4, fp!(4000.0000008));
+            (fp!(68601.48179), -468, fp!(-32105493.47772));
+        },
+        fp128 {
+            (fp!(3000000000000.0000000000000006), 0, FixedPoint::ZERO);
+            (fp!(3000000000000.0000000000000006), 1, fp!(3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), 0, FixedPoint::ZERO);
+            (fp!(-1000000000000.0000000000000002), 3, fp!(-3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), -4, fp!(4000000000000.0000000000000008));
+            (fp!(68603957391461.48475635294179), -85204, fp!(-5845331585582084347.18029605227516));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | i128, expected | FixedPoint) => {
+            let b = b as Layout;
+            assert_eq!(a.saturating_mul(b), expected);
+        },
+        fp64 {
+            (fp!(9222222222), 9222222222, FixedPoint::MAX);
+            (fp!(4611686019.427387903), 4611686019, FixedPoint::MAX);
+            (fp!(-9222222222), 9222222222, FixedPoint::MIN);
+            (fp!(4611686019.427387903), -4611686019, FixedPoint::MIN);
+        },
+        fp128 {
+            (fp!(85550005550005550005), 85550005550005550005, FixedPoint::MAX);
+            (fp!(14000444000.427387), 14000444000, FixedPoint::MAX);
+            (fp!(-85550005550005550005), 85550005550005550005, FixedPoint::MIN);
+            (fp!(14000444000.427387), -14000444000, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_rmul() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.saturating_rmul(b, Floor), expected);
+            assert_eq!(b.saturating_rmul(a, Floor), expected);
+            assert_eq!(a.cneg()?.saturating_rmul(b, Floor), expected.cneg()?);
+            assert_eq!(a.saturating_rmul(b.cneg()?, Floor), expected.cneg()?);
+            assert_eq!(a.cneg()?.saturating_rmul(b.cneg()?, Floor), expected);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(0), fp!(3000.0000006), fp!(0));
+            (fp!(1), fp!(3000.0000006), fp!(3000.0000006));
+            (fp!(-1000.0000002), fp!(0), fp!(0));
+            (fp!(-1000.0000002), fp!(3), fp!(-3000.0000006));
+            (fp!(-1000.0000002), fp!(-4), fp!(4000.0000008));
+            (fp!(68601.48179), fp!(-468.28), fp!(-32124701.8926212));
+        },
+        fp128 {
+            (fp!(0), fp!(3000000000000.0000000000000006), fp!(0));
+            (fp!(1), fp!(3000000000000.0000000000000006), fp!(3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(0));
+            (fp!(-1000000000000.0000000000000002), fp!(3), fp!(-3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), fp!(-4), fp!(4000000000000.0000000000000008));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, mode | RoundMode, expected | FixedPoint) => {
+            assert_eq!(a.saturating_rmul(b, mode), expected);
+        },
+        fp64 {
+            (fp!(0.000000001), fp!(-0.1), Floor, fp!(-0.000000001));
+            (fp!(0.000000001), fp!(0.1), Ceil, fp!(0.000000001));
+            (fp!(0.000000001), fp!(0.1), Floor, fp!(0));
+            (fp!(-0.000000001), fp!(0.1), Ceil, fp!(0));
+            (fp!(9222222222), fp!(9222222222), Floor, FixedPoint::MAX);
+            (fp!(4611686019), fp!(4611686018.427387903), Floor, FixedPoint::MAX);
+            (fp!(-9222222222), fp!(9222222222), Floor, FixedPoint::MIN);
+            (fp!(4611686019), fp!(-4611686018.427387903), Floor, FixedPoint::MIN);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), fp!(0.1), Floor, fp!(0));
+            (fp!(0.000000000000000001), fp!(-0.1), Floor, fp!(-0.000000000000000001));
+            (fp!(0.000000000000000001), fp!(0.1), Ceil, fp!(0.000000000000000001));
+            (fp!(-0.000000000000000001), fp!(0.1), Ceil, fp!(0));
+            (fp!(85550005550005550005), fp!(85550005550005550005), Floor, FixedPoint::MAX);
+            (fp!(4611686019), fp!(4611686018000000000.000000000427387903), Floor, FixedPoint::MAX);
+            (fp!(-85550005550005550005), fp!(85550005550005550005), Floor, FixedPoint::MIN);
+            (fp!(4611686019), fp!(-4611686018000000000.000000000427387903), Floor, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_rdiv() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, mode | RoundMode, expected | FixedPoint) => {
+            assert_eq!(a.saturating_rdiv(b, mode), expected);
+        },
+        all {
+            (fp!(1), fp!(3), Floor, FixedPoint::ONE.rdiv(fp!(3), Floor)?);
+            (fp!(1), fp!(3), Ceil, FixedPoint::ONE.rdiv(fp!(3), Ceil)?);
+            (fp!(-7.5), fp!(2.5), Floor, fp!(-3));
+            (fp!(0), fp!(-2), Ceil, fp!(0));
+            (FixedPoint::MAX, fp!(0.1), Floor, FixedPoint::MAX);
+            (FixedPoint::MAX, fp!(-0.1), Floor, FixedPoint::MIN);
+            (FixedPoint::MIN, fp!(0.1), Ceil, FixedPoint::MIN);
+            (FixedPoint::MIN, fp!(-0.1), Ceil, FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::EPSILON.cneg()?, Floor, FixedPoint::MAX);
+            (fp!(1), fp!(0), Floor, FixedPoint::MAX);
+            (FixedPoint::EPSILON.cneg()?, fp!(0), Ceil, FixedPoint::MIN);
+            (fp!(0), fp!(0), Floor, fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn overflowing_ops() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, sum | (FixedPoint, bool), difference | (FixedPoint, bool)) => {
+            assert_eq!(a.overflowing_add(b), sum);
+            assert_eq!(a.overflowing_sub(b), difference);
+            assert_eq!(a.cadd(b).is_err(), sum.1);
+            assert_eq!(a.csub(b).is_err(), difference.1);
+        },
+        all {
+            (fp!(1.5), fp!(-2), (fp!(-0.5), false), (fp!(3.5), false));
+            (FixedPoint::MAX, FixedPoint::EPSILON, (FixedPoint::MIN, true), (FixedPoint::MAX.csub(FixedPoint::EPSILON)?, false));
+            (FixedPoint::MIN, FixedPoint::EPSILON, (FixedPoint::MIN.cadd(FixedPoint::EPSILON)?, false), (FixedPoint::MAX, true));
+            (FixedPoint::MAX, FixedPoint::MAX, (FixedPoint::from_bits(-2), true), (FixedPoint::ZERO, false));
+            (FixedPoint::MIN, FixedPoint::MIN, (FixedPoint::ZERO, true), (FixedPoint::ZERO, false));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, mode | RoundMode, product | (FixedPoint, bool), quotient | (FixedPoint, bool)) => {
+            assert_eq!(a.overflowing_rmul(b, mode), product);
+            assert_eq!(a.overflowing_rdiv(b, mode), quotient);
+        },
+        all {
+            (fp!(1.5), fp!(-2), Floor, (fp!(-3), false), (fp!(-0.75), false));
+            (fp!(1), fp!(3), Ceil, (fp!(3), false), (FixedPoint::ONE.rdiv(fp!(3), Ceil)?, false));
+            (FixedPoint::MAX, fp!(2), Floor, (FixedPoint::MAX, true), (FixedPoint::MAX.rdiv(fp!(2), Floor)?, false));
+            (FixedPoint::MAX, fp!(-0.5), Floor, (FixedPoint::MAX.rmul(fp!(-0.5), Floor)?, false), (FixedPoint::MIN, true));
+            (FixedPoint::MIN, FixedPoint::EPSILON.cneg()?, Floor, (FixedPoint::ZERO.csub(FixedPoint::MIN.rmul(FixedPoint::EPSILON, Ceil)?)?, false), (FixedPoint::MAX, true));
+            (fp!(-1), fp!(0), Floor, (fp!(0), false), (FixedPoint::MIN, true));
+            (fp!(0), fp!(0), Floor, (fp!(0), false), (fp!(0), true));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_sub() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.saturating_sub(b), expected);
+            assert_eq!(b.saturating_sub(a), expected.cneg()?);
+            assert_eq!(a.cneg()?.saturating_sub(b.cneg()?), expected.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(0), fp!(3000.0000006), fp!(-3000.0000006));
+            (fp!(-1000.0000002), fp!(0), fp!(-1000.0000002));
+            (fp!(-1000.0000002), fp!(3000.0000006), fp!(-4000.0000008));
+            (fp!(-1000.0000002), fp!(-3000.0000006), fp!(2000.0000004));
+            (fp!(4611686018.427387903), fp!(-4611686018.427387903), fp!(9223372036.854775806));
+        },
+        fp128 {
+            (fp!(0), fp!(3000000000000.0000000000000006), fp!(-3000000000000.0000000000000006));
+            (fp!(-1000000000000.0000000000000002), fp!(0), fp!(-1000000000000.0000000000000002));
+            (fp!(-1000000000000.0000000000000002), fp!(3000000000000.0000000000000006), fp!(-4000000000000.0000000000000008));
+            (fp!(-1000000000000.0000000000000002), fp!(-3000000000000.0000000000000006), fp!(2000000000000.0000000000000004));
+            (fp!(4611686018000000000.000000000427387903), fp!(-4611686018000000000.000000000427387903), fp!(9223372036000000000.000000000854775806));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(a.saturating_sub(b), expected);
+        },
+        fp64 {
+            (fp!(9222222222), fp!(-9222222222), FixedPoint::MAX);
+            (fp!(4611686019), fp!(-4611686018.27387903), FixedPoint::MAX);
+            (fp!(-9222222222), fp!(9222222222), FixedPoint::MIN);
+            (fp!(-4611686019), fp!(4611686018.47387903), FixedPoint::MIN);
+        },
+        fp128 {
+            (fp!(85550005550005550005), fp!(-85550005550005550005), FixedPoint::MAX);
+            (fp!(85550005550005550005), fp!(-85550005550005550005.000000000427387903), FixedPoint::MAX);
+            (fp!(-85550005550005550005), fp!(85550005550005550005), FixedPoint::MIN);
+            (fp!(-85550005550005550005), fp!(85550005550005550005.000000000427387903), FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sqrt_exact() -> Result<()> {
+    test_fixed_point! {
+        case (expected | FixedPoint) => {
+            let square = expected.rmul(expected, Floor)?;
+            assert_eq!(expected.rmul(expected, Ceil)?, square);
+            assert_eq!(square.rsqrt(Floor)?, expected);
+            assert_eq!(square.rsqrt(Ceil)?, expected);
+        },
+        all {
+            (fp!(0));
+            (fp!(1));
+            (fp!(2));
+            (fp!(3));
+            (fp!(1000));
+            (fp!(96038));
+            (FixedPoint::MAX.rsqrt(Floor)?.integral(Floor).try_into()?);
+        },
+        fp128 {
+            (fp!(10431725));
+            (fp!(13043817825));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sqrt_approx() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected_floor | FixedPoint) => {
+            assert_eq!(x.rsqrt(Floor)?, expected_floor);
+            assert_eq!(x.rsqrt(Ceil)?.inner, expected_floor.inner + 1);
+        },
+        fp64 {
+            (fp!(2), fp!(1.414213562));
+            (FixedPoint::MAX, fp!(96038.388349944));
+        },
+        fp128 {
+            (fp!(2), fp!(1.414213562373095048));
+            (fp!(3.14159265358979323), fp!(1.772453850905516024));
+            (fp!(5), fp!(2.236067977499789696));
+            (FixedPoint::MAX, fp!(13043817825.332782212349571806));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sqrt_nearest() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint, expected | FixedPoint) => {
+            assert_eq!(x.rsqrt(Nearest)?, expected);
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(4), fp!(2));
+        },
+        fp64 {
+            (fp!(2), fp!(1.414213562));
+            (fp!(3), fp!(1.732050808));
+        },
+        fp128 {
+            (fp!(2), fp!(1.414213562373095049));
+            (fp!(3), fp!(1.732050807568877294));
+            (fp!(5), fp!(2.236067977499789696));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i16", feature = "i32"))]
+fn nearest_narrow_layouts() -> Result<()> {
+    use typenum::{U2, U4};
+
+    type Fp16 = crate::FixedPoint<i16, U2>;
+    type Fp32 = crate::FixedPoint<i32, U4>;
+
+    let a: Fp16 = "0.5".parse()?;
+    let b: Fp16 = "0.01".parse()?;
+    assert_eq!(a.rmul(b, Nearest)?, b);
+    assert_eq!(a.cneg()?.rmul(b, Nearest)?, b.cneg()?);
+    assert_eq!(b.rdiv(2, Nearest)?, b);
+    assert_eq!("-2.5".parse::<Fp16>()?.integral(Nearest), -3);
+    assert_eq!("3".parse::<Fp16>()?.rsqrt(Nearest)?, "1.73".parse()?);
+
+    let a: Fp32 = "0.5".parse()?;
+    let b: Fp32 = "0.0001".parse()?;
+    assert_eq!(a.rmul(b, Nearest)?, b);
+    assert_eq!(a.cneg()?.rmul(b, Nearest)?, b.cneg()?);
+    assert_eq!(b.rdiv(2, Nearest)?, b);
+    assert_eq!("-2.5".parse::<Fp32>()?.integral(Nearest), -3);
+    assert_eq!("3".parse::<Fp32>()?.rsqrt(Nearest)?, "1.7321".parse()?);
+    Ok(())
+}
+
+#[test]
+fn nearest_other_entry_points() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(1250).round_to_pow10(2, Nearest)?, fp!(1300));
+            assert_eq!(fp!(1249.99).round_to_pow10(2, Nearest)?, fp!(1200));
+            assert_eq!(fp!(1250).cneg()?.round_to_pow10(2, Nearest)?, fp!(1300).cneg()?);
+            assert_eq!(FixedPoint::MAX.round_to_pow10(40, Nearest)?, FixedPoint::ZERO);
+            assert_eq!(
+                FixedPoint::inverse_lerp(fp!(0), fp!(3), fp!(2), Nearest)?,
+                FixedPoint::inverse_lerp(fp!(0), fp!(3), fp!(2), Ceil)?,
+            );
+        },
+    };
+    test_fixed_point! {
+        case (values | [FixedPoint; 2], expected | FixedPoint) => {
+            assert_eq!(FixedPoint::rms(&values, Nearest)?, expected);
+        },
+        fp64 {
+            ([fp!(1), fp!(2)], fp!(1.58113883));
+            ([fp!(3), fp!(4)], fp!(3.535533906));
+        },
+        fp128 {
+            ([fp!(1), fp!(2)], fp!(1.581138830084189666));
+            ([fp!(3), fp!(4)], fp!(3.535533905932737622));
+        },
+    };
+    test_fixed_point! {
+        case (value | i128, scale | u32, expected | FixedPoint) => {
+            assert_eq!(FixedPoint::from_scaled_int(value, scale, Nearest)?, expected);
+            assert_eq!(FixedPoint::from_scaled_int(-value, scale, Nearest)?, expected.cneg()?);
+        },
+        fp64 {
+            (15, 10, fp!(0.000000002));
+            (14, 10, fp!(0.000000001));
+        },
+        fp128 {
+            (15, 19, fp!(0.000000000000000002));
+            (14, 19, fp!(0.000000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn directed_rounding() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, toward_zero | FixedPoint, away_from_zero | FixedPoint) => {
+            assert_eq!(a.rmul(b, TowardZero)?, toward_zero);
+            assert_eq!(a.rmul(b, AwayFromZero)?, away_from_zero);
+            // Negative operands are rounded symmetrically.
+            assert_eq!(a.cneg()?.rmul(b, TowardZero)?, toward_zero.cneg()?);
+            assert_eq!(a.cneg()?.rmul(b, AwayFromZero)?, away_from_zero.cneg()?);
+        },
+        all {
+            (fp!(1.5), fp!(2), fp!(3), fp!(3));
+        },
+        fp64 {
+            (fp!(0.1), fp!(0.000000001), fp!(0), fp!(0.000000001));
+            (fp!(0.9), fp!(0.000000001), fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(0.1), fp!(0.000000000000000001), fp!(0), fp!(0.000000000000000001));
+            (fp!(0.9), fp!(0.000000000000000001), fp!(0), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, toward_zero | FixedPoint, away_from_zero | FixedPoint) => {
+            assert_eq!(a.rdiv(b, TowardZero)?, toward_zero);
+            assert_eq!(a.rdiv(b, AwayFromZero)?, away_from_zero);
+            assert_eq!(a.cneg()?.rdiv(b, TowardZero)?, toward_zero.cneg()?);
+            assert_eq!(a.cneg()?.rdiv(b, AwayFromZero)?, away_from_zero.cneg()?);
+            assert_eq!(a.rdiv(b.cneg()?, TowardZero)?, toward_zero.cneg()?);
+            assert_eq!(a.rdiv(b.cneg()?, AwayFromZero)?, away_from_zero.cneg()?);
+        },
+        all {
+            (fp!(3), fp!(2), fp!(1.5), fp!(1.5));
+        },
+        fp64 {
+            (fp!(100), fp!(3), fp!(33.333333333), fp!(33.333333334));
+        },
+        fp128 {
+            (fp!(100), fp!(3), fp!(33.333333333333333333), fp!(33.333333333333333334));
+        },
+    };
+    test_fixed_point! {
+        case (a | FixedPoint, toward_zero | Layout, away_from_zero | Layout) => {
+            assert_eq!(a.integral(TowardZero), toward_zero);
+            assert_eq!(a.integral(AwayFromZero), away_from_zero);
+            assert_eq!(a.cneg()?.integral(TowardZero), -toward_zero);
+            assert_eq!(a.cneg()?.integral(AwayFromZero), -away_from_zero);
+        },
+        all {
+            (fp!(0), 0, 0);
+            (fp!(2), 2, 2);
+            (fp!(2.0001), 2, 3);
+            (fp!(2.9999), 2, 3);
+        },
+    };
+    test_fixed_point! {
+        case (x | FixedPoint, toward_zero | FixedPoint, away_from_zero | FixedPoint) => {
+            assert_eq!(x.rsqrt(TowardZero)?, toward_zero);
+            assert_eq!(x.rsqrt(AwayFromZero)?, away_from_zero);
+        },
+        all {
+            (fp!(4), fp!(2), fp!(2));
+        },
+        fp64 {
+            (fp!(2), fp!(1.414213562), fp!(1.414213563));
+        },
+        fp128 {
+            (fp!(2), fp!(1.414213562373095048), fp!(1.414213562373095049));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(1234.5).round_to_pow10(2, TowardZero)?, fp!(1200));
+            assert_eq!(fp!(1234.5).round_to_pow10(2, AwayFromZero)?, fp!(1300));
+            assert_eq!(fp!(1234.5).cneg()?.round_to_pow10(2, TowardZero)?, fp!(1200).cneg()?);
+            assert_eq!(fp!(1234.5).cneg()?.round_to_pow10(2, AwayFromZero)?, fp!(1300).cneg()?);
+            assert_eq!(FixedPoint::MIN.round_to_pow10(40, TowardZero)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::MIN.round_to_pow10(40, AwayFromZero), Err(ArithmeticError::Overflow));
+
+            let epsilon = FixedPoint::EPSILON;
+            assert_eq!(FixedPoint::from_scaled_int(-1, 60, TowardZero)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::from_scaled_int(-1, 60, AwayFromZero)?, epsilon.cneg()?);
+            assert_eq!(FixedPoint::from_scaled_int(1, 60, AwayFromZero)?, epsilon);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sqrt_negative() -> Result<()> {
+    test_fixed_point! {
+        case (x | FixedPoint) => {
+            let expected = Err(ArithmeticError::DomainViolation);
+            assert_eq!(x.rsqrt(Floor), expected);
+            assert_eq!(x.rsqrt(Ceil), expected);
+        },
         all {
             (fp!(-1));
             (FixedPoint::EPSILON.cneg()?);
@@ -1031,10 +5208,877 @@ fn sqrt_negative() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn running_extrema() -> Result<()> {
+    test_fixed_point! {
+        case (series | [FixedPoint; 5], expected | [(FixedPoint, FixedPoint); 5]) => {
+            let mut extrema = crate::iter::running_extrema(series);
+            for (i, expected) in expected.iter().enumerate() {
+                assert_eq!(extrema.next(), Some(*expected), "position {}", i);
+                assert_eq!(extrema.running_min(), Some(expected.0));
+                assert_eq!(extrema.running_max(), Some(expected.1));
+            }
+            assert_eq!(extrema.next(), None);
+        },
+        all {
+            (
+                [fp!(1.5), fp!(-2), fp!(0.1), fp!(3), fp!(-2.000000001)],
+                [
+                    (fp!(1.5), fp!(1.5)),
+                    (fp!(-2), fp!(1.5)),
+                    (fp!(-2), fp!(1.5)),
+                    (fp!(-2), fp!(3)),
+                    (fp!(-2.000000001), fp!(3)),
+                ]
+            );
+            (
+                [FixedPoint::ZERO, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::EPSILON, FixedPoint::ZERO],
+                [
+                    (FixedPoint::ZERO, FixedPoint::ZERO),
+                    (FixedPoint::ZERO, FixedPoint::MAX),
+                    (FixedPoint::MIN, FixedPoint::MAX),
+                    (FixedPoint::MIN, FixedPoint::MAX),
+                    (FixedPoint::MIN, FixedPoint::MAX),
+                ]
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn running_extrema_empty() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let mut extrema = crate::iter::running_extrema(core::iter::empty::<FixedPoint>());
+            assert_eq!(extrema.next(), None);
+            assert_eq!(extrema.running_min(), None);
+            assert_eq!(extrema.running_max(), None);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn dot() -> Result<()> {
+    test_fixed_point! {
+        case (xs | &[FixedPoint], ys | &[FixedPoint], mode | RoundMode, expected | Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(crate::ops::dot(xs, ys, mode), expected);
+        },
+        all {
+            (&[], &[], Floor, Ok(FixedPoint::ZERO));
+            (&[fp!(1.5), fp!(-2)], &[fp!(2), fp!(0.25)], Floor, Ok(fp!(2.5)));
+            (&[fp!(0.5), fp!(0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON], Floor, Ok(FixedPoint::EPSILON));
+            (&[fp!(0.5), fp!(0.5), fp!(0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON], Floor, Ok(FixedPoint::EPSILON));
+            (&[fp!(0.5), fp!(0.5), fp!(0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON], Ceil, Ok(FixedPoint::from_bits(2)));
+            (&[fp!(0.5), fp!(0.5), fp!(0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON], Nearest, Ok(FixedPoint::from_bits(2)));
+            (&[fp!(-0.5), fp!(-0.5), fp!(-0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON], Floor, Ok(FixedPoint::from_bits(-2)));
+            (&[fp!(-0.5), fp!(-0.5), fp!(-0.5)], &[FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON], TowardZero, Ok(FixedPoint::EPSILON.cneg()?));
+            (&[FixedPoint::MAX, FixedPoint::MIN], &[fp!(1), fp!(1)], Floor, Ok(FixedPoint::EPSILON.cneg()?));
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(1), fp!(-1)], Floor, Ok(FixedPoint::ZERO));
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(1), fp!(1)], Floor, Err(ArithmeticError::Overflow));
+            // Terms are out of range, but the sum isn't.
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[FixedPoint::MAX, FixedPoint::MIN], Floor, Ok(FixedPoint::MAX.rmul(FixedPoint::EPSILON.cneg()?, Floor)?));
+            (&[fp!(1), fp!(1)], &[fp!(1)], Floor, Err(ArithmeticError::DomainViolation));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn try_sum_product() -> Result<()> {
+    use crate::{TryProduct, TrySum};
+
+    test_fixed_point! {
+        case (values | [FixedPoint; 3], mode | RoundMode, sum | Result<FixedPoint, ArithmeticError>, product | Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(values.iter().copied().try_sum::<FixedPoint>(), sum);
+            assert_eq!(values.iter().copied().try_product::<FixedPoint>(mode), product);
+        },
+        all {
+            ([fp!(1.5), fp!(2), fp!(3)], Floor, Ok(fp!(6.5)), Ok(fp!(9)));
+            ([fp!(1.5), fp!(-2), fp!(0.1)], Ceil, Ok(fp!(-0.4)), Ok(fp!(-0.3)));
+            ([fp!(0.5), fp!(0.5), FixedPoint::EPSILON], Floor, Ok(fp!(1).cadd(FixedPoint::EPSILON)?), Ok(FixedPoint::ZERO));
+            ([fp!(0.5), fp!(0.5), FixedPoint::EPSILON], Ceil, Ok(fp!(1).cadd(FixedPoint::EPSILON)?), Ok(FixedPoint::EPSILON));
+            ([FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MIN], Floor, Err(ArithmeticError::Overflow), Err(ArithmeticError::Overflow));
+            ([FixedPoint::MAX, FixedPoint::MIN, FixedPoint::ZERO], Floor, Ok(FixedPoint::EPSILON.cneg()?), Err(ArithmeticError::Overflow));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(core::iter::empty::<FixedPoint>().try_sum::<FixedPoint>(), Ok(FixedPoint::ZERO));
+            assert_eq!(core::iter::empty::<FixedPoint>().try_product::<FixedPoint>(Floor), Ok(FixedPoint::ONE));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn round_preserving_sum() -> Result<()> {
+    test_fixed_point! {
+        case (values | &[FixedPoint], digits | u32, mode | RoundMode, expected | &[FixedPoint], total | FixedPoint) => {
+            let rounded = FixedPoint::round_preserving_sum(values, digits, mode)?;
+            assert_eq!(rounded, expected);
+
+            let sum = rounded.iter().try_fold(FixedPoint::ZERO, |acc, v| acc.cadd(*v))?;
+            assert_eq!(sum, total);
+        },
+        all {
+            // Naive flooring gives 99.99.
+            (
+                &[fp!(33.333333333), fp!(33.333333333), fp!(33.333333334)], 2, Floor,
+                &[fp!(33.33), fp!(33.33), fp!(33.34)], fp!(100)
+            );
+            // Naive ceiling gives 100.02.
+            (
+                &[fp!(33.333333333), fp!(33.333333333), fp!(33.333333334)], 2, Ceil,
+                &[fp!(33.33), fp!(33.33), fp!(33.34)], fp!(100)
+            );
+            // Naive flooring gives 0.9, ties are broken by position.
+            (
+                &[fp!(0.15), fp!(0.15), fp!(0.7)], 1, Floor,
+                &[fp!(0.2), fp!(0.1), fp!(0.7)], fp!(1)
+            );
+            (
+                &[fp!(-0.15), fp!(-0.15), fp!(1.3)], 1, Floor,
+                &[fp!(-0.1), fp!(-0.2), fp!(1.3)], fp!(1)
+            );
+            (
+                &[fp!(0.25), fp!(0.25)], 0, Ceil,
+                &[fp!(1), fp!(0)], fp!(1)
+            );
+            (
+                &[fp!(0.25), fp!(0.25)], 0, Floor,
+                &[fp!(0), fp!(0)], fp!(0)
+            );
+            (&[fp!(1.123456789)], 9, Floor, &[fp!(1.123456789)], fp!(1.123456789));
+            (&[], 2, Floor, &[], fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn amortize() -> Result<()> {
+    test_fixed_point! {
+        case (principal | FixedPoint, periods | u32, mode | RoundMode, payment | FixedPoint, last | FixedPoint) => {
+            let payments = principal.amortize(periods, mode)?;
+            assert_eq!(payments.len(), periods as usize);
+            assert!(payments[..payments.len() - 1].iter().all(|p| *p == payment));
+            assert_eq!(payments[payments.len() - 1], last);
+
+            let sum = payments.iter().try_fold(FixedPoint::ZERO, |acc, p| acc.cadd(*p))?;
+            assert_eq!(sum, principal);
+        },
+        all {
+            (fp!(100), 4, Floor, fp!(25), fp!(25));
+            (fp!(100), 1, Ceil, fp!(100), fp!(100));
+            (fp!(0), 5, Ceil, fp!(0), fp!(0));
+            (FixedPoint::EPSILON, 3, Ceil, fp!(0), FixedPoint::EPSILON);
+            (FixedPoint::EPSILON, 3, AwayFromZero, fp!(0), FixedPoint::EPSILON);
+            (FixedPoint::EPSILON.cneg()?, 3, Floor, fp!(0), FixedPoint::EPSILON.cneg()?);
+        },
+        fp64 {
+            (fp!(0.000000002), 3, Ceil, fp!(0.000000001), fp!(0));
+            (fp!(0.000000005), 3, Ceil, fp!(0.000000002), fp!(0.000000001));
+            (fp!(100), 3, Floor, fp!(33.333333333), fp!(33.333333334));
+            (fp!(100), 3, Ceil, fp!(33.333333334), fp!(33.333333332));
+            (fp!(-100), 3, Floor, fp!(-33.333333334), fp!(-33.333333332));
+            (fp!(-100), 3, Ceil, fp!(-33.333333333), fp!(-33.333333334));
+            (fp!(0.000000002), 3, Floor, fp!(0), fp!(0.000000002));
+        },
+        fp128 {
+            (fp!(100), 3, Floor, fp!(33.333333333333333333), fp!(33.333333333333333334));
+            (fp!(-100), 3, Ceil, fp!(-33.333333333333333333), fp!(-33.333333333333333334));
+            (fp!(0.000000000000000002), 3, Floor, fp!(0), fp!(0.000000000000000002));
+        },
+    };
+
+    #[cfg(feature = "u64")]
+    {
+        type Amount = crate::FixedPoint<u64, typenum::U9>;
+
+        let epsilon = Amount::EPSILON.amortize(3, Ceil)?;
+        assert_eq!(epsilon, [Amount::ZERO, Amount::ZERO, Amount::EPSILON]);
+        let payments = "100".parse::<Amount>()?.amortize(3, Ceil)?;
+        let payment: Amount = "33.333333334".parse()?;
+        assert_eq!(payments, [payment, payment, "33.333333332".parse()?]);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn amortize_zero_periods() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(100).amortize(0, Floor), Err(ArithmeticError::DivisionByZero));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn geometric_sequence() -> Result<()> {
+    test_fixed_point! {
+        case (start | FixedPoint, ratio | FixedPoint, mode | RoundMode) => {
+            let sequence = FixedPoint::geometric_sequence(start, ratio, 4, mode)?;
+            let mut expected = start;
+            assert_eq!(sequence.len(), 4);
+            for level in sequence {
+                assert_eq!(level, expected);
+                expected = expected.rmul(ratio, mode)?;
+            }
+            assert!(FixedPoint::geometric_sequence(start, ratio, 0, mode)?.is_empty());
+            assert_eq!(FixedPoint::geometric_sequence(start, ratio, 1, mode)?, [start]);
+        },
+        all {
+            (fp!(100), fp!(1.1), Floor);
+            (fp!(100), fp!(0.9), Ceil);
+            (fp!(-1), fp!(-2), Floor);
+            (fp!(3), fp!(0.333333333), Floor);
+            (fp!(3), fp!(0.333333333), Ceil);
+            (fp!(0), fp!(10), Ceil);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let ladder = FixedPoint::geometric_sequence(fp!(100), fp!(1.1), 3, Floor)?;
+            assert_eq!(ladder, [fp!(100), fp!(110), fp!(121)]);
+            assert_eq!(
+                FixedPoint::geometric_sequence(fp!(1), FixedPoint::MAX, 3, Floor),
+                Err(ArithmeticError::Overflow),
+            );
+            assert!(FixedPoint::geometric_sequence(FixedPoint::MAX, fp!(2), 1, Floor).is_ok());
+            assert!(FixedPoint::geometric_sequence(FixedPoint::MAX, fp!(2), 2, Floor).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn assert_fixnum_eq() -> Result<()> {
+    test_fixed_point! {
+        case (a | FixedPoint, b | FixedPoint, tolerance | FixedPoint) => {
+            crate::assert_fixnum_eq!(a, b, tolerance);
+            crate::assert_fixnum_eq!(b, a, tolerance);
+            crate::assert_fixnum_eq!(a, a);
+        },
+        all {
+            (fp!(1), fp!(1), fp!(0));
+            (fp!(1), fp!(1.000000001), fp!(0.000000001));
+            (fp!(-1), fp!(1), fp!(2));
+            (FixedPoint::MIN, FixedPoint::MIN, fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "i64"))]
+fn assert_fixnum_eq_message() {
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    let a: Fp64 = "1.5".parse().unwrap();
+    let b: Fp64 = "1.500000003".parse().unwrap();
+    let message = std::panic::catch_unwind(|| crate::assert_fixnum_eq!(a, b, Fp64::EPSILON))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+
+    assert!(message.contains("tolerance: 0.000000001"));
+    assert!(message.contains("left: 1.5 (bits: 1500000000)"));
+    assert!(message.contains("right: 1.500000003 (bits: 1500000003)"));
+    assert!(message.contains("diff: 3 ULP"));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "i64"))]
+#[should_panic(expected = "diff: 1 ULP")]
+fn assert_fixnum_eq_fails() {
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    crate::assert_fixnum_eq!(Fp64::ONE, Fp64::ONE.cadd(Fp64::EPSILON).unwrap());
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn precision_macro() -> Result<()> {
+    use crate::precision;
+
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    fn same_type(x: Fp64) -> crate::FixedPoint<i64, precision!(9)> {
+        x
+    }
+
+    let x: crate::FixedPoint<i64, precision!(9)> = "1.000000001".parse()?;
+    assert_eq!(same_type(x), "1.000000001".parse::<Fp64>()?);
+    assert_eq!(crate::FixedPoint::<i64, precision!(9)>::PRECISION, 9);
+    assert_eq!(crate::FixedPoint::<i64, precision!(0)>::PRECISION, 0);
+    assert_eq!(crate::FixedPoint::<i64, precision!(18)>::PRECISION, 18);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn define_fixed_wrapper() -> Result<()> {
+    type Fp64 = crate::FixedPoint<i64, typenum::U9>;
+
+    crate::define_fixed_wrapper! {
+        /// Price of a single item.
+        pub(crate) struct Price(Fp64);
+    }
+    crate::define_fixed_wrapper! {
+        #[derive(Hash)]
+        pub(crate) struct Ratio(pub(crate) Fp64);
+    }
+
+    crate::impl_op!(Price [cadd] Price = Price);
+    crate::impl_op!(Price [rmul] Ratio = Price);
+    crate::impl_op!(Price [rdiv] Price = Ratio);
+    crate::impl_op!(Price [rrem] Price = Price);
+
+    let price = Price::from("1.5".parse::<Fp64>()?);
+    let ratio = Ratio("0.5".parse()?);
+    assert_eq!(Price::default(), Price::ZERO);
+    assert_eq!(Fp64::from(Price::ONE), Fp64::ONE);
+    assert_eq!(Ratio::MAX.0, Fp64::MAX);
+    assert_eq!(Ratio::MIN.0, Fp64::MIN);
+    assert!(Price::MIN < price && price < Price::MAX);
+
+    assert_eq!(price.cadd(Price::ONE)?, Price("2.5".parse()?));
+    assert_eq!(price.rmul(ratio, Floor)?, Price("0.75".parse()?));
+    assert_eq!(price.rdiv(Price::ONE, Floor)?, Ratio("1.5".parse()?));
+    assert_eq!(price.rrem(Price::ONE, Floor)?, Price("0.5".parse()?));
+    assert_eq!(Price::MAX.cadd(Price::ONE), Err(ArithmeticError::Overflow));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "u64", feature = "std"))]
+fn unsigned_u64() -> Result<()> {
+    type Amount = crate::FixedPoint<u64, typenum::U9>;
+
+    let a: Amount = "1.5".parse()?;
+    let b: Amount = "0.000000001".parse()?;
+    assert_eq!(Amount::MAX.into_bits(), u64::MAX);
+    assert_eq!(Amount::MAX.to_string(), "18446744073.709551615");
+    assert_eq!(Amount::MIN, Amount::ZERO);
+    assert_eq!(a.to_string(), "1.5");
+    assert_eq!(format!("{:#}", a), "1.500000000");
+    assert_eq!("18446744073.709551615".parse::<Amount>()?, Amount::MAX);
+    assert!("18446744073.709551616".parse::<Amount>().is_err());
+    assert!("-1".parse::<Amount>().is_err());
+    assert!("-0.5".parse::<Amount>().is_err());
+    assert!("1.0000000001".parse::<Amount>().is_err());
+    assert_eq!(Amount::try_from(3u8)?, "3".parse()?);
+    assert!(Amount::try_from(-3i64).is_err());
+    assert_eq!(Amount::HALF, "0.5".parse()?);
+    assert_eq!(f64::from(a), 1.5);
+    assert_eq!(Amount::try_from(1.5f32)?, a);
+    assert!(Amount::try_from(-1.5f32).is_err());
+    assert_eq!(Amount::try_from(-0.0f64)?, Amount::ZERO);
+    assert_eq!("+.5".parse::<Amount>()?, Amount::HALF);
+    assert_eq!("1_0".parse::<Amount>()?, "10".parse()?);
+    assert_eq!("1e2".parse::<Amount>()?, "100".parse()?);
+    assert_eq!("-0".parse::<Amount>()?, Amount::ZERO);
+    assert!("-1e2".parse::<Amount>().is_err());
+    assert_eq!(format!("{:>6}", a), "   1.5");
+    assert_eq!(format!("{:<6}|", a), "1.5   |");
+    assert_eq!(format!("{:+}", a), "+1.5");
+    assert_eq!(format!("{:06.2}", a), "001.50");
+    assert_eq!(format!("{:e}", a), "1.5e0");
+    assert_eq!(format!("{:E}", Amount::MAX), "1.8446744073709551615E10");
+
+    assert_eq!(a.cadd(a)?, "3".parse()?);
+    assert_eq!(Amount::MAX.cadd(b), Err(ArithmeticError::Overflow));
+    assert_eq!(a.csub(b)?, "1.499999999".parse()?);
+    assert_eq!(b.csub(a), Err(ArithmeticError::Overflow));
+    assert_eq!(b.saturating_sub(a), Amount::ZERO);
+    assert_eq!(a.cmul(3)?, "4.5".parse()?);
+    assert_eq!(3.cmul(a)?, "4.5".parse()?);
+    assert_eq!(Amount::MAX.cmul(2), Err(ArithmeticError::Overflow));
+
+    assert_eq!(a.rmul(a, Floor)?, "2.25".parse()?);
+    assert_eq!(b.rmul(a, Floor)?, b);
+    assert_eq!(b.rmul(a, Nearest)?, "0.000000002".parse()?);
+    assert_eq!(b.rmul(a, Ceil)?, "0.000000002".parse()?);
+    assert_eq!(b.rmul(a, TowardZero)?, b);
+    assert_eq!(Amount::MAX.rmul(a, Floor), Err(ArithmeticError::Overflow));
+    // The product is out of `i64`, but fits `u64`.
+    let big: Amount = "10000000000".parse()?;
+    assert_eq!(big.rmul(a, Floor)?, "15000000000".parse()?);
+    assert_eq!(Amount::MAX.saturating_rmul(a, Floor), Amount::MAX);
+
+    let third = Amount::ONE.rdiv(Amount::from_bits(3_000_000_000), Floor)?;
+    assert_eq!(third, "0.333333333".parse()?);
+    assert_eq!(
+        Amount::ONE.rdiv("3".parse::<Amount>()?, Ceil)?,
+        "0.333333334".parse()?
+    );
+    assert_eq!(
+        "2".parse::<Amount>()?
+            .rdiv("3".parse::<Amount>()?, Nearest)?,
+        "0.666666667".parse()?
+    );
+    assert_eq!(
+        a.rdiv(Amount::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(
+        Amount::MAX.rdiv(b.cmul(10)?, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(a.rdiv(4, Floor)?, "0.375".parse()?);
+    assert_eq!(b.rdiv(2, Nearest)?, b);
+    assert_eq!(1.rdiv("3".parse::<Amount>()?, Floor)?, third);
+
+    assert_eq!("2".parse::<Amount>()?.rsqrt(Floor)?, "1.414213562".parse()?);
+    assert_eq!("2".parse::<Amount>()?.rsqrt(Ceil)?, "1.414213563".parse()?);
+    assert_eq!(Amount::MAX.rsqrt(Floor)?, "135818.791312945".parse()?);
+
+    let c: Amount = "8273.5".parse()?;
+    assert_eq!(c.integral(Floor), 8273);
+    assert_eq!(c.integral(Nearest), 8274);
+    assert_eq!(c.integral(Ceil), 8274);
+    assert_eq!(Amount::MAX.integral(Ceil), 18_446_744_074);
+
+    let (quotient, remainder) = c.rdiv_rem(a, Floor)?;
+    assert_eq!((quotient, remainder), ("5515.666666666".parse()?, b));
+    assert_eq!(c.rdiv_rem(a, Ceil), Err(ArithmeticError::Overflow));
+    assert_eq!(big.mul_div(a, "3".parse()?, Floor)?, "5000000000".parse()?);
+    assert_eq!(a.cpowi(3, Floor)?, "3.375".parse()?);
+    assert_eq!(Amount::ONE.rexp(Floor)?, "2.718281828".parse()?);
+    assert_eq!(a.rln(Floor)?, "0.405465108".parse()?);
+    assert_eq!(Amount::HALF.rln(Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(Amount::inverse_lerp(big, a, big, Floor)?, Amount::ZERO);
+    assert_eq!(Amount::inverse_lerp(a, big, a, Floor)?, Amount::ZERO);
+    assert_eq!(Amount::inverse_lerp(big, a, a, Floor)?, Amount::ONE);
+    assert_eq!(
+        Amount::inverse_lerp(a, "2".parse()?, b, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "u128", feature = "std"))]
+fn unsigned_u128() -> Result<()> {
+    type Amount = crate::FixedPoint<u128, typenum::U18>;
+
+    let a: Amount = "1.5".parse()?;
+    let b = Amount::EPSILON;
+    assert_eq!(Amount::MAX.into_bits(), u128::MAX);
+    assert_eq!(
+        Amount::MAX.to_string(),
+        "340282366920938463463.374607431768211455"
+    );
+    assert_eq!(a.rmul(a, Floor)?, "2.25".parse()?);
+    assert_eq!(b.rmul(a, Ceil)?, Amount::from_bits(2));
+    assert_eq!(b.rmul(a, Floor)?, b);
+    assert_eq!(Amount::MAX.rmul(Amount::ONE, Floor)?, Amount::MAX);
+    assert_eq!(
+        Amount::MAX.rmul(Amount::MAX, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Amount::MAX.rdiv(Amount::ONE, Floor)?, Amount::MAX);
+    assert_eq!(Amount::MAX.rdiv(Amount::MAX, Floor)?, Amount::ONE);
+    assert_eq!(
+        Amount::ONE.rdiv("3".parse::<Amount>()?, Ceil)?,
+        "0.333333333333333334".parse()?
+    );
+    assert_eq!(
+        "2".parse::<Amount>()?.rsqrt(Floor)?,
+        "1.414213562373095048".parse()?
+    );
+    assert_eq!(
+        "2".parse::<Amount>()?.rsqrt(Nearest)?,
+        "1.414213562373095049".parse()?
+    );
+    assert_eq!(
+        Amount::MAX.rsqrt(Floor)?,
+        "18446744073.709551615999999999".parse::<crate::FixedPoint<u128, typenum::U18>>()?
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "u128", feature = "std", feature = "serde"))]
+fn unsigned_serde() -> Result<()> {
+    type Amount = crate::FixedPoint<u128, typenum::U18>;
+    type Integer = crate::FixedPoint<u128, typenum::U0>;
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct Struct {
+        #[serde(with = "crate::serde::fixed_digits")]
+        number: Integer,
+    }
+
+    let json = serde_json::to_string(&Amount::MAX).unwrap();
+    assert_eq!(json, r#""340282366920938463463.374607431768211455""#);
+    assert_eq!(serde_json::from_str::<Amount>(&json).unwrap(), Amount::MAX);
+
+    let json = serde_json::to_string(&Struct {
+        number: Integer::MAX,
+    })
+    .unwrap();
+    assert_eq!(
+        json,
+        r#"{"number":"340282366920938463463374607431768211455.0"}"#
+    );
+    assert!(serde_json::from_str::<Amount>(r#""-1""#).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "std"))]
+fn const_generic_precision() -> Result<()> {
+    type Amount = crate::cg::FixedPoint<i64, 9>;
+    type Typenum = crate::FixedPoint<i64, typenum::U9>;
+
+    // The `typenum` API is still available through `Precision`.
+    fn precision<P: crate::Precision>(_: crate::FixedPoint<i64, P>) -> (i32, u32, usize) {
+        (P::I32, P::U32, P::USIZE)
+    }
+
+    let a: Amount = "1.000000001".parse()?;
+    assert_eq!(precision(a), (9, 9, 9));
+    // It's the same type.
+    let _: Typenum = a;
+    assert_eq!(Amount::PRECISION, Typenum::PRECISION);
+    assert_eq!(a.into_bits(), "1.000000001".parse::<Typenum>()?.into_bits());
+    assert_eq!(a.rmul(a, Ceil)?.to_string(), "1.000000003");
+    assert_eq!(a.rmul(a, Floor)?.to_string(), "1.000000002");
+    assert_eq!(
+        Amount::ONE.rdiv(Amount::try_from(3)?, Nearest)?,
+        "0.333333333".parse()?
+    );
+    assert_eq!(crate::cg::FixedPoint::<i64, 0>::ONE.into_bits(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "std"))]
+fn dyn_fixed_point() -> Result<()> {
+    use crate::DynFixedPoint;
+
+    type Dyn = DynFixedPoint<i64>;
+
+    let a: Dyn = "1.50".parse()?;
+    let b: Dyn = "-0.125".parse()?;
+    assert_eq!(a.precision(), 2);
+    assert_eq!(a.into_bits(), 150);
+    assert_eq!(b.precision(), 3);
+    assert_eq!(b.into_bits(), -125);
+    assert_eq!("-0.5".parse::<Dyn>()?.into_bits(), -5);
+    assert_eq!("42".parse::<Dyn>()?.precision(), 0);
+    assert_eq!(a.to_string(), "1.50");
+    assert_eq!(b.to_string(), "-0.125");
+    assert_eq!("-0.05".parse::<Dyn>()?.to_string(), "-0.05");
+    assert_eq!("42".parse::<Dyn>()?.to_string(), "42");
+    assert!("1.5x".parse::<Dyn>().is_err());
+    assert!("0.0000000000000000001".parse::<Dyn>().is_err());
+    assert!("10000.000000000000000".parse::<Dyn>().is_err());
+
+    assert_eq!(a.cadd(b), Err(ArithmeticError::DomainViolation));
+    assert_eq!(a.rmul(b, Floor), Err(ArithmeticError::DomainViolation));
+    assert_eq!(b.rescale(2, Floor)?.to_string(), "-0.13");
+    assert_eq!(b.rescale(2, Nearest)?.to_string(), "-0.13");
+    assert_eq!(b.rescale(2, TowardZero)?.to_string(), "-0.12");
+    assert_eq!(a.rescale(5, Floor)?.to_string(), "1.50000");
+    assert_eq!(a.rescale(19, Floor), Err(ArithmeticError::DomainViolation));
+    assert_eq!(
+        Dyn::from_bits(i64::MAX, 0)?.rescale(1, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+
+    let b = b.rescale(2, Ceil)?;
+    assert_eq!(a.cadd(b)?.to_string(), "1.38");
+    assert_eq!(a.csub(b)?.to_string(), "1.62");
+    assert_eq!(a.rmul(b, Floor)?.to_string(), "-0.18");
+    let c: Dyn = "0.33".parse()?;
+    assert_eq!(a.rmul(c, Floor)?.to_string(), "0.49");
+    assert_eq!(a.rmul(c, Nearest)?.to_string(), "0.50");
+    assert_eq!(a.rmul(c.cneg()?, AwayFromZero)?.to_string(), "-0.50");
+    assert_eq!(a.rdiv(b, Floor)?.to_string(), "-12.50");
+    assert_eq!(a.cmul(3)?.to_string(), "4.50");
+    assert_eq!(
+        a.rdiv(Dyn::from_integer(0, 2)?, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(Dyn::from_integer(7, 3)?.to_string(), "7.000");
+    assert!(Dyn::from_bits(1, 19).is_err());
+
+    type Amount = crate::FixedPoint<i64, typenum::U3>;
+    let c = Dyn::from("1.25".parse::<Amount>()?);
+    assert_eq!(c.to_string(), "1.250");
+    assert_eq!(Amount::try_from(a)?, "1.5".parse()?);
+    assert!(Amount::try_from("0.0001".parse::<Dyn>()?).is_err());
+    assert_eq!(Amount::try_from("0.1000".parse::<Dyn>()?)?, "0.1".parse()?);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "std"))]
+fn bin_fixed_point() -> Result<()> {
+    use crate::BinFixedPoint;
+
+    type Q32 = BinFixedPoint<i64, typenum::U32>;
+
+    let three = Q32::try_from(3)?;
+    let half = Q32::ONE.rdiv(Q32::try_from(2)?, Floor)?;
+    let one_and_half = Q32::ONE.cadd(half)?;
+    assert_eq!(Q32::ONE.into_bits(), 1 << 32);
+    assert_eq!(Q32::ONE.rdiv(three, Floor)?.into_bits(), 1_431_655_765);
+    assert_eq!(Q32::ONE.rdiv(three, Ceil)?.into_bits(), 1_431_655_766);
+    assert_eq!(Q32::ONE.rdiv(three, Nearest)?.into_bits(), 1_431_655_765);
+    assert_eq!(
+        Q32::ONE.cneg()?.rdiv(three, Floor)?.into_bits(),
+        -1_431_655_766
+    );
+    assert_eq!(
+        Q32::ONE.rdiv(Q32::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+
+    assert_eq!(one_and_half.rmul(one_and_half, Floor)?.to_string(), "2.25");
+    assert_eq!(Q32::EPSILON.rmul(half, Floor)?, Q32::ZERO);
+    assert_eq!(Q32::EPSILON.rmul(half, Ceil)?, Q32::EPSILON);
+    assert_eq!(Q32::EPSILON.rmul(half, Nearest)?, Q32::EPSILON);
+    assert_eq!(
+        Q32::EPSILON.cneg()?.rmul(half, Floor)?,
+        Q32::EPSILON.cneg()?
+    );
+    assert_eq!(Q32::EPSILON.cneg()?.rmul(half, TowardZero)?, Q32::ZERO);
+    assert_eq!(
+        Q32::MAX.rmul(Q32::try_from(2)?, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Q32::MAX.saturating_add(Q32::ONE), Q32::MAX);
+    assert_eq!(Q32::MIN.cneg(), Err(ArithmeticError::Overflow));
+    assert_eq!(three.cmul(2)?, Q32::try_from(6)?);
+    assert!(Q32::try_from(1_i64 << 31).is_err());
+
+    assert_eq!(one_and_half.cneg()?.to_string(), "-1.5");
+    assert_eq!(Q32::ONE.to_string(), "1.0");
+    assert_eq!(
+        Q32::EPSILON.to_string(),
+        "0.00000000023283064365386962890625"
+    );
+    assert_eq!(Q32::MIN.to_string(), "-2147483648.0");
+    assert_eq!(
+        Q32::MAX.to_string(),
+        "2147483647.99999999976716935634613037109375"
+    );
+
+    assert_eq!(one_and_half.integral(Floor), 1);
+    assert_eq!(one_and_half.integral(Nearest), 2);
+    assert_eq!(one_and_half.cneg()?.integral(Floor), -2);
+    assert_eq!(one_and_half.cneg()?.integral(TowardZero), -1);
+    assert_eq!(f64::from(one_and_half.cneg()?), -1.5);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i128", feature = "std"))]
+fn bin_fixed_point_i128() -> Result<()> {
+    type Q64 = crate::BinFixedPoint<i128, typenum::U64>;
+
+    let three = Q64::try_from(3)?;
+    assert_eq!(
+        Q64::ONE.rdiv(three, Nearest)?.into_bits(),
+        6_148_914_691_236_517_205
+    );
+    assert_eq!(Q64::MAX.rmul(Q64::ONE, Floor)?, Q64::MAX);
+    assert_eq!(Q64::MIN.rmul(Q64::ONE, Floor)?, Q64::MIN);
+    assert_eq!(Q64::MIN.rdiv(Q64::ONE, Floor)?, Q64::MIN);
+    assert_eq!(Q64::MIN.to_string(), "-9223372036854775808.0");
+    assert_eq!(three.rmul(three, Floor)?.to_string(), "9.0");
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i256", feature = "std"))]
+fn i256_layout() -> Result<()> {
+    use crate::I256;
+
+    type Amount = crate::FixedPoint<I256, typenum::U18>;
+
+    let max = "57896044618658097711785492504343953926634992332820282019728.792003956564819967";
+    assert_eq!(Amount::MAX.to_string(), max);
+    assert_eq!(max.parse::<Amount>()?, Amount::MAX);
+    assert_eq!(
+        Amount::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728.792003956564819968"
+    );
+    assert_eq!(Amount::MIN.to_string().parse::<Amount>()?, Amount::MIN);
+    assert!(
+        "57896044618658097711785492504343953926634992332820282019728.792003956564819968"
+            .parse::<Amount>()
+            .is_err()
+    );
+    assert_eq!(Amount::ONE.to_string(), "1.0");
+    assert_eq!("-0.5".parse::<Amount>()?.to_string(), "-0.5");
+
+    let a: Amount = "1.5".parse()?;
+    let big: Amount = "100000000000000000000000000000".parse()?;
+    assert_eq!(a.rmul(a, Floor)?, "2.25".parse()?);
+    assert_eq!(a.rmul(a.cneg()?, Floor)?, "-2.25".parse()?);
+    assert_eq!(
+        Amount::EPSILON.rmul(a, Ceil)?,
+        Amount::from_bits(I256::from(2i128))
+    );
+    assert_eq!(Amount::EPSILON.rmul(a, Floor)?, Amount::EPSILON);
+    assert_eq!(
+        Amount::EPSILON.cneg()?.rmul(a, Floor)?,
+        Amount::from_bits(I256::from(-2i128))
+    );
+    assert_eq!(
+        big.rmul(big, Floor)?.to_string(),
+        "10000000000000000000000000000000000000000000000000000000000.0"
+    );
+    assert_eq!(
+        big.rmul(big.cmul(I256::from(10i128))?, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Amount::MAX.rmul(Amount::ONE, Floor)?, Amount::MAX);
+    assert_eq!(Amount::MIN.rmul(Amount::ONE, Floor)?, Amount::MIN);
+
+    assert_eq!(Amount::MAX.rdiv(Amount::MAX, Floor)?, Amount::ONE);
+    assert_eq!(Amount::MIN.rdiv(Amount::ONE, Floor)?, Amount::MIN);
+    assert_eq!(
+        Amount::MIN.rdiv(Amount::ONE.cneg()?, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        Amount::ONE.rdiv(Amount::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    let seven = Amount::try_from(7)?;
+    assert_eq!(
+        big.rdiv(seven, Floor)?,
+        "14285714285714285714285714285.714285714285714285".parse()?
+    );
+    assert_eq!(
+        big.cneg()?.rdiv(seven, Floor)?,
+        "-14285714285714285714285714285.714285714285714286".parse()?
+    );
+    assert_eq!(
+        big.rdiv(seven, Nearest)?,
+        "14285714285714285714285714285.714285714285714286".parse()?
+    );
+
+    assert_eq!(
+        Amount::MAX.cadd(Amount::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Amount::MAX.saturating_add(Amount::ONE), Amount::MAX);
+    assert_eq!(Amount::MIN.saturating_sub(Amount::ONE), Amount::MIN);
+    assert_eq!(Amount::ONE.csub(a)?, "-0.5".parse()?);
+    assert_eq!(Amount::MIN.cneg(), Err(ArithmeticError::Overflow));
+    assert_eq!(Amount::MIN.abs(), Err(ArithmeticError::Overflow));
+
+    assert_eq!(
+        "2".parse::<Amount>()?.rsqrt(Floor)?,
+        "1.414213562373095048".parse()?
+    );
+    assert_eq!(
+        Amount::MAX.rsqrt(Floor)?,
+        "240615969168004511545033772477.625056927114980741".parse()?
+    );
+    assert_eq!(
+        a.cneg()?.rsqrt(Floor),
+        Err(ArithmeticError::DomainViolation)
+    );
+
+    let b: Amount = "-8273.519".parse()?;
+    assert_eq!(b.integral(Floor), I256::from(-8274i128));
+    assert_eq!(b.integral(Ceil), I256::from(-8273i128));
+    assert_eq!(b.integral(Nearest), I256::from(-8274i128));
+    assert_eq!(a.integral(Nearest), I256::from(2i128));
+    assert_eq!(f64::from(b), -8273.519);
+
+    assert_eq!(format!("{:>10}", a), "       1.5");
+    assert_eq!(format!("{:+.3}", a), "+1.500");
+    assert_eq!(format!("{:.0}", b), "-8273");
+    assert_eq!(format!("{:#}", a), "1.500000000000000000");
+    assert_eq!(format!("{:.20}", a), "1.50000000000000000000");
+    assert_eq!(Amount::MIN.to_array_string().len(), Amount::MAX_DISPLAY_LEN);
+    assert_eq!("+.5".parse::<Amount>()?, "0.5".parse()?);
+    assert_eq!("1.".parse::<Amount>()?, Amount::ONE);
+    assert_eq!("-8_273.519".parse::<Amount>()?, b);
+    assert_eq!("-8.273519e3".parse::<Amount>()?, b);
+    assert_eq!(
+        "2.5e-17".parse::<Amount>()?,
+        Amount::from_bits(I256::from(25i128))
+    );
+    assert_eq!("0e1000000000".parse::<Amount>()?, Amount::ZERO);
+    assert!("1e-19".parse::<Amount>().is_err());
+    assert!("1e59".parse::<Amount>().is_err());
+    assert!("1.0000000000000000000".parse::<Amount>().is_err());
+    assert!("1__0".parse::<Amount>().is_err());
+    assert_eq!(Amount::try_from(-8273.519)?, b);
+    assert_eq!(Amount::try_from(0.1f32)?, "0.1".parse()?);
+    // Integral digits are kept as is, like for primitive layouts.
+    assert_eq!(
+        Amount::try_from(1e40)?,
+        "10000000000000000303786028427003666890752".parse()?
+    );
+    assert!(Amount::try_from(1e59).is_err());
+    assert!(Amount::try_from(f64::NAN).is_err());
+    assert_eq!(
+        Amount::from_f64_rounded(-1e40, Floor)?,
+        "-10000000000000000303786028427003666890752".parse()?
+    );
+
+    assert_eq!(Amount::TWO.rmul(Amount::HALF, Floor)?, Amount::ONE);
+    assert_eq!(format!("{:e}", big), "1e29");
+    assert_eq!(format!("{:.2e}", Amount::MIN), "-5.79e58");
+    assert_eq!(
+        big.fmt_grouped(',', '.').to_string(),
+        "100,000,000,000,000,000,000,000,000,000.0"
+    );
+    assert_eq!(big.to_decimal(100), (I256::from(1i128), 29));
+    assert_eq!(Amount::from_decimal(I256::from(1i128), 29)?, big);
+    assert_eq!(big.next_power_of_ten()?, big);
+    assert_eq!(
+        Amount::MAX.next_power_of_ten(),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        "2".parse::<Amount>()?.rln(Floor)?,
+        "0.693147180559945309".parse()?
+    );
+    assert_eq!(Amount::MAX.rln(Floor), Err(ArithmeticError::Overflow));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "u32", feature = "std"))]
+fn unsigned_u32() -> Result<()> {
+    type Amount = crate::FixedPoint<u32, typenum::U4>;
+
+    let a: Amount = "1.5".parse()?;
+    assert_eq!(Amount::MAX.to_string(), "429496.7295");
+    assert_eq!(a.rmul(a, Floor)?, "2.25".parse()?);
+    assert_eq!(
+        Amount::ONE.rdiv("3".parse::<Amount>()?, Nearest)?,
+        "0.3333".parse()?
+    );
+    assert_eq!("2".parse::<Amount>()?.rsqrt(Floor)?, "1.4142".parse()?);
+    Ok(())
+}
+
 #[test]
 fn const_fn() {
     let test_cases = trybuild::TestCases::new();
     test_cases.compile_fail(
         "src/tests/const_fn/01_fixnum_const_bad_str_with_too_long_fractional_part.rs",
     );
+    #[cfg(feature = "i16")]
+    test_cases.compile_fail("src/tests/const_fn/02_hundred_out_of_range.rs");
 }